@@ -2,7 +2,8 @@
  * Global State Sync Service
  * 
  * Syncs the on-chain Global account to the database.
- * This includes fee configuration, operators, and trading status.
+ * This includes fee configuration and trading status. Roles (keepers,
+ * operators, ...) live in RoleAssignment PDAs, not in the Global account.
  */
 
 import { PublicKey, Connection } from '@solana/web3.js';
@@ -53,7 +54,6 @@ function getReadOnlyProgram(): Program {
 interface GlobalAccount {
   authority: PublicKey;
   usdcMint: PublicKey;
-  keeper: PublicKey;
   bump: number;
   platformTreasuryBump: number;
   totalTradingFeesCollected: bigint;
@@ -64,8 +64,6 @@ interface GlobalAccount {
   makerRebateRate: number;
   creatorIncentiveRate: number;
   tradingPaused: boolean;
-  operatorCount: number;
-  operators: PublicKey[];
 }
 
 async function fetchGlobalAccount(): Promise<GlobalAccount | null> {
@@ -78,7 +76,6 @@ async function fetchGlobalAccount(): Promise<GlobalAccount | null> {
     return {
       authority: global.authority,
       usdcMint: global.usdcMint,
-      keeper: global.keeper,
       bump: global.bump,
       platformTreasuryBump: global.platformTreasuryBump,
       totalTradingFeesCollected: BigInt(global.totalTradingFeesCollected?.toString() || '0'),
@@ -89,8 +86,6 @@ async function fetchGlobalAccount(): Promise<GlobalAccount | null> {
       makerRebateRate: Number(global.makerRebateRate || 0),
       creatorIncentiveRate: Number(global.creatorIncentiveRate || 0),
       tradingPaused: Boolean(global.tradingPaused),
-      operatorCount: Number(global.operatorCount || 0),
-      operators: (global.operators || []).filter((op: PublicKey) => !op.equals(PublicKey.default)),
     };
   } catch (err) {
     logger.error('Failed to fetch Global account', err);
@@ -99,8 +94,6 @@ async function fetchGlobalAccount(): Promise<GlobalAccount | null> {
 }
 
 async function upsertGlobalState(client: PoolClient, global: GlobalAccount, slot: number): Promise<void> {
-  const operatorAddresses = global.operators.map(op => op.toString());
-  
   // Check if global_state exists
   const existing = await client.query(`SELECT id FROM public.global_state LIMIT 1`);
   
@@ -108,15 +101,14 @@ async function upsertGlobalState(client: PoolClient, global: GlobalAccount, slot
     // Insert new
     await client.query(
       `INSERT INTO public.global_state 
-       (authority, usdc_mint, keeper, center_taker_fee_rate, extreme_taker_fee_rate, 
+       (authority, usdc_mint, center_taker_fee_rate, extreme_taker_fee_rate, 
         platform_fee_rate, maker_rebate_rate, creator_incentive_rate,
         total_trading_fees_collected, total_creation_fees_collected,
-        operator_count, operators, trading_paused, last_synced_slot, last_synced_at)
-       VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9, $10, $11, $12, $13, $14, NOW())`,
+        trading_paused, last_synced_slot, last_synced_at)
+       VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9, $10, $11, NOW())`,
       [
         global.authority.toString(),
         global.usdcMint.toString(),
-        global.keeper.toString(),
         global.centerTakerFeeRate,
         global.extremeTakerFeeRate,
         global.platformFeeRate,
@@ -124,8 +116,6 @@ async function upsertGlobalState(client: PoolClient, global: GlobalAccount, slot
         global.creatorIncentiveRate,
         global.totalTradingFeesCollected.toString(),
         global.totalCreationFeesCollected.toString(),
-        global.operatorCount,
-        operatorAddresses,
         global.tradingPaused,
         slot,
       ]
@@ -136,24 +126,20 @@ async function upsertGlobalState(client: PoolClient, global: GlobalAccount, slot
       `UPDATE public.global_state SET
          authority = $1,
          usdc_mint = $2,
-         keeper = $3,
-         center_taker_fee_rate = $4,
-         extreme_taker_fee_rate = $5,
-         platform_fee_rate = $6,
-         maker_rebate_rate = $7,
-         creator_incentive_rate = $8,
-         total_trading_fees_collected = $9,
-         total_creation_fees_collected = $10,
-         operator_count = $11,
-         operators = $12,
-         trading_paused = $13,
-         last_synced_slot = $14,
+         center_taker_fee_rate = $3,
+         extreme_taker_fee_rate = $4,
+         platform_fee_rate = $5,
+         maker_rebate_rate = $6,
+         creator_incentive_rate = $7,
+         total_trading_fees_collected = $8,
+         total_creation_fees_collected = $9,
+         trading_paused = $10,
+         last_synced_slot = $11,
          last_synced_at = NOW(),
          updated_at = NOW()`,
       [
         global.authority.toString(),
         global.usdcMint.toString(),
-        global.keeper.toString(),
         global.centerTakerFeeRate,
         global.extremeTakerFeeRate,
         global.platformFeeRate,
@@ -161,8 +147,6 @@ async function upsertGlobalState(client: PoolClient, global: GlobalAccount, slot
         global.creatorIncentiveRate,
         global.totalTradingFeesCollected.toString(),
         global.totalCreationFeesCollected.toString(),
-        global.operatorCount,
-        operatorAddresses,
         global.tradingPaused,
         slot,
       ]
@@ -211,7 +195,6 @@ async function main() {
   logger.info('Fetched Global account', {
     authority: global.authority.toString(),
     tradingPaused: global.tradingPaused,
-    operatorCount: global.operatorCount,
     centerTakerFeeRate: global.centerTakerFeeRate,
   });
   
@@ -20,6 +20,10 @@ pub const MAX_QUESTION_LEN: usize = 200;
 pub const MAX_DESCRIPTION_LEN: usize = 500;
 pub const MAX_OUTCOME_DESCRIPTION_LEN: usize = 200;
 
+// Market clarification log limits
+pub const MAX_CLARIFICATIONS: usize = 10;
+pub const MAX_CLARIFICATION_LEN: usize = 280;
+
 // ============================================
 // VRF Termination Constants (Updated 2026-01-10 - User opt-in mechanism)
 // ============================================
@@ -63,11 +67,28 @@ pub const DEFAULT_TERMINATION_PROBABILITY: u32 = 1_000; // 0.1% (scaled by 10^6)
 // ============================================
 // Platform Fees
 // ============================================
-/// Market creation fee: 10 USDC (scaled by 10^6)
-/// Charged when creating a new prediction market
+/// Default market creation fee: 10 USDC (scaled by 10^6)
+/// Charged when creating a new prediction market (see `Global::creation_fee`)
 /// Collected in platform treasury as protocol revenue
 pub const MARKET_CREATION_FEE: u64 = 10_000_000; // 10 USDC (6 decimals)
 
+/// Maximum market creation fee: 1,000 USDC (scaled by 10^6)
+pub const MAX_MARKET_CREATION_FEE: u64 = 1_000_000_000;
+
+// ============================================
+// Market Closing
+// ============================================
+/// After this long since settlement/termination, close_market may sweep the
+/// vault even if some positions were never redeemed or balances withdrawn
+pub const MARKET_CLOSE_GRACE_PERIOD_SECONDS: i64 = 180 * 24 * 60 * 60; // 180 days
+
+// ============================================
+// Dust Sweep
+// ============================================
+/// Maximum value (USDC balance + positions at final prices) a keeper may
+/// sweep to the owner when closing user accounts after redemption.
+pub const DUST_SWEEP_THRESHOLD_USDC: u64 = 10_000; // 0.01 USDC
+
 // ============================================
 // Inactivity Termination Reward
 // ============================================
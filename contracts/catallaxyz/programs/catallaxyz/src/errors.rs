@@ -195,4 +195,175 @@ pub enum TerminatorError {
     
     #[msg("Order already cancelled or filled")]
     OrderAlreadyCancelledOrFilled,
+
+    // ============================================
+    // Stop Order Errors
+    // ============================================
+
+    #[msg("Invalid stop trigger")]
+    InvalidStopTrigger,
+
+    #[msg("Stop order not triggered by last trade price")]
+    StopNotTriggered,
+
+    // ============================================
+    // Delegation Errors
+    // ============================================
+
+    #[msg("Order signer is not authorized to act for maker")]
+    SignerNotAuthorized,
+
+    #[msg("Delegation has expired")]
+    DelegationExpired,
+
+    #[msg("Delegate spend cap exceeded")]
+    DelegateSpendCapExceeded,
+
+    #[msg("Too many delegates")]
+    TooManyDelegates,
+
+    #[msg("Delegate not found")]
+    DelegateNotFound,
+
+    // ============================================
+    // Session Key Errors
+    // ============================================
+
+    #[msg("Session key has expired")]
+    SessionKeyExpired,
+
+    #[msg("Session key not allowed to trade this market")]
+    SessionMarketNotAllowed,
+
+    #[msg("Session key notional limit exceeded")]
+    SessionNotionalExceeded,
+
+    // ============================================
+    // Market Nonce Errors
+    // ============================================
+
+    #[msg("Cancellation list is full; cancel with a nonce or the cancel bitmap instead")]
+    CancellationListFull,
+
+    #[msg("Order cancelled in market scope")]
+    OrderCancelledInMarket,
+
+    // ============================================
+    // Order Status Errors
+    // ============================================
+
+    #[msg("Order status cannot be closed until the order expires or is cancelled by nonce, range or salt")]
+    OrderStatusNotClosable,
+
+    // ============================================
+    // Account Closing Errors
+    // ============================================
+
+    #[msg("Account still holds a balance")]
+    AccountNotEmpty,
+
+    #[msg("Balance exceeds the dust sweep threshold")]
+    BalanceAboveDustThreshold,
+
+    #[msg("Market still holds positions or USDC and the grace period has not passed")]
+    MarketNotClosable,
+
+    #[msg("Market id belongs to a closed market and cannot be reused")]
+    MarketIdClosed,
+
+    // ============================================
+    // Authority Transfer Errors
+    // ============================================
+
+    #[msg("No authority transfer is pending")]
+    NoPendingAuthority,
+
+    #[msg("Signer is not the pending authority")]
+    NotPendingAuthority,
+
+    #[msg("Authority transfer timelock has not elapsed")]
+    AuthorityTimelockActive,
+
+    // ============================================
+    // Role Errors
+    // ============================================
+
+    #[msg("Unknown role")]
+    InvalidRole,
+
+    // ============================================
+    // Governance Errors
+    // ============================================
+
+    #[msg("No proposal is pending")]
+    NoPendingProposal,
+
+    #[msg("Governance timelock has not elapsed")]
+    GovernanceTimelockActive,
+
+    #[msg("Fee override exceeds the global fee configuration")]
+    FeeOverrideOutOfBounds,
+
+    #[msg("Order's signed fee rate does not cover the market's fee override")]
+    OrderFeeBelowMarketFee,
+
+    #[msg("Fee override was proposed by the fee admin")]
+    FeeOverrideSetByFeeAdmin,
+
+    // ============================================
+    // Fee Curve Errors
+    // ============================================
+
+    #[msg("Invalid fee curve: unknown type, or breakpoints not strictly increasing within 0-1")]
+    InvalidFeeCurve,
+
+    #[msg("Invalid fee tiers: thresholds must increase from above zero, discounts must not decrease or exceed 100%")]
+    InvalidFeeTiers,
+
+    // ============================================
+    // Referral Errors
+    // ============================================
+
+    #[msg("Invalid referrer: cannot be empty or the user")]
+    InvalidReferrer,
+
+    #[msg("Withdrawal would leave the treasury below unclaimed referral rewards")]
+    ReferralRewardsReserved,
+
+    // ============================================
+    // Creation Fee Errors
+    // ============================================
+
+    #[msg("Alternative fee token is not enabled")]
+    AltFeeTokenNotEnabled,
+
+    #[msg("Creation fee is not refundable: paid in the alternative token, already refunded, or volume below threshold")]
+    CreationFeeNotRefundable,
+
+    // ============================================
+    // Moderation Errors
+    // ============================================
+
+    #[msg("Market is already delisted")]
+    MarketAlreadyDelisted,
+
+    #[msg("Invalid delist reason code")]
+    InvalidDelistReason,
+
+    // ============================================
+    // Market Metadata Errors
+    // ============================================
+
+    #[msg("Market metadata is frozen after the first trade or position; append a clarification instead")]
+    MarketMetadataFrozen,
+
+    #[msg("Market clarification log is full")]
+    ClarificationLogFull,
+
+    // ============================================
+    // Migration Errors
+    // ============================================
+
+    #[msg("Account already uses the current layout")]
+    AccountAlreadyMigrated,
 }
@@ -1,4 +1,5 @@
 use anchor_lang::prelude::*;
+use crate::states::{FeeCurve, FeeRates, FeeTierTable, SaltRange};
 
 #[event]
 pub struct MarketCreated {
@@ -12,6 +13,30 @@ pub struct MarketCreated {
     pub timestamp: i64,
 }
 
+/// Market metadata edited by the creator (before the first trade)
+#[event]
+pub struct MarketMetadataUpdated {
+    pub market: Pubkey,
+    pub creator: Pubkey,
+    /// Metadata after the edit
+    pub question: String,
+    pub description: String,
+    pub yes_description: String,
+    pub no_description: String,
+    pub timestamp: i64,
+}
+
+/// Clarification appended to a market's clarification log
+#[event]
+pub struct MarketClarificationAdded {
+    pub market: Pubkey,
+    pub creator: Pubkey,
+    /// Position in the clarification log
+    pub index: u32,
+    pub text: String,
+    pub timestamp: i64,
+}
+
 #[event]
 pub struct MarketSettled {
     pub market: Pubkey,
@@ -54,6 +79,24 @@ pub struct CtfTokensRedeemed {
     pub timestamp: i64,
 }
 
+/// Emitted by redeem_all with everything paid out in one transfer
+#[event]
+pub struct AllRedeemed {
+    pub market: Pubkey,
+    pub user: Pubkey,
+    /// YES positions redeemed at the final YES price
+    pub yes_amount: u64,
+    /// NO positions redeemed at the final NO price
+    pub no_amount: u64,
+    /// USDC paid for the positions
+    pub redeemed_usdc: u64,
+    /// Idle trading balance (UserBalance) paid out
+    pub idle_usdc: u64,
+    /// Total USDC transferred
+    pub total_usdc: u64,
+    pub timestamp: i64,
+}
+
 // ============================================
 // Termination Events
 // ============================================
@@ -61,7 +104,7 @@ pub struct CtfTokensRedeemed {
 #[event]
 pub struct MarketTerminated {
     pub market: Pubkey,
-    /// 0 = VRF, 1 = inactivity
+    /// 0 = VRF, 1 = inactivity, 2 = delisted by moderator
     pub reason: u8,
     /// Final YES price (scaled by 10^6)
     pub final_yes_price: u64,
@@ -73,6 +116,36 @@ pub struct MarketTerminated {
     pub timestamp: i64,
 }
 
+/// Market closed event (archival record for indexers)
+///
+/// Emitted when a settled/terminated market and its vault are closed; the
+/// on-chain account no longer exists afterwards.
+#[event]
+pub struct MarketClosed {
+    pub market: Pubkey,
+    pub creator: Pubkey,
+    pub market_id: [u8; 32],
+    /// Final market status (settled or terminated)
+    pub status: u8,
+    /// Final YES price (scaled by 10^6)
+    pub final_yes_price: Option<u64>,
+    /// Final NO price (scaled by 10^6)
+    pub final_no_price: Option<u64>,
+    pub total_trades: u64,
+    pub total_redeemable_usdc: u64,
+    pub total_redeemed_usdc: u64,
+    /// Positions never redeemed (non-zero only after the grace period)
+    pub unredeemed_yes_supply: u64,
+    pub unredeemed_no_supply: u64,
+    /// Vault USDC swept to the platform treasury
+    pub dust_swept: u64,
+    pub created_at: i64,
+    pub terminated_at: Option<i64>,
+    /// Creator or keeper that closed the market
+    pub closed_by: Pubkey,
+    pub timestamp: i64,
+}
+
 /// VRF termination check result event
 /// 
 /// Emitted EVERY time a user attempts VRF termination check.
@@ -173,7 +246,9 @@ pub struct MarketCreationFeeCollected {
     pub market: Pubkey,
     /// Market creator
     pub creator: Pubkey,
-    /// Creation fee amount (USDC, scaled by 10^6)
+    /// Token the fee was paid in (USDC or the alternative fee token)
+    pub fee_mint: Pubkey,
+    /// Creation fee amount (in `fee_mint` base units)
     pub fee_amount: u64,
     /// Transaction slot
     pub slot: u64,
@@ -185,6 +260,21 @@ pub struct MarketCreationFeeCollected {
 // Admin Control Events
 // ============================================
 
+/// Market delisted event (moderation; indexers hide delisted markets)
+#[event]
+pub struct MarketDelisted {
+    /// Market address
+    pub market: Pubkey,
+    /// Reason code (see `delist_reason`)
+    pub reason: u8,
+    /// Whether the market was also terminated at its last traded price
+    pub terminated: bool,
+    /// Moderator who delisted
+    pub delisted_by: Pubkey,
+    /// Delist timestamp
+    pub delisted_at: i64,
+}
+
 /// Market paused event (admin emergency stop)
 #[event]
 pub struct MarketPaused {
@@ -213,7 +303,7 @@ pub struct MarketResumed {
 /// All markets read from Global account, so changes take effect immediately.
 #[event]
 pub struct GlobalFeeRatesUpdated {
-    /// Admin who updated
+    /// Executor of the queued change
     pub updated_by: Pubkey,
     /// Center taker fee rate (at 50% probability)
     pub center_taker_fee_rate: u32,
@@ -225,6 +315,10 @@ pub struct GlobalFeeRatesUpdated {
     pub maker_rebate_rate: u32,
     /// Creator incentive rate
     pub creator_incentive_rate: u32,
+    /// Fee curve shape
+    pub curve: FeeCurve,
+    /// Volume fee tiers
+    pub fee_tiers: FeeTierTable,
     /// Update timestamp
     pub updated_at: i64,
 }
@@ -347,24 +441,148 @@ pub struct NonceIncremented {
     pub timestamp: i64,
 }
 
-/// Operator added event
+/// Market-scoped orders cancelled event
+/// 
+/// Emitted when a user raises a market nonce or cancels salt ranges / order hashes
+#[event]
+pub struct MarketOrdersCancelled {
+    /// User address
+    pub user: Pubkey,
+    /// Market PDA
+    pub market: Pubkey,
+    /// Market nonce after the update
+    pub current_nonce: u64,
+    /// Salt ranges cancelled
+    pub salt_ranges: Vec<SaltRange>,
+    /// Order hashes cancelled
+    pub order_hashes: Vec<[u8; 32]>,
+    /// Transaction slot
+    pub slot: u64,
+    /// Transaction timestamp
+    pub timestamp: i64,
+}
+
+/// Emitted when a user cancels orders by salt (cancellation bitmap)
+#[event]
+pub struct OrdersCancelledBySalt {
+    /// User address
+    pub user: Pubkey,
+    /// Salts cancelled
+    pub salts: Vec<u64>,
+    /// Transaction slot
+    pub slot: u64,
+    /// Transaction timestamp
+    pub timestamp: i64,
+}
+
+/// Emitted when an order status account is closed and its rent refunded
+#[event]
+pub struct OrderStatusClosed {
+    /// Order hash
+    pub order_hash: [u8; 32],
+    /// Order maker
+    pub maker: Pubkey,
+    /// Rent recipient (original payer)
+    pub payer: Pubkey,
+    /// Transaction slot
+    pub slot: u64,
+    /// Transaction timestamp
+    pub timestamp: i64,
+}
+
+/// Emitted when a user's UserBalance and UserPosition are closed
+#[event]
+pub struct UserAccountsClosed {
+    /// Market PDA
+    pub market: Pubkey,
+    /// Account owner (rent recipient)
+    pub user: Pubkey,
+    /// Dust paid out to the owner before closing
+    pub swept_usdc: u64,
+    /// User or keeper that closed the accounts
+    pub closed_by: Pubkey,
+    /// Timestamp
+    pub timestamp: i64,
+}
+
+/// Delegate added or updated event
+#[event]
+pub struct DelegateAdded {
+    /// Maker granting the delegation
+    pub maker: Pubkey,
+    /// Delegate signer
+    pub signer: Pubkey,
+    /// Spend cap in USDC (0 = unlimited)
+    pub spend_cap: u64,
+    /// Expiry timestamp (0 = never)
+    pub expires_at: i64,
+    /// Timestamp
+    pub timestamp: i64,
+}
+
+/// Delegate revoked event
+#[event]
+pub struct DelegateRevoked {
+    /// Maker revoking the delegation
+    pub maker: Pubkey,
+    /// Delegate signer
+    pub signer: Pubkey,
+    /// Timestamp
+    pub timestamp: i64,
+}
+
+/// Session key created event
+#[event]
+pub struct SessionKeyCreated {
+    /// Wallet authorizing the session
+    pub owner: Pubkey,
+    /// Session signing key
+    pub key: Pubkey,
+    /// Markets the key may trade
+    pub markets: Vec<Pubkey>,
+    /// Maximum cumulative USDC notional
+    pub max_notional: u64,
+    /// Expiry timestamp
+    pub expires_at: i64,
+    /// Timestamp
+    pub timestamp: i64,
+}
+
+/// Session key revoked event
+#[event]
+pub struct SessionKeyRevoked {
+    /// Wallet that authorized the session
+    pub owner: Pubkey,
+    /// Session signing key
+    pub key: Pubkey,
+    /// Notional used before revocation
+    pub used_notional: u64,
+    /// Timestamp
+    pub timestamp: i64,
+}
+
+/// Role granted event
 #[event]
-pub struct OperatorAdded {
-    /// New operator address
-    pub operator: Pubkey,
-    /// Added by admin
-    pub added_by: Pubkey,
+pub struct RoleGranted {
+    /// Role tag (see `states::role`)
+    pub role: u8,
+    /// Key receiving the role
+    pub holder: Pubkey,
+    /// Granted by admin
+    pub granted_by: Pubkey,
     /// Timestamp
     pub timestamp: i64,
 }
 
-/// Operator removed event
+/// Role revoked event
 #[event]
-pub struct OperatorRemoved {
-    /// Removed operator address
-    pub operator: Pubkey,
-    /// Removed by admin
-    pub removed_by: Pubkey,
+pub struct RoleRevoked {
+    /// Role tag (see `states::role`)
+    pub role: u8,
+    /// Key losing the role
+    pub holder: Pubkey,
+    /// Revoked by admin
+    pub revoked_by: Pubkey,
     /// Timestamp
     pub timestamp: i64,
 }
@@ -386,3 +604,295 @@ pub struct GlobalTradingUnpaused {
     /// Timestamp
     pub timestamp: i64,
 }
+
+/// Authority transfer proposed (or cancelled when pending_authority is default)
+#[event]
+pub struct AuthorityProposed {
+    /// Current authority
+    pub authority: Pubkey,
+    /// Proposed authority
+    pub pending_authority: Pubkey,
+    /// Earliest acceptance time
+    pub eta: i64,
+    /// Timestamp
+    pub timestamp: i64,
+}
+
+/// Authority transfer accepted event
+#[event]
+pub struct AuthorityTransferred {
+    /// Previous authority
+    pub previous_authority: Pubkey,
+    /// New authority
+    pub new_authority: Pubkey,
+    /// Timestamp
+    pub timestamp: i64,
+}
+
+/// Authority transfer timelock updated event
+#[event]
+pub struct AuthorityTransferDelayUpdated {
+    /// New delay (seconds)
+    pub delay: i64,
+    /// Admin who updated the delay (executor for a queued decrease)
+    pub updated_by: Pubkey,
+    /// Timestamp
+    pub timestamp: i64,
+}
+
+/// Authority transfer timelock decrease queued event
+/// (applies at `eta` via execute_authority_transfer_delay)
+#[event]
+pub struct AuthorityTransferDelayProposed {
+    /// Proposed delay (seconds)
+    pub delay: i64,
+    /// Earliest execution time
+    pub eta: i64,
+    /// Admin who proposed
+    pub proposed_by: Pubkey,
+    /// Timestamp
+    pub timestamp: i64,
+}
+
+/// Legacy order hash migration flag updated event
+#[event]
+pub struct LegacyOrderHashUpdated {
+    /// Whether legacy order hashes are accepted
+    pub accept_legacy_order_hash: bool,
+    /// Admin who updated the flag
+    pub updated_by: Pubkey,
+    /// Timestamp
+    pub timestamp: i64,
+}
+
+/// Fee rates proposal queued event (applies at `eta` via execute_fee_rates)
+#[event]
+pub struct FeeRatesProposed {
+    /// Fee admin who proposed
+    pub proposed_by: Pubkey,
+    /// Center taker fee rate (at 50% probability)
+    pub center_taker_fee_rate: u32,
+    /// Extreme taker fee rate (at 0%/100% probability)
+    pub extreme_taker_fee_rate: u32,
+    /// Platform fee share
+    pub platform_fee_rate: u32,
+    /// Maker rebate rate
+    pub maker_rebate_rate: u32,
+    /// Creator incentive rate
+    pub creator_incentive_rate: u32,
+    /// Fee curve shape
+    pub curve: FeeCurve,
+    /// Volume fee tiers
+    pub fee_tiers: FeeTierTable,
+    /// Earliest execution time
+    pub eta: i64,
+    /// Timestamp
+    pub timestamp: i64,
+}
+
+/// Pending fee rates cancelled event
+#[event]
+pub struct FeeRatesProposalCancelled {
+    /// Fee admin who cancelled
+    pub cancelled_by: Pubkey,
+    /// Timestamp
+    pub timestamp: i64,
+}
+
+/// Market parameters proposal queued event (applies at `eta` via execute_market_params)
+#[event]
+pub struct MarketParamsProposed {
+    pub market: Pubkey,
+    /// Market moderator who proposed
+    pub proposed_by: Pubkey,
+    /// Termination probability (scaled by 10^6, e.g., 1000 = 0.1%)
+    pub termination_probability: u32,
+    /// Earliest execution time
+    pub eta: i64,
+    /// Timestamp
+    pub timestamp: i64,
+}
+
+/// Pending market parameters cancelled event
+#[event]
+pub struct MarketParamsProposalCancelled {
+    pub market: Pubkey,
+    /// Market moderator who cancelled
+    pub cancelled_by: Pubkey,
+    /// Timestamp
+    pub timestamp: i64,
+}
+
+/// Governance timelock updated event
+#[event]
+pub struct GovernanceDelayUpdated {
+    /// New delay (seconds)
+    pub delay: i64,
+    /// Admin who updated the delay (executor for a queued decrease)
+    pub updated_by: Pubkey,
+    /// Timestamp
+    pub timestamp: i64,
+}
+
+/// Governance timelock decrease queued event (applies at `eta` via execute_governance_delay)
+#[event]
+pub struct GovernanceDelayProposed {
+    /// Proposed delay (seconds)
+    pub delay: i64,
+    /// Earliest execution time
+    pub eta: i64,
+    /// Admin who proposed
+    pub proposed_by: Pubkey,
+    /// Timestamp
+    pub timestamp: i64,
+}
+
+/// Market fee override proposal queued event (applies at `eta` via execute_market_fee_override)
+#[event]
+pub struct MarketFeeOverrideProposed {
+    pub market: Pubkey,
+    /// Fee admin or market creator who proposed
+    pub proposed_by: Pubkey,
+    /// Proposed override (None reverts to the global rates)
+    pub fee_override: Option<FeeRates>,
+    /// Earliest execution time
+    pub eta: i64,
+    /// Timestamp
+    pub timestamp: i64,
+}
+
+/// Market fee override applied event
+#[event]
+pub struct MarketFeeOverrideUpdated {
+    pub market: Pubkey,
+    /// Executor of the queued change
+    pub updated_by: Pubkey,
+    /// Active override (None = global rates)
+    pub fee_override: Option<FeeRates>,
+    /// Timestamp
+    pub timestamp: i64,
+}
+
+/// Pending market fee override cancelled event
+#[event]
+pub struct MarketFeeOverrideProposalCancelled {
+    pub market: Pubkey,
+    /// Fee admin or market creator who cancelled
+    pub cancelled_by: Pubkey,
+    /// Timestamp
+    pub timestamp: i64,
+}
+
+/// User fee tier changed event (rolling 30-day taker volume crossed a threshold)
+#[event]
+pub struct UserFeeTierChanged {
+    pub user: Pubkey,
+    /// Previous tier (0 = base tier)
+    pub old_tier: u8,
+    /// New tier
+    pub new_tier: u8,
+    /// Rolling 30-day taker notional after the fill
+    pub rolling_volume: u64,
+    /// Timestamp
+    pub timestamp: i64,
+}
+
+/// Referrer registered event
+#[event]
+pub struct ReferralRegistered {
+    pub user: Pubkey,
+    pub referrer: Pubkey,
+    /// Timestamp
+    pub timestamp: i64,
+}
+
+/// Referral fee accrued event (one per fill with a referred fee payer)
+#[event]
+pub struct ReferralFeeAccrued {
+    pub referrer: Pubkey,
+    /// Referred user who paid the fee
+    pub user: Pubkey,
+    pub market: Pubkey,
+    /// Fee charged to the user
+    pub fee: u64,
+    /// Referrer's share (out of the platform fee)
+    pub referral_fee: u64,
+    /// Referrer's unclaimed total after this accrual
+    pub accrued: u64,
+    /// Timestamp
+    pub timestamp: i64,
+}
+
+/// Referral fees claimed event
+#[event]
+pub struct ReferralFeesClaimed {
+    pub referrer: Pubkey,
+    /// Recipient USDC token account
+    pub recipient: Pubkey,
+    /// Amount paid from the platform treasury
+    pub amount: u64,
+    /// Lifetime claimed after this payout
+    pub total_claimed: u64,
+    /// Timestamp
+    pub timestamp: i64,
+}
+
+/// Referral share rate updated event
+#[event]
+pub struct ReferralShareRateUpdated {
+    /// New share of the platform fee (scaled by 10^6)
+    pub referral_share_rate: u32,
+    /// Fee admin who updated
+    pub updated_by: Pubkey,
+    /// Timestamp
+    pub timestamp: i64,
+}
+
+/// Market creation fee configuration updated event
+#[event]
+pub struct CreationFeeConfigUpdated {
+    /// Creation fee (USDC lamports)
+    pub creation_fee: u64,
+    /// Volume that makes a USDC creation fee refundable (0 = no refunds)
+    pub refund_volume: u64,
+    /// Alternative fee token (Pubkey::default() if disabled)
+    pub alt_fee_mint: Pubkey,
+    /// Token account receiving alternative-token fees
+    pub alt_fee_recipient: Pubkey,
+    /// Alternative-token base units per USDC lamport (scaled by 10^6)
+    pub alt_fee_rate: u64,
+    /// Fee admin who updated
+    pub updated_by: Pubkey,
+    /// Timestamp
+    pub timestamp: i64,
+}
+
+/// Market creation fee refunded event
+#[event]
+pub struct CreationFeeRefunded {
+    pub market: Pubkey,
+    pub creator: Pubkey,
+    /// Refunded amount (USDC lamports)
+    pub amount: u64,
+    /// Market volume at refund time
+    pub total_volume: u64,
+    /// Refund-eligible market volume at refund time
+    pub refund_eligible_volume: u64,
+    /// Timestamp
+    pub timestamp: i64,
+}
+
+/// Account rewritten in the current layout by a migrate_* instruction
+#[event]
+pub struct AccountMigrated {
+    /// Migrated account (Global or a Market)
+    pub account: Pubkey,
+    /// Layout version before the migration
+    pub from_version: u8,
+    /// Layout version after the migration
+    pub to_version: u8,
+    /// Who paid the extra rent
+    pub payer: Pubkey,
+    /// Timestamp
+    pub timestamp: i64,
+}
@@ -0,0 +1,247 @@
+//! Authority Transfer Instructions
+//!
+//! Two-step rotation of `Global::authority`:
+//! 1. the current authority proposes a new authority (optionally timelocked
+//!    by `authority_transfer_delay`)
+//! 2. the proposed authority signs `accept_authority` after the timelock
+//!
+//! Requiring the new key to sign prevents transfers to a mistyped address,
+//! and since only a signature is needed, the new authority can be a multisig
+//! vault signing via CPI. Admin-gated instructions read `global.authority`, so
+//! they follow the new authority without changes.
+//!
+//! Raising `authority_transfer_delay` applies immediately. A decrease is
+//! itself timelocked at the current delay and applied by the permissionless
+//! `execute_authority_transfer_delay`, so the timelock cannot be bypassed by
+//! lowering it first. Setting the delay again drops a queued decrease.
+
+use anchor_lang::prelude::*;
+use crate::constants::GLOBAL_SEED;
+use crate::errors::TerminatorError;
+use crate::events::{
+    AuthorityProposed, AuthorityTransferred, AuthorityTransferDelayProposed, AuthorityTransferDelayUpdated,
+};
+use crate::states::{Global, MAX_AUTHORITY_TRANSFER_DELAY};
+
+// ============================================
+// Propose Authority
+// ============================================
+
+#[derive(Accounts)]
+pub struct ProposeAuthority<'info> {
+    /// Current authority
+    #[account(
+        constraint = admin.key() == global.authority @ TerminatorError::NotAdmin
+    )]
+    pub admin: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [GLOBAL_SEED.as_bytes()],
+        bump = global.bump,
+    )]
+    pub global: Account<'info, Global>,
+}
+
+/// Parameters for propose_authority instruction
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug)]
+pub struct ProposeAuthorityParams {
+    /// New authority; Pubkey::default() cancels a pending proposal
+    pub new_authority: Pubkey,
+}
+
+pub fn handler_propose_authority(
+    ctx: Context<ProposeAuthority>,
+    params: ProposeAuthorityParams,
+) -> Result<()> {
+    let clock = Clock::get()?;
+    let global = &mut ctx.accounts.global;
+
+    require!(params.new_authority != global.authority, TerminatorError::InvalidInput);
+
+    let eta = if params.new_authority == Pubkey::default() {
+        0
+    } else {
+        clock.unix_timestamp
+            .checked_add(global.authority_transfer_delay)
+            .ok_or(TerminatorError::ArithmeticOverflow)?
+    };
+    global.pending_authority = params.new_authority;
+    global.pending_authority_eta = eta;
+
+    emit!(AuthorityProposed {
+        authority: global.authority,
+        pending_authority: params.new_authority,
+        eta,
+        timestamp: clock.unix_timestamp,
+    });
+
+    msg!("Authority proposed: {} (eta {})", params.new_authority, eta);
+
+    Ok(())
+}
+
+// ============================================
+// Accept Authority
+// ============================================
+
+#[derive(Accounts)]
+pub struct AcceptAuthority<'info> {
+    /// Proposed authority
+    #[account(
+        constraint = global.pending_authority != Pubkey::default() @ TerminatorError::NoPendingAuthority,
+        constraint = new_authority.key() == global.pending_authority @ TerminatorError::NotPendingAuthority,
+    )]
+    pub new_authority: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [GLOBAL_SEED.as_bytes()],
+        bump = global.bump,
+    )]
+    pub global: Account<'info, Global>,
+}
+
+pub fn handler_accept_authority(ctx: Context<AcceptAuthority>) -> Result<()> {
+    let clock = Clock::get()?;
+    let global = &mut ctx.accounts.global;
+
+    require!(
+        clock.unix_timestamp >= global.pending_authority_eta,
+        TerminatorError::AuthorityTimelockActive
+    );
+
+    let previous_authority = global.authority;
+    global.authority = global.pending_authority;
+    global.pending_authority = Pubkey::default();
+    global.pending_authority_eta = 0;
+
+    emit!(AuthorityTransferred {
+        previous_authority,
+        new_authority: global.authority,
+        timestamp: clock.unix_timestamp,
+    });
+
+    msg!("Authority transferred: {} -> {}", previous_authority, global.authority);
+
+    Ok(())
+}
+
+// ============================================
+// Set Authority Transfer Delay
+// ============================================
+
+#[derive(Accounts)]
+pub struct SetAuthorityTransferDelay<'info> {
+    /// Current authority
+    #[account(
+        constraint = admin.key() == global.authority @ TerminatorError::NotAdmin
+    )]
+    pub admin: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [GLOBAL_SEED.as_bytes()],
+        bump = global.bump,
+    )]
+    pub global: Account<'info, Global>,
+}
+
+/// Parameters for set_authority_transfer_delay instruction
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug)]
+pub struct SetAuthorityTransferDelayParams {
+    /// Timelock for future proposals (seconds, 0..=MAX_AUTHORITY_TRANSFER_DELAY)
+    pub delay: i64,
+}
+
+/// The new delay applies to future proposals; a pending proposal keeps its eta.
+/// Increases apply immediately, decreases are queued at the current delay.
+pub fn handler_set_authority_transfer_delay(
+    ctx: Context<SetAuthorityTransferDelay>,
+    params: SetAuthorityTransferDelayParams,
+) -> Result<()> {
+    require!(
+        (0..=MAX_AUTHORITY_TRANSFER_DELAY).contains(&params.delay),
+        TerminatorError::InvalidInput
+    );
+
+    let clock = Clock::get()?;
+    let global = &mut ctx.accounts.global;
+
+    // Any new setting replaces a queued decrease
+    global.pending_authority_transfer_delay = None;
+    global.pending_authority_transfer_delay_eta = 0;
+
+    if params.delay >= global.authority_transfer_delay {
+        global.authority_transfer_delay = params.delay;
+
+        emit!(AuthorityTransferDelayUpdated {
+            delay: params.delay,
+            updated_by: ctx.accounts.admin.key(),
+            timestamp: clock.unix_timestamp,
+        });
+
+        msg!("Authority transfer delay set to {}s", params.delay);
+    } else {
+        let eta = clock.unix_timestamp
+            .checked_add(global.authority_transfer_delay)
+            .ok_or(TerminatorError::ArithmeticOverflow)?;
+        global.pending_authority_transfer_delay = Some(params.delay);
+        global.pending_authority_transfer_delay_eta = eta;
+
+        emit!(AuthorityTransferDelayProposed {
+            delay: params.delay,
+            eta,
+            proposed_by: ctx.accounts.admin.key(),
+            timestamp: clock.unix_timestamp,
+        });
+
+        msg!("Authority transfer delay decrease to {}s queued until {}", params.delay, eta);
+    }
+
+    Ok(())
+}
+
+// ============================================
+// Execute Authority Transfer Delay (permissionless)
+// ============================================
+
+#[derive(Accounts)]
+pub struct ExecuteAuthorityTransferDelay<'info> {
+    /// Anyone can execute a queued decrease once its timelock has passed
+    pub executor: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [GLOBAL_SEED.as_bytes()],
+        bump = global.bump,
+        constraint = global.pending_authority_transfer_delay.is_some() @ TerminatorError::NoPendingProposal,
+    )]
+    pub global: Account<'info, Global>,
+}
+
+pub fn handler_execute_authority_transfer_delay(ctx: Context<ExecuteAuthorityTransferDelay>) -> Result<()> {
+    let global = &mut ctx.accounts.global;
+    let clock = Clock::get()?;
+
+    require!(
+        clock.unix_timestamp >= global.pending_authority_transfer_delay_eta,
+        TerminatorError::AuthorityTimelockActive
+    );
+
+    let delay = global.pending_authority_transfer_delay
+        .take()
+        .ok_or(TerminatorError::NoPendingProposal)?;
+    global.authority_transfer_delay = delay;
+    global.pending_authority_transfer_delay_eta = 0;
+
+    emit!(AuthorityTransferDelayUpdated {
+        delay,
+        updated_by: ctx.accounts.executor.key(),
+        timestamp: clock.unix_timestamp,
+    });
+
+    msg!("Authority transfer delay set to {}s", delay);
+
+    Ok(())
+}
@@ -7,7 +7,7 @@
 
 use anchor_lang::prelude::*;
 use crate::constants::PRICE_SCALE;
-use crate::states::{Order, Global, MAX_FEE_RATE_BPS};
+use crate::states::{Order, FeeRates, Global, Market, MarketNonce, CancelBitmap, MAX_FEE_RATE_BPS, trigger_direction};
 
 /// Basis points divisor (100% = 10000 bps)
 pub const BPS_DIVISOR: u64 = 10_000;
@@ -98,22 +98,8 @@ pub fn calculate_fee(
     Ok(fee as u64)
 }
 
-/// Calculate fee using global fee configuration (dynamic fee curve)
-/// 
-/// Uses the Global account's fee configuration to calculate fees
-/// based on price distance from 50%
-pub fn calculate_fee_with_global(
-    global: &Global,
-    proceeds: u64,
-    price: u64,
-) -> Result<u64> {
-    if proceeds == 0 {
-        return Ok(0);
-    }
-    
-    // Get dynamic fee rate based on price
-    let fee_rate = global.calculate_taker_fee_rate(price);
-    
+/// Calculate fee on proceeds at a fee curve rate (scaled by 10^6)
+fn calculate_curve_fee(fee_rate: u32, proceeds: u64) -> Result<u64> {
     // Fee = fee_rate * proceeds / PRICE_SCALE (since fee_rate is scaled by 10^6)
     let fee = (fee_rate as u128)
         .checked_mul(proceeds as u128)
@@ -124,42 +110,123 @@ pub fn calculate_fee_with_global(
     Ok(fee as u64)
 }
 
-/// Distribute fee according to global configuration
+/// Calculate the fee for filling an order in a market
+/// 
+/// The order's signed fee (`calculate_fee`) is the most the maker agreed to pay.
+/// In a market with a fee override, the override's curve rate is charged on the
+/// proceeds instead: lower-fee markets (e.g. zero in promotional markets) charge
+/// less than signed, and orders whose signed fee does not cover a higher-fee
+/// override are rejected, so makers must sign at least the market's fee.
+pub fn calculate_order_fee(
+    order: &Order,
+    proceeds: u64,
+    fee_override: Option<&FeeRates>,
+) -> Result<u64> {
+    let signed_fee = calculate_fee(
+        order.fee_rate_bps,
+        proceeds,
+        order.maker_amount,
+        order.taker_amount,
+        order.side,
+    )?;
+    
+    match fee_override {
+        Some(rates) => {
+            let override_fee = calculate_curve_fee(rates.taker_fee_rate(order.calculate_price()), proceeds)?;
+            require!(
+                override_fee <= signed_fee,
+                crate::errors::TerminatorError::OrderFeeBelowMarketFee
+            );
+            Ok(override_fee)
+        }
+        None => Ok(signed_fee),
+    }
+}
+
+/// Apply a volume tier discount (scaled by 10^6) to a fee
 /// 
-/// Returns (platform_fee, maker_rebate, creator_incentive)
+/// The discount comes on top of the dynamic curve and any market override:
+/// the fee is computed as usual, then reduced by the taker's tier discount.
+pub fn apply_tier_discount(fee: u64, discount_rate: u32) -> Result<u64> {
+    let discount = (fee as u128)
+        .checked_mul(discount_rate.min(crate::states::MAX_TIER_DISCOUNT_RATE) as u128)
+        .ok_or(crate::errors::TerminatorError::ArithmeticOverflow)?
+        .checked_div(PRICE_SCALE as u128)
+        .ok_or(crate::errors::TerminatorError::ArithmeticOverflow)?;
+    
+    Ok(fee - discount as u64)
+}
+
+/// Distribute fee according to the market's fee configuration
+/// 
+/// Uses the market's fee override split if set, otherwise the global split.
+/// For a referred user, `Global::referral_share_rate` of the platform fee goes
+/// to the referrer instead.
+/// Returns (platform_fee, maker_rebate, creator_incentive, referral_fee)
 pub fn distribute_fee(
     global: &Global,
+    fee_override: Option<&FeeRates>,
     total_fee: u64,
-) -> Result<(u64, u64, u64)> {
+    referred: bool,
+) -> Result<(u64, u64, u64, u64)> {
+    let rates = global.effective_fee_rates(fee_override);
+    
     // Platform fee
     let platform_fee = (total_fee as u128)
-        .checked_mul(global.platform_fee_rate as u128)
+        .checked_mul(rates.platform_fee_rate as u128)
         .ok_or(crate::errors::TerminatorError::ArithmeticOverflow)?
         .checked_div(PRICE_SCALE as u128)
         .ok_or(crate::errors::TerminatorError::ArithmeticOverflow)? as u64;
     
     // Maker rebate
     let maker_rebate = (total_fee as u128)
-        .checked_mul(global.maker_rebate_rate as u128)
+        .checked_mul(rates.maker_rebate_rate as u128)
         .ok_or(crate::errors::TerminatorError::ArithmeticOverflow)?
         .checked_div(PRICE_SCALE as u128)
         .ok_or(crate::errors::TerminatorError::ArithmeticOverflow)? as u64;
     
     // Creator incentive
     let creator_incentive = (total_fee as u128)
-        .checked_mul(global.creator_incentive_rate as u128)
+        .checked_mul(rates.creator_incentive_rate as u128)
         .ok_or(crate::errors::TerminatorError::ArithmeticOverflow)?
         .checked_div(PRICE_SCALE as u128)
         .ok_or(crate::errors::TerminatorError::ArithmeticOverflow)? as u64;
     
-    Ok((platform_fee, maker_rebate, creator_incentive))
+    // Referral fee (out of the platform's cut)
+    let referral_fee = if referred {
+        (platform_fee as u128)
+            .checked_mul(global.referral_share_rate as u128)
+            .ok_or(crate::errors::TerminatorError::ArithmeticOverflow)?
+            .checked_div(PRICE_SCALE as u128)
+            .ok_or(crate::errors::TerminatorError::ArithmeticOverflow)? as u64
+    } else {
+        0
+    };
+    
+    Ok((platform_fee - referral_fee, maker_rebate, creator_incentive, referral_fee))
+}
+
+/// Cancellation state of an order's maker
+#[derive(Clone, Copy, Default)]
+pub struct CancellationScope<'a> {
+    /// Global nonce (UserNonce)
+    pub user_nonce: u64,
+    /// Market nonce, salt ranges and hashes (None if not initialized)
+    pub market_nonce: Option<&'a MarketNonce>,
+    /// Salt bitmap page for the order's salt (None if not initialized)
+    pub cancel_bitmap: Option<&'a CancelBitmap>,
 }
 
 /// Validate order against common checks
+/// 
+/// Cancellation scope: the order must pass the user's global nonce, the
+/// maker's MarketNonce for this market (market nonce, cancelled salt ranges,
+/// cancelled order hashes) and the maker's salt cancellation bitmap.
 pub fn validate_order(
     order: &Order,
+    order_hash: &[u8; 32],
     current_timestamp: i64,
-    user_nonce: u64,
+    scope: &CancellationScope,
 ) -> Result<()> {
     // Check expiration
     require!(
@@ -169,10 +236,26 @@ pub fn validate_order(
     
     // Check nonce
     require!(
-        order.nonce >= user_nonce,
+        order.nonce >= scope.user_nonce,
         crate::errors::TerminatorError::InvalidNonce
     );
     
+    // Check market-scoped cancellation
+    if let Some(market_nonce) = scope.market_nonce {
+        require!(
+            market_nonce.is_order_valid(order, order_hash),
+            crate::errors::TerminatorError::OrderCancelledInMarket
+        );
+    }
+    
+    // Check salt cancellation bitmap
+    if let Some(cancel_bitmap) = scope.cancel_bitmap {
+        require!(
+            !cancel_bitmap.is_cancelled(order.salt),
+            crate::errors::TerminatorError::OrderAlreadyCancelledOrFilled
+        );
+    }
+    
     // Check fee rate
     require!(
         order.fee_rate_bps <= MAX_FEE_RATE_BPS,
@@ -191,6 +274,39 @@ pub fn validate_order(
         crate::errors::TerminatorError::InvalidAmount
     );
     
+    // Check stop trigger is well-formed
+    if order.is_stop() {
+        require!(
+            order.trigger_direction <= trigger_direction::BELOW
+                && order.trigger_price > 0
+                && order.trigger_price <= PRICE_SCALE,
+            crate::errors::TerminatorError::InvalidStopTrigger
+        );
+    } else {
+        require!(
+            order.trigger_price == 0,
+            crate::errors::TerminatorError::InvalidStopTrigger
+        );
+    }
+    
+    Ok(())
+}
+
+/// Validate that a stop order's trigger condition has been met
+/// 
+/// Uses the market's recorded last trade price for the order's outcome token,
+/// so a stop cannot execute before some fill has crossed the trigger.
+/// 
+/// Trust assumption: the last price is set by any fill, whatever its size,
+/// and the operator sequences fills. An operator can therefore trigger a stop
+/// with a small fill at the trigger price (e.g. against its own inventory).
+/// Makers signing stop orders rely on the operator not to do so, just as they
+/// rely on it for fill ordering in general.
+pub fn validate_stop_trigger(order: &Order, market: &Market) -> Result<()> {
+    require!(
+        order.is_triggered(market.last_price_for_token(order.token_id)),
+        crate::errors::TerminatorError::StopNotTriggered
+    );
     Ok(())
 }
 
@@ -205,6 +321,7 @@ pub fn validate_taker(order: &Order, taker: &Pubkey) -> Result<()> {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::states::FeeCurve;
     
     #[test]
     fn test_calculate_taking_amount() {
@@ -230,4 +347,71 @@ mod tests {
         let fee = calculate_fee(0, 1_000_000, 500_000, 1_000_000, 0).unwrap();
         assert_eq!(fee, 0);
     }
+    
+    #[test]
+    fn test_calculate_order_fee_with_override() {
+        use crate::states::{side, token_id, trigger_direction};
+        
+        // SELL 1_000_000 tokens for 500_000 USDC (price 0.5) at 100 bps
+        let order = Order {
+            salt: 1,
+            maker: Pubkey::new_unique(),
+            signer: Pubkey::new_unique(),
+            taker: Pubkey::default(),
+            market: Pubkey::new_unique(),
+            token_id: token_id::YES,
+            maker_amount: 1_000_000,
+            taker_amount: 500_000,
+            expiration: 0,
+            nonce: 0,
+            fee_rate_bps: 100,
+            side: side::SELL,
+            trigger_price: 0,
+            trigger_direction: trigger_direction::NONE,
+        };
+        let rates = FeeRates {
+            center_taker_fee_rate: 2_000,
+            extreme_taker_fee_rate: 0,
+            platform_fee_rate: 750_000,
+            maker_rebate_rate: 200_000,
+            creator_incentive_rate: 50_000,
+            curve: FeeCurve::default(),
+        };
+        
+        // Signed fee: 100 bps * 0.5 * 500_000 = 2_500
+        assert_eq!(calculate_order_fee(&order, 500_000, None).unwrap(), 2_500);
+        // Override curve: 0.2% * 500_000 = 1_000
+        assert_eq!(calculate_order_fee(&order, 500_000, Some(&rates)).unwrap(), 1_000);
+        // Override above the signed fee rejects the under-signed order
+        let high = FeeRates { center_taker_fee_rate: 40_000, ..rates };
+        assert_eq!(
+            calculate_order_fee(&order, 500_000, Some(&high)).unwrap_err(),
+            error!(crate::errors::TerminatorError::OrderFeeBelowMarketFee)
+        );
+        // ... and charges the full override to an order signed high enough
+        let exotic = Order { fee_rate_bps: 1_000, ..order.clone() };
+        assert_eq!(calculate_order_fee(&exotic, 500_000, Some(&high)).unwrap(), 20_000);
+        // Zero-fee promotion
+        let promo = FeeRates { center_taker_fee_rate: 0, ..rates };
+        assert_eq!(calculate_order_fee(&order, 500_000, Some(&promo)).unwrap(), 0);
+    }
+    
+    #[test]
+    fn test_apply_tier_discount() {
+        assert_eq!(apply_tier_discount(2_500, 0).unwrap(), 2_500);
+        // 10% off (the discount rounds down)
+        assert_eq!(apply_tier_discount(2_500, 100_000).unwrap(), 2_250);
+        assert_eq!(apply_tier_discount(2_501, 100_000).unwrap(), 2_251);
+        assert_eq!(apply_tier_discount(2_500, 1_000_000).unwrap(), 0);
+    }
+    
+    #[test]
+    fn test_distribute_fee_with_referral() {
+        let global = crate::test_utils::global(Pubkey::new_unique());
+        
+        // 75% platform / 20% maker / 5% creator
+        assert_eq!(distribute_fee(&global, None, 10_000, false).unwrap(), (7_500, 2_000, 500, 0));
+        // Referrer takes 10% of the platform's cut
+        assert_eq!(distribute_fee(&global, None, 10_000, true).unwrap(), (6_750, 2_000, 500, 750));
+    }
 }
@@ -0,0 +1,88 @@
+//! Cancel Market Orders Instruction
+//! 
+//! Per-market cancellation for a user in one call:
+//! - raise the (user, market) nonce, cancelling every order with a lower nonce
+//!   in this market only
+//! - cancel inclusive salt ranges
+//! - cancel a list of order hashes
+//! 
+//! Unlike increment_nonce, orders in other markets are not affected.
+
+use anchor_lang::prelude::*;
+use crate::errors::TerminatorError;
+use crate::events::MarketOrdersCancelled;
+use crate::states::{Market, MarketNonce, SaltRange};
+
+/// Parameters for cancel_market_orders instruction
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug)]
+pub struct CancelMarketOrdersParams {
+    /// New market nonce (must exceed the current one), if any
+    pub new_nonce: Option<u64>,
+    /// Inclusive salt ranges to cancel
+    pub salt_ranges: Vec<SaltRange>,
+    /// Order hashes to cancel
+    pub order_hashes: Vec<[u8; 32]>,
+}
+
+#[derive(Accounts)]
+pub struct CancelMarketOrders<'info> {
+    /// User cancelling their orders
+    #[account(mut)]
+    pub user: Signer<'info>,
+
+    pub market: Box<Account<'info, Market>>,
+
+    /// User's nonce account for this market
+    #[account(
+        init_if_needed,
+        payer = user,
+        space = MarketNonce::INIT_SPACE,
+        seeds = [MarketNonce::SEED_PREFIX, user.key().as_ref(), market.key().as_ref()],
+        bump,
+    )]
+    pub market_nonce: Box<Account<'info, MarketNonce>>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn handler(ctx: Context<CancelMarketOrders>, params: CancelMarketOrdersParams) -> Result<()> {
+    let clock = Clock::get()?;
+    let market_nonce = &mut ctx.accounts.market_nonce;
+    
+    require!(
+        params.new_nonce.is_some() || !params.salt_ranges.is_empty() || !params.order_hashes.is_empty(),
+        TerminatorError::InvalidInput
+    );
+    
+    // Initialize if new account
+    if market_nonce.user == Pubkey::default() {
+        market_nonce.user = ctx.accounts.user.key();
+        market_nonce.market = ctx.accounts.market.key();
+        market_nonce.current_nonce = 0;
+        market_nonce.bump = ctx.bumps.market_nonce;
+    }
+    
+    if let Some(new_nonce) = params.new_nonce {
+        market_nonce.set_nonce(new_nonce)?;
+    }
+    for range in params.salt_ranges.iter() {
+        market_nonce.cancel_salt_range(*range)?;
+    }
+    for order_hash in params.order_hashes.iter() {
+        market_nonce.cancel_order_hash(*order_hash)?;
+    }
+    
+    emit!(MarketOrdersCancelled {
+        user: market_nonce.user,
+        market: market_nonce.market,
+        current_nonce: market_nonce.current_nonce,
+        salt_ranges: params.salt_ranges,
+        order_hashes: params.order_hashes,
+        slot: clock.slot,
+        timestamp: clock.unix_timestamp,
+    });
+    
+    msg!("Market orders cancelled, market nonce {}", market_nonce.current_nonce);
+    
+    Ok(())
+}
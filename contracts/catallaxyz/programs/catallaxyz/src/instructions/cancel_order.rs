@@ -34,7 +34,7 @@ pub struct CancelOrder<'info> {
         init_if_needed,
         payer = maker,
         space = OrderStatus::INIT_SPACE,
-        seeds = [OrderStatus::SEED_PREFIX, &hash_order(&params.order)],
+        seeds = [OrderStatus::SEED_PREFIX, &hash_order(&params.order, &global.order_domain())],
         bump,
     )]
     pub order_status: Box<Account<'info, OrderStatus>>,
@@ -52,12 +52,12 @@ pub fn handler(ctx: Context<CancelOrder>, params: CancelOrderParams) -> Result<(
         TerminatorError::NotOrderMaker
     );
     
-    let order_hash = hash_order(order);
+    let order_hash = hash_order(order, &ctx.accounts.global.order_domain());
     let order_status = &mut ctx.accounts.order_status;
     
     // Initialize if new
     if order_status.order_hash == [0u8; 32] {
-        order_status.init(order_hash, order.maker_amount, ctx.bumps.order_status);
+        order_status.init(order_hash, order.maker_amount, ctx.accounts.maker.key(), ctx.bumps.order_status);
     } else {
         // Verify hash matches
         require!(
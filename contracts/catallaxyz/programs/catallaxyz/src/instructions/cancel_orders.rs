@@ -0,0 +1,84 @@
+//! Cancel Orders Instruction (batch)
+//!
+//! Cancels many orders of the caller at once by setting their salts in the
+//! caller's CancelBitmap. Every fill path checks the bitmap page for the
+//! order's salt, so a cancelled salt can never be filled again, in any market.
+//!
+//! Unlike cancel_order, no OrderStatus account is created per order: cancelling
+//! hundreds of sequential-salt quotes costs one page write (and at most one
+//! page creation the first time the page is used).
+//!
+//! Remaining accounts: one CancelBitmap page PDA per distinct page touched by
+//! `salts`, in ascending page order. Missing pages are created and paid for by
+//! the user.
+
+use anchor_lang::prelude::*;
+use crate::errors::TerminatorError;
+use crate::events::OrdersCancelledBySalt;
+use crate::states::CancelBitmap;
+use crate::instructions::order_accounts::load_or_init_cancel_bitmap;
+
+/// Maximum number of salts cancelled in a single instruction
+pub const MAX_CANCEL_SALTS: usize = 256;
+
+/// Parameters for cancel_orders instruction
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug)]
+pub struct CancelOrdersParams {
+    /// Salts of the orders to cancel
+    pub salts: Vec<u64>,
+}
+
+#[derive(Accounts)]
+pub struct CancelOrders<'info> {
+    /// User cancelling their orders (pays for new bitmap pages)
+    #[account(mut)]
+    pub user: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+    // Remaining accounts: CancelBitmap pages (ascending page order)
+}
+
+pub fn handler<'info>(
+    ctx: Context<'_, '_, 'info, 'info, CancelOrders<'info>>,
+    params: CancelOrdersParams,
+) -> Result<()> {
+    let clock = Clock::get()?;
+    let user = ctx.accounts.user.key();
+
+    require!(
+        !params.salts.is_empty() && params.salts.len() <= MAX_CANCEL_SALTS,
+        TerminatorError::InvalidInput
+    );
+
+    // Distinct pages touched, one remaining account each
+    let mut pages: Vec<u64> = params.salts.iter().map(|salt| CancelBitmap::page_of(*salt)).collect();
+    pages.sort_unstable();
+    pages.dedup();
+    require!(
+        ctx.remaining_accounts.len() == pages.len(),
+        TerminatorError::InvalidAccountInput
+    );
+
+    let user_info = ctx.accounts.user.to_account_info();
+    let system_program_info = ctx.accounts.system_program.to_account_info();
+
+    for (page, info) in pages.iter().zip(ctx.remaining_accounts.iter()) {
+        let mut bitmap = load_or_init_cancel_bitmap(info, &user, *page, &user_info, &system_program_info)?;
+        for salt in params.salts.iter().filter(|salt| CancelBitmap::page_of(**salt) == *page) {
+            bitmap.cancel(*salt)?;
+        }
+        let mut data = info.try_borrow_mut_data()?;
+        bitmap.try_serialize(&mut &mut data[..])?;
+    }
+
+    emit!(OrdersCancelledBySalt {
+        user,
+        salts: params.salts.clone(),
+        slot: clock.slot,
+        timestamp: clock.unix_timestamp,
+    });
+
+    msg!("Cancelled {} orders across {} bitmap pages", params.salts.len(), pages.len());
+
+    Ok(())
+}
@@ -0,0 +1,182 @@
+//! Close Market Instruction
+//!
+//! Final lifecycle step after settlement or termination. Once every YES/NO
+//! position has been redeemed and the vault is empty, or
+//! `MARKET_CLOSE_GRACE_PERIOD_SECONDS` have passed since the market stopped
+//! trading:
+//! 1. any USDC left in `market_vault` is swept to the platform treasury. The
+//!    vault also backs users' idle `UserBalance` USDC, which cannot be
+//!    withdrawn once the market is gone, so the sweep (and with it any idle
+//!    balance or unredeemed position) is only possible after the grace period
+//! 2. the vault token account and the market account are closed, returning
+//!    their rent to the market creator
+//! 3. a `MarketTombstone` is written for the market address, so the creator
+//!    can never re-create it with the same `market_id` (the market's user
+//!    balances, positions and order statuses may outlive it)
+//! 4. `MarketClosed` is emitted with the final stats for indexers
+//!
+//! Callable by the market creator or the keeper (who pays for the tombstone).
+
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::{
+    self as token_interface, CloseAccount, Mint, TokenAccount, TokenInterface, TransferChecked,
+};
+use crate::constants::{
+    GLOBAL_SEED, MARKET_CLOSE_GRACE_PERIOD_SECONDS, MARKET_SEED, PLATFORM_TREASURY_SEED, USDC_DECIMALS,
+};
+use crate::errors::TerminatorError;
+use crate::events::MarketClosed;
+use crate::states::{role, Global, Market, MarketTombstone, RoleAssignment};
+
+#[derive(Accounts)]
+pub struct CloseMarket<'info> {
+    /// Market creator or keeper (pays for the tombstone)
+    #[account(mut)]
+    pub caller: Signer<'info>,
+
+    #[account(
+        seeds = [GLOBAL_SEED.as_bytes()],
+        bump = global.bump,
+        constraint = caller.key() == market.creator
+            || global.has_role(&caller.key(), keeper_role.as_deref(), role::KEEPER)
+            @ TerminatorError::Unauthorized,
+    )]
+    pub global: Box<Account<'info, Global>>,
+
+    /// Signer's keeper role assignment (omitted when the signer is the authority)
+    #[account(
+        seeds = [RoleAssignment::SEED_PREFIX, &[role::KEEPER], caller.key().as_ref()],
+        bump = keeper_role.bump,
+    )]
+    pub keeper_role: Option<Account<'info, RoleAssignment>>,
+
+    #[account(
+        mut,
+        close = creator,
+        seeds = [
+            MARKET_SEED.as_bytes(),
+            market.creator.as_ref(),
+            market.market_id.as_ref(),
+        ],
+        bump = market.bump,
+        constraint = market.global == global.key() @ TerminatorError::InvalidAccountInput,
+        constraint = market.can_redeem @ TerminatorError::RedemptionNotAllowed,
+    )]
+    pub market: Box<Account<'info, Market>>,
+
+    /// Permanent record that this market address was closed
+    #[account(
+        init,
+        payer = caller,
+        space = MarketTombstone::INIT_SPACE,
+        seeds = [MarketTombstone::SEED_PREFIX, market.key().as_ref()],
+        bump,
+    )]
+    pub market_tombstone: Box<Account<'info, MarketTombstone>>,
+
+    /// CHECK: market creator, receives the rent of the market and its vault
+    #[account(mut, address = market.creator @ TerminatorError::Unauthorized)]
+    pub creator: UncheckedAccount<'info>,
+
+    #[account(
+        mut,
+        seeds = [b"market_vault", market.key().as_ref()],
+        bump,
+        constraint = market_usdc_vault.mint == global.usdc_mint @ TerminatorError::InvalidTokenMint,
+        constraint = market_usdc_vault.owner == market.key() @ TerminatorError::Unauthorized,
+    )]
+    pub market_usdc_vault: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    /// Platform treasury (receives leftover vault dust)
+    #[account(
+        mut,
+        seeds = [PLATFORM_TREASURY_SEED.as_bytes()],
+        bump = global.platform_treasury_bump,
+    )]
+    pub platform_treasury: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    #[account(address = global.usdc_mint @ TerminatorError::InvalidTokenMint)]
+    pub usdc_mint: Box<InterfaceAccount<'info, Mint>>,
+
+    pub token_program: Interface<'info, TokenInterface>,
+    pub system_program: Program<'info, System>,
+}
+
+pub fn handler(ctx: Context<CloseMarket>) -> Result<()> {
+    let clock = Clock::get()?;
+    let market = &ctx.accounts.market;
+
+    // Nothing left to pay out, or the grace period for late redeemers and
+    // withdrawals has passed (the sweep forfeits idle balances)
+    let fully_redeemed = market.total_yes_supply == 0
+        && market.total_no_supply == 0
+        && ctx.accounts.market_usdc_vault.amount == 0;
+    let grace_elapsed = market.terminated_at.is_some_and(|terminated_at| {
+        clock.unix_timestamp >= terminated_at.saturating_add(MARKET_CLOSE_GRACE_PERIOD_SECONDS)
+    });
+    require!(fully_redeemed || grace_elapsed, TerminatorError::MarketNotClosable);
+
+    let market_seeds = &[
+        MARKET_SEED.as_bytes(),
+        market.creator.as_ref(),
+        market.market_id.as_ref(),
+        &[market.bump],
+    ];
+    let signer_seeds = &[&market_seeds[..]];
+
+    // Sweep what is left to the platform treasury (only after the grace period)
+    let dust_swept = ctx.accounts.market_usdc_vault.amount;
+    if dust_swept > 0 {
+        let transfer_ctx = CpiContext::new_with_signer(
+            ctx.accounts.token_program.to_account_info(),
+            TransferChecked {
+                from: ctx.accounts.market_usdc_vault.to_account_info(),
+                mint: ctx.accounts.usdc_mint.to_account_info(),
+                to: ctx.accounts.platform_treasury.to_account_info(),
+                authority: market.to_account_info(),
+            },
+            signer_seeds,
+        );
+        token_interface::transfer_checked(transfer_ctx, dust_swept, USDC_DECIMALS)?;
+    }
+
+    // Close the vault (rent to creator); the market is closed by Anchor on exit
+    let close_ctx = CpiContext::new_with_signer(
+        ctx.accounts.token_program.to_account_info(),
+        CloseAccount {
+            account: ctx.accounts.market_usdc_vault.to_account_info(),
+            destination: ctx.accounts.creator.to_account_info(),
+            authority: market.to_account_info(),
+        },
+        signer_seeds,
+    );
+    token_interface::close_account(close_ctx)?;
+
+    let tombstone = &mut ctx.accounts.market_tombstone;
+    tombstone.market = market.key();
+    tombstone.closed_at = clock.unix_timestamp;
+    tombstone.bump = ctx.bumps.market_tombstone;
+
+    emit!(MarketClosed {
+        market: market.key(),
+        creator: market.creator,
+        market_id: market.market_id,
+        status: market.status,
+        final_yes_price: market.final_yes_price,
+        final_no_price: market.final_no_price,
+        total_trades: market.total_trades,
+        total_redeemable_usdc: market.total_redeemable_usdc,
+        total_redeemed_usdc: market.total_redeemed_usdc,
+        unredeemed_yes_supply: market.total_yes_supply,
+        unredeemed_no_supply: market.total_no_supply,
+        dust_swept,
+        created_at: market.created_at,
+        terminated_at: market.terminated_at,
+        closed_by: ctx.accounts.caller.key(),
+        timestamp: clock.unix_timestamp,
+    });
+
+    msg!("Market {} closed, {} USDC dust swept", market.key(), dust_swept);
+
+    Ok(())
+}
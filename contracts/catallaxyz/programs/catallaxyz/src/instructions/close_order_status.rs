@@ -0,0 +1,197 @@
+//! Close Order Status Instruction
+//!
+//! Closes an OrderStatus PDA and refunds its rent to the account that paid
+//! for it (the operator for fills, the maker for cancel_order).
+//!
+//! An order status can only be closed once the order is expired, below the
+//! maker's global or market nonce, or cancelled in the salt bitmap. Nonces
+//! only increase and bitmap bits are never cleared, so a new OrderStatus for
+//! the same hash can never be filled.
+//!
+//! A fully filled or cancelled order that is only cancelled through its
+//! market's salt range or order hash lists keeps its status account, as does
+//! any order that would still validate (the status is what stops it being
+//! filled again), until it expires or the maker cancels it by nonce or salt.
+
+use anchor_lang::prelude::*;
+use crate::constants::GLOBAL_SEED;
+use crate::errors::TerminatorError;
+use crate::events::OrderStatusClosed;
+use crate::states::{
+    CancelBitmap, Global, MarketNonce, Order, OrderStatus, UserNonce, hash_order,
+};
+use crate::instructions::calculator::CancellationScope;
+use crate::instructions::order_accounts::{read_cancel_bitmap, read_market_nonce, read_user_nonce};
+
+/// Parameters for close_order_status instruction
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug)]
+pub struct CloseOrderStatusParams {
+    /// The order whose status account is closed
+    pub order: Order,
+}
+
+#[derive(Accounts)]
+#[instruction(params: CloseOrderStatusParams)]
+pub struct CloseOrderStatus<'info> {
+    /// Account that paid for the order status (receives the rent)
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    #[account(
+        seeds = [GLOBAL_SEED.as_bytes()],
+        bump = global.bump,
+    )]
+    pub global: Box<Account<'info, Global>>,
+
+    #[account(
+        mut,
+        close = payer,
+        seeds = [OrderStatus::SEED_PREFIX, &hash_order(&params.order, &global.order_domain())],
+        bump = order_status.bump,
+        constraint = order_status.payer == payer.key() @ TerminatorError::Unauthorized,
+    )]
+    pub order_status: Box<Account<'info, OrderStatus>>,
+
+    /// CHECK: maker's UserNonce (may be uninitialized)
+    #[account(
+        seeds = [UserNonce::SEED_PREFIX, params.order.maker.as_ref()],
+        bump,
+    )]
+    pub maker_nonce: UncheckedAccount<'info>,
+
+    /// CHECK: maker's MarketNonce for the order's market (may be uninitialized)
+    #[account(
+        seeds = [MarketNonce::SEED_PREFIX, params.order.maker.as_ref(), params.order.market.as_ref()],
+        bump,
+    )]
+    pub maker_market_nonce: UncheckedAccount<'info>,
+
+    /// CHECK: maker's CancelBitmap page for the order's salt (may be uninitialized)
+    #[account(
+        seeds = [
+            CancelBitmap::SEED_PREFIX,
+            params.order.maker.as_ref(),
+            &CancelBitmap::page_of(params.order.salt).to_le_bytes(),
+        ],
+        bump,
+    )]
+    pub maker_cancel_bitmap: UncheckedAccount<'info>,
+}
+
+pub fn handler(ctx: Context<CloseOrderStatus>, params: CloseOrderStatusParams) -> Result<()> {
+    let order = &params.order;
+    let clock = Clock::get()?;
+
+    let order_hash = hash_order(order, &ctx.accounts.global.order_domain());
+    let order_status = &ctx.accounts.order_status;
+    require!(
+        order_status.order_hash == order_hash,
+        TerminatorError::OrderHashMismatch
+    );
+
+    // Only an order that can never be filled again may lose its status
+    let maker_nonce = read_user_nonce(&ctx.accounts.maker_nonce)?;
+    let maker_market_nonce = read_market_nonce(&ctx.accounts.maker_market_nonce)?;
+    let maker_cancel_bitmap = read_cancel_bitmap(&ctx.accounts.maker_cancel_bitmap)?;
+    require!(
+        is_permanently_unfillable(
+            order,
+            clock.unix_timestamp,
+            &CancellationScope {
+                user_nonce: maker_nonce.map_or(0, |nonce| nonce.current_nonce),
+                market_nonce: maker_market_nonce.as_ref(),
+                cancel_bitmap: maker_cancel_bitmap.as_ref(),
+            },
+        ),
+        TerminatorError::OrderStatusNotClosable
+    );
+
+    emit!(OrderStatusClosed {
+        order_hash,
+        maker: order.maker,
+        payer: order_status.payer,
+        slot: clock.slot,
+        timestamp: clock.unix_timestamp,
+    });
+
+    msg!("Order status closed");
+
+    Ok(())
+}
+
+/// Whether an order is expired or cancelled by a nonce or the salt bitmap
+///
+/// Salt range and order hash list entries do not count: they are bounded lists
+/// rather than monotonic nonces or bits, so they are not relied on to keep a
+/// closed order from being filled again.
+fn is_permanently_unfillable(order: &Order, now: i64, scope: &CancellationScope) -> bool {
+    order.is_expired(now)
+        || order.nonce < scope.user_nonce
+        || scope.market_nonce.is_some_and(|market_nonce| order.nonce < market_nonce.current_nonce)
+        || scope.cancel_bitmap.is_some_and(|cancel_bitmap| cancel_bitmap.is_cancelled(order.salt))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::states::{
+        side, token_id, trigger_direction, SaltRange, MAX_CANCELLED_ORDER_HASHES,
+        MAX_CANCELLED_SALT_RANGES,
+    };
+
+    #[test]
+    fn test_hash_cancelled_order_status_not_closable() {
+        let maker = Pubkey::new_unique();
+        let market = Pubkey::new_unique();
+        let order = Order {
+            salt: 50,
+            maker,
+            signer: maker,
+            taker: Pubkey::default(),
+            market,
+            token_id: token_id::YES,
+            maker_amount: 1,
+            taker_amount: 1,
+            expiration: 0,
+            nonce: 3,
+            fee_rate_bps: 0,
+            side: side::BUY,
+            trigger_price: 0,
+            trigger_direction: trigger_direction::NONE,
+        };
+        let order_hash = [7u8; 32];
+        let mut market_nonce = MarketNonce {
+            user: maker,
+            market,
+            current_nonce: 0,
+            salt_range_count: 0,
+            salt_ranges: [SaltRange::default(); MAX_CANCELLED_SALT_RANGES],
+            order_hash_count: 0,
+            order_hashes: [[0u8; 32]; MAX_CANCELLED_ORDER_HASHES],
+            bump: 255,
+        };
+
+        // Filled, then cancelled by hash: the status must stay
+        market_nonce.cancel_order_hash(order_hash).unwrap();
+        market_nonce.cancel_salt_range(SaltRange { start: 0, end: 100 }).unwrap();
+        fn scope(market_nonce: &MarketNonce) -> CancellationScope<'_> {
+            CancellationScope { market_nonce: Some(market_nonce), ..CancellationScope::default() }
+        }
+        assert!(!market_nonce.is_order_valid(&order, &order_hash));
+        assert!(!is_permanently_unfillable(&order, 0, &scope(&market_nonce)));
+
+        // Cancelled by the market nonce: closable
+        market_nonce.set_nonce(4).unwrap();
+        assert!(is_permanently_unfillable(&order, 0, &scope(&market_nonce)));
+
+        // Expired or below the global nonce: closable
+        let expiring = Order { expiration: 100, ..order.clone() };
+        assert!(is_permanently_unfillable(&expiring, 101, &CancellationScope::default()));
+        assert!(is_permanently_unfillable(
+            &order,
+            0,
+            &CancellationScope { user_nonce: 4, ..CancellationScope::default() },
+        ));
+        assert!(!is_permanently_unfillable(&order, 0, &CancellationScope::default()));
+    }
+}
@@ -0,0 +1,228 @@
+//! Close User Accounts Instructions
+//!
+//! Reclaims the rent held by a trader's UserBalance and UserPosition for a market:
+//! - close_user_accounts: the user closes both accounts once they are empty and
+//!   the market is settled or terminated (or already closed)
+//! - sweep_user_accounts: once the market is redeemable, a keeper pays out
+//!   dust (USDC balance plus positions at their final prices, up to
+//!   `DUST_SWEEP_THRESHOLD_USDC`) to the user's USDC account, then closes both
+//!
+//! In both cases the rent is returned to the user.
+
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::{self as token_interface, Mint, TokenAccount, TokenInterface, TransferChecked};
+use crate::constants::{DUST_SWEEP_THRESHOLD_USDC, GLOBAL_SEED, MARKET_SEED, USDC_DECIMALS};
+use crate::errors::TerminatorError;
+use crate::events::UserAccountsClosed;
+use crate::states::{role, Global, Market, RoleAssignment, UserBalance, UserPosition};
+
+// ============================================
+// Close User Accounts
+// ============================================
+
+#[derive(Accounts)]
+pub struct CloseUserAccounts<'info> {
+    /// Account owner (receives the rent)
+    #[account(mut)]
+    pub user: Signer<'info>,
+
+    /// CHECK: market PDA, must be settled or terminated unless already closed by
+    /// close_market (checked in the handler)
+    pub market: UncheckedAccount<'info>,
+
+    #[account(
+        mut,
+        close = user,
+        seeds = [b"user_balance", market.key().as_ref(), user.key().as_ref()],
+        bump = user_balance.bump,
+        constraint = user_balance.user == user.key() @ TerminatorError::Unauthorized,
+        constraint = user_balance.usdc_balance == 0 @ TerminatorError::AccountNotEmpty,
+    )]
+    pub user_balance: Account<'info, UserBalance>,
+
+    #[account(
+        mut,
+        close = user,
+        seeds = [b"user_position", market.key().as_ref(), user.key().as_ref()],
+        bump = user_position.bump,
+        constraint = user_position.user == user.key() @ TerminatorError::Unauthorized,
+        constraint = user_position.yes_balance == 0 && user_position.no_balance == 0
+            @ TerminatorError::AccountNotEmpty,
+    )]
+    pub user_position: Account<'info, UserPosition>,
+}
+
+pub fn handler_close_user_accounts(ctx: Context<CloseUserAccounts>) -> Result<()> {
+    let clock = Clock::get()?;
+
+    // Accounts stay open while the market trades; close_market only closes
+    // settled or terminated markets
+    let market_info = ctx.accounts.market.to_account_info();
+    if market_info.owner == &crate::ID && !market_info.data_is_empty() {
+        let market = Market::try_deserialize(&mut &market_info.data.borrow()[..])?;
+        require!(
+            market.is_settled() || market.is_terminated(),
+            TerminatorError::MarketNotTerminated
+        );
+    }
+
+    emit!(UserAccountsClosed {
+        market: ctx.accounts.market.key(),
+        user: ctx.accounts.user.key(),
+        swept_usdc: 0,
+        closed_by: ctx.accounts.user.key(),
+        timestamp: clock.unix_timestamp,
+    });
+
+    msg!("User accounts closed for market {}", ctx.accounts.market.key());
+
+    Ok(())
+}
+
+// ============================================
+// Sweep User Accounts (keeper)
+// ============================================
+
+#[derive(Accounts)]
+pub struct SweepUserAccounts<'info> {
+    /// Keeper or authority
+    pub keeper: Signer<'info>,
+
+    #[account(
+        seeds = [GLOBAL_SEED.as_bytes()],
+        bump = global.bump,
+        constraint = global.has_role(&keeper.key(), keeper_role.as_deref(), role::KEEPER)
+            @ TerminatorError::Unauthorized,
+    )]
+    pub global: Box<Account<'info, Global>>,
+
+    /// Signer's keeper role assignment (omitted when the signer is the authority)
+    #[account(
+        seeds = [RoleAssignment::SEED_PREFIX, &[role::KEEPER], keeper.key().as_ref()],
+        bump = keeper_role.bump,
+    )]
+    pub keeper_role: Option<Account<'info, RoleAssignment>>,
+
+    #[account(
+        mut,
+        seeds = [
+            MARKET_SEED.as_bytes(),
+            market.creator.as_ref(),
+            market.market_id.as_ref(),
+        ],
+        bump = market.bump,
+        constraint = market.global == global.key() @ TerminatorError::InvalidAccountInput,
+        constraint = market.can_redeem @ TerminatorError::RedemptionNotAllowed,
+    )]
+    pub market: Box<Account<'info, Market>>,
+
+    /// CHECK: account owner, receives the rent (checked against user_balance.user)
+    #[account(mut)]
+    pub user: UncheckedAccount<'info>,
+
+    #[account(
+        mut,
+        close = user,
+        seeds = [b"user_balance", market.key().as_ref(), user.key().as_ref()],
+        bump = user_balance.bump,
+        constraint = user_balance.user == user.key() @ TerminatorError::Unauthorized,
+    )]
+    pub user_balance: Box<Account<'info, UserBalance>>,
+
+    #[account(
+        mut,
+        close = user,
+        seeds = [b"user_position", market.key().as_ref(), user.key().as_ref()],
+        bump = user_position.bump,
+        constraint = user_position.user == user.key() @ TerminatorError::Unauthorized,
+    )]
+    pub user_position: Box<Account<'info, UserPosition>>,
+
+    #[account(
+        mut,
+        seeds = [b"market_vault", market.key().as_ref()],
+        bump,
+        constraint = market_usdc_vault.mint == global.usdc_mint @ TerminatorError::InvalidTokenMint,
+        constraint = market_usdc_vault.owner == market.key() @ TerminatorError::Unauthorized,
+    )]
+    pub market_usdc_vault: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    /// User's USDC account receiving the dust
+    #[account(
+        mut,
+        constraint = user_usdc_account.owner == user.key() @ TerminatorError::Unauthorized,
+        constraint = user_usdc_account.mint == global.usdc_mint @ TerminatorError::InvalidTokenMint,
+    )]
+    pub user_usdc_account: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    #[account(address = global.usdc_mint @ TerminatorError::InvalidTokenMint)]
+    pub usdc_mint: Box<InterfaceAccount<'info, Mint>>,
+
+    pub token_program: Interface<'info, TokenInterface>,
+}
+
+pub fn handler_sweep_user_accounts(ctx: Context<SweepUserAccounts>) -> Result<()> {
+    let clock = Clock::get()?;
+    let usdc_balance = ctx.accounts.user_balance.usdc_balance;
+    let yes_balance = ctx.accounts.user_position.yes_balance;
+    let no_balance = ctx.accounts.user_position.no_balance;
+
+    // Only dust may be swept: the user redeems anything larger themselves
+    let market = &mut ctx.accounts.market;
+    let position_value = market.redemption_value(yes_balance, no_balance)?;
+    let swept_usdc = usdc_balance
+        .checked_add(position_value)
+        .ok_or(TerminatorError::ArithmeticOverflow)?;
+    require!(
+        swept_usdc <= DUST_SWEEP_THRESHOLD_USDC,
+        TerminatorError::BalanceAboveDustThreshold
+    );
+
+    // Burn positions at final prices (updates supply and collateral tracking)
+    market.redeem_positions(yes_balance, no_balance)?;
+    ctx.accounts.user_balance.usdc_balance = 0;
+    ctx.accounts.user_position.yes_balance = 0;
+    ctx.accounts.user_position.no_balance = 0;
+
+    if swept_usdc > 0 {
+        require!(
+            ctx.accounts.market_usdc_vault.amount >= swept_usdc,
+            TerminatorError::InsufficientVaultBalance
+        );
+
+        let market_seeds = &[
+            MARKET_SEED.as_bytes(),
+            market.creator.as_ref(),
+            market.market_id.as_ref(),
+            &[market.bump],
+        ];
+        let signer_seeds = &[&market_seeds[..]];
+
+        let transfer_ctx = CpiContext::new_with_signer(
+            ctx.accounts.token_program.to_account_info(),
+            TransferChecked {
+                from: ctx.accounts.market_usdc_vault.to_account_info(),
+                mint: ctx.accounts.usdc_mint.to_account_info(),
+                to: ctx.accounts.user_usdc_account.to_account_info(),
+                authority: market.to_account_info(),
+            },
+            signer_seeds,
+        );
+        token_interface::transfer_checked(transfer_ctx, swept_usdc, USDC_DECIMALS)?;
+
+        ctx.accounts.market_usdc_vault.reload()?;
+        market.verify_vault_invariant(ctx.accounts.market_usdc_vault.amount)?;
+    }
+
+    emit!(UserAccountsClosed {
+        market: market.key(),
+        user: ctx.accounts.user.key(),
+        swept_usdc,
+        closed_by: ctx.accounts.keeper.key(),
+        timestamp: clock.unix_timestamp,
+    });
+
+    msg!("Swept {} USDC and closed user accounts for {}", swept_usdc, ctx.accounts.user.key());
+
+    Ok(())
+}
@@ -1,13 +1,13 @@
 use anchor_lang::prelude::*;
 use anchor_spl::token_interface::{self as token_interface, Mint, TokenAccount, TokenInterface, TransferChecked};
 use crate::constants::{
-    DEFAULT_TERMINATION_PROBABILITY, GLOBAL_SEED, MARKET_CREATION_FEE, MARKET_SEED,
+    DEFAULT_TERMINATION_PROBABILITY, GLOBAL_SEED, MARKET_SEED,
     MAX_DESCRIPTION_LEN, MAX_OUTCOME_DESCRIPTION_LEN, MAX_QUESTION_LEN, PLATFORM_TREASURY_SEED,
 };
 use crate::errors::TerminatorError;
 use crate::switchboard_lite::{RandomnessAccountData, SWITCHBOARD_PROGRAM_ID};
 use crate::events::{MarketCreated, MarketCreationFeeCollected};
-use crate::states::{global::Global, market::Market};
+use crate::states::{global::Global, market::Market, MarketTombstone};
 
 #[derive(AnchorSerialize, AnchorDeserialize)]
 pub struct CreateMarketParams {
@@ -46,6 +46,13 @@ pub struct CreateMarket<'info> {
     )]
     pub market: Account<'info, Market>,
 
+    /// CHECK: tombstone of a closed market at the same address (must not exist)
+    #[account(
+        seeds = [MarketTombstone::SEED_PREFIX, market.key().as_ref()],
+        bump,
+    )]
+    pub market_tombstone: UncheckedAccount<'info>,
+
     /// CHECK: Switchboard queue account
     pub switchboard_queue: UncheckedAccount<'info>,
 
@@ -66,15 +73,32 @@ pub struct CreateMarket<'info> {
     pub creator_usdc_account: InterfaceAccount<'info, TokenAccount>,
 
     /// USDC mint account
+    #[account(address = global.usdc_mint @ TerminatorError::InvalidUsdcMint)]
     pub usdc_mint: InterfaceAccount<'info, Mint>,
 
     pub token_program: Interface<'info, TokenInterface>,
     pub system_program: Program<'info, System>,
+
+    /// Alternative fee token mint (pass all three alt accounts to pay the
+    /// creation fee in the alternative token instead of USDC)
+    #[account(address = global.alt_fee_mint @ TerminatorError::InvalidTokenMint)]
+    pub alt_fee_mint: Option<Box<InterfaceAccount<'info, Mint>>>,
+
+    /// Creator's alternative-token account
+    #[account(mut)]
+    pub creator_alt_fee_account: Option<Box<InterfaceAccount<'info, TokenAccount>>>,
+
+    /// Alternative-token fee recipient (Global::alt_fee_recipient)
+    #[account(mut, address = global.alt_fee_recipient @ TerminatorError::InvalidAccountInput)]
+    pub alt_fee_recipient: Option<Box<InterfaceAccount<'info, TokenAccount>>>,
 }
 
 pub fn handler(ctx: Context<CreateMarket>, params: CreateMarketParams) -> Result<()> {
     let clock = Clock::get()?;
 
+    // Closed market addresses are never reused
+    MarketTombstone::require_absent(&ctx.accounts.market_tombstone)?;
+
     let market = &mut ctx.accounts.market;
     let global = &mut ctx.accounts.global;
 
@@ -95,22 +119,57 @@ pub fn handler(ctx: Context<CreateMarket>, params: CreateMarketParams) -> Result
         TerminatorError::InvalidInput
     );
 
-    // Transfer creation fee from creator to platform treasury
-    let transfer_ctx = CpiContext::new(
-        ctx.accounts.token_program.to_account_info(),
-        TransferChecked {
-            from: ctx.accounts.creator_usdc_account.to_account_info(),
-            mint: ctx.accounts.usdc_mint.to_account_info(),
-            to: ctx.accounts.platform_treasury.to_account_info(),
-            authority: ctx.accounts.creator.to_account_info(),
-        },
-    );
-    token_interface::transfer_checked(transfer_ctx, MARKET_CREATION_FEE, 6)?;
+    // Transfer creation fee from creator: USDC to the platform treasury, or
+    // the alternative token (at Global::alt_fee_rate) to its recipient
+    let (fee_mint, fee_amount) = match (
+        &ctx.accounts.alt_fee_mint,
+        &ctx.accounts.creator_alt_fee_account,
+        &ctx.accounts.alt_fee_recipient,
+    ) {
+        (None, None, None) => {
+            let fee_amount = global.creation_fee;
+            let transfer_ctx = CpiContext::new(
+                ctx.accounts.token_program.to_account_info(),
+                TransferChecked {
+                    from: ctx.accounts.creator_usdc_account.to_account_info(),
+                    mint: ctx.accounts.usdc_mint.to_account_info(),
+                    to: ctx.accounts.platform_treasury.to_account_info(),
+                    authority: ctx.accounts.creator.to_account_info(),
+                },
+            );
+            token_interface::transfer_checked(transfer_ctx, fee_amount, ctx.accounts.usdc_mint.decimals)?;
+
+            // Update global stats (USDC only)
+            global.total_creation_fees_collected = global.total_creation_fees_collected
+                .checked_add(fee_amount)
+                .ok_or(TerminatorError::ArithmeticOverflow)?;
+            market.creation_fee_paid = fee_amount;
+            (ctx.accounts.usdc_mint.key(), fee_amount)
+        }
+        (Some(alt_fee_mint), Some(creator_alt_fee_account), Some(alt_fee_recipient)) => {
+            require!(global.alt_fee_enabled(), TerminatorError::AltFeeTokenNotEnabled);
+            let fee_amount = global.alt_creation_fee()?;
+            let transfer_ctx = CpiContext::new(
+                ctx.accounts.token_program.to_account_info(),
+                TransferChecked {
+                    from: creator_alt_fee_account.to_account_info(),
+                    mint: alt_fee_mint.to_account_info(),
+                    to: alt_fee_recipient.to_account_info(),
+                    authority: ctx.accounts.creator.to_account_info(),
+                },
+            );
+            token_interface::transfer_checked(transfer_ctx, fee_amount, alt_fee_mint.decimals)?;
 
-    // Update global stats
-    global.total_creation_fees_collected = global.total_creation_fees_collected
-        .checked_add(MARKET_CREATION_FEE)
-        .ok_or(TerminatorError::ArithmeticOverflow)?;
+            // Alternative-token fees are not refundable
+            market.creation_fee_paid = 0;
+            (alt_fee_mint.key(), fee_amount)
+        }
+        _ => return err!(TerminatorError::InvalidAccountInput),
+    };
+    market.creation_fee_refund_volume = global.creation_fee_refund_volume;
+    market.creation_fee_refunded = false;
+    market.total_volume = 0;
+    market.refund_eligible_volume = 0;
 
     // Validate randomness account belongs to Switchboard and correct queue
     require!(
@@ -162,7 +221,19 @@ pub fn handler(ctx: Context<CreateMarket>, params: CreateMarketParams) -> Result
     market.final_no_price = None;
     market.can_redeem = false;
     market.termination_trade_slot = None;
+    market.layout_version = Market::LAYOUT_VERSION;
+    market.terminated_at = None;
+    market.pending_termination_probability = None;
+    market.pending_params_eta = 0;
+    market.fee_override = None;
+    market.pending_fee_override = None;
+    market.pending_fee_override_eta = 0;
+    market.fee_override_proposer = Pubkey::default();
+    market.pending_fee_override_proposer = Pubkey::default();
     market.trade_nonce = 0;
+    market.is_delisted = false;
+    market.delist_reason = 0;
+    market.delisted_at = None;
     
     // Creator incentive tracking
     // Fee rates are read from Global account (see Global.calculate_taker_fee_rate())
@@ -187,13 +258,13 @@ pub fn handler(ctx: Context<CreateMarket>, params: CreateMarketParams) -> Result
     emit!(MarketCreationFeeCollected {
         market: market.key(),
         creator: ctx.accounts.creator.key(),
-        fee_amount: MARKET_CREATION_FEE,
+        fee_mint,
+        fee_amount,
         slot: clock.slot,
         timestamp: clock.unix_timestamp,
     });
 
-    msg!("Market creation fee collected: {} USDC", MARKET_CREATION_FEE as f64 / 1_000_000.0);
-    msg!("Platform treasury balance updated");
+    msg!("Market creation fee collected: {} (mint {})", fee_amount, fee_mint);
 
     Ok(())
 }
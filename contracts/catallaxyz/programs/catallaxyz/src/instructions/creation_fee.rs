@@ -0,0 +1,203 @@
+//! Market Creation Fee Instructions
+//!
+//! The creation fee charged by `create_market` lives in `Global`:
+//! - `update_creation_fee` (fee admin) sets the USDC fee, the volume at which
+//!   it is refunded, and the whitelisted alternative token and its rate
+//! - `claim_creation_fee_refund` (market creator) refunds a USDC creation fee
+//!   from the platform treasury once the market's refund-eligible volume
+//!   reaches the threshold snapshotted at creation. Fees paid in the
+//!   alternative token are not refundable.
+//!
+//! Refund-eligible volume leaves out fills where the creator is a party,
+//! self-matched fills and fee-free mint/merge matches. A creator trading
+//! between other wallets can still reach the threshold, paying the market's
+//! trading fees on that volume.
+
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::{self as token_interface, Mint, TokenAccount, TokenInterface, TransferChecked};
+use crate::constants::{GLOBAL_SEED, MARKET_SEED, MAX_MARKET_CREATION_FEE, PLATFORM_TREASURY_SEED};
+use crate::errors::TerminatorError;
+use crate::events::{CreationFeeConfigUpdated, CreationFeeRefunded};
+use crate::states::{role, Global, Market, RoleAssignment};
+
+// ============================================
+// Update Creation Fee
+// ============================================
+
+#[derive(Accounts)]
+pub struct UpdateCreationFee<'info> {
+    /// Fee admin (or authority)
+    #[account(
+        constraint = global.has_role(&authority.key(), fee_admin_role.as_deref(), role::FEE_ADMIN)
+            @ TerminatorError::Unauthorized
+    )]
+    pub authority: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [GLOBAL_SEED.as_bytes()],
+        bump = global.bump,
+    )]
+    pub global: Account<'info, Global>,
+
+    /// Signer's fee admin role assignment (omitted when the signer is the authority)
+    #[account(
+        seeds = [RoleAssignment::SEED_PREFIX, &[role::FEE_ADMIN], authority.key().as_ref()],
+        bump = fee_admin_role.bump,
+    )]
+    pub fee_admin_role: Option<Account<'info, RoleAssignment>>,
+
+    /// Token account receiving alternative-token fees; its mint becomes the
+    /// alternative fee token (required when alt_fee_rate > 0)
+    pub alt_fee_recipient: Option<InterfaceAccount<'info, TokenAccount>>,
+}
+
+/// Parameters for update_creation_fee instruction
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug)]
+pub struct UpdateCreationFeeParams {
+    /// Creation fee (USDC lamports, at most MAX_MARKET_CREATION_FEE)
+    pub creation_fee: u64,
+    /// Market volume that makes a USDC creation fee refundable (0 = no refunds)
+    pub refund_volume: u64,
+    /// Alternative-token base units per USDC lamport (scaled by 10^6, 0 = disabled)
+    pub alt_fee_rate: u64,
+}
+
+pub fn handler_update_creation_fee(
+    ctx: Context<UpdateCreationFee>,
+    params: UpdateCreationFeeParams,
+) -> Result<()> {
+    require!(
+        params.creation_fee <= MAX_MARKET_CREATION_FEE,
+        TerminatorError::InvalidAmount
+    );
+
+    let global = &mut ctx.accounts.global;
+    let (alt_fee_mint, alt_fee_recipient) = if params.alt_fee_rate > 0 {
+        let recipient = ctx.accounts.alt_fee_recipient
+            .as_ref()
+            .ok_or(TerminatorError::InvalidAccountInput)?;
+        require!(recipient.mint != global.usdc_mint, TerminatorError::InvalidTokenMint);
+        (recipient.mint, recipient.key())
+    } else {
+        (Pubkey::default(), Pubkey::default())
+    };
+
+    global.creation_fee = params.creation_fee;
+    global.creation_fee_refund_volume = params.refund_volume;
+    global.alt_fee_mint = alt_fee_mint;
+    global.alt_fee_recipient = alt_fee_recipient;
+    global.alt_fee_rate = params.alt_fee_rate;
+    // Reject rates that overflow at this fee
+    global.alt_creation_fee()?;
+
+    let clock = Clock::get()?;
+    emit!(CreationFeeConfigUpdated {
+        creation_fee: params.creation_fee,
+        refund_volume: params.refund_volume,
+        alt_fee_mint,
+        alt_fee_recipient,
+        alt_fee_rate: params.alt_fee_rate,
+        updated_by: ctx.accounts.authority.key(),
+        timestamp: clock.unix_timestamp,
+    });
+
+    msg!("Creation fee: {} USDC", params.creation_fee as f64 / 1_000_000.0);
+    msg!("Refund volume: {} USDC", params.refund_volume as f64 / 1_000_000.0);
+    if params.alt_fee_rate > 0 {
+        msg!("Alternative fee token: {} (rate {})", alt_fee_mint, params.alt_fee_rate);
+    }
+
+    Ok(())
+}
+
+// ============================================
+// Claim Creation Fee Refund
+// ============================================
+
+#[derive(Accounts)]
+pub struct ClaimCreationFeeRefund<'info> {
+    pub creator: Signer<'info>,
+
+    #[account(
+        seeds = [GLOBAL_SEED.as_bytes()],
+        bump = global.bump,
+    )]
+    pub global: Account<'info, Global>,
+
+    #[account(
+        mut,
+        seeds = [
+            MARKET_SEED.as_bytes(),
+            market.creator.as_ref(),
+            market.market_id.as_ref(),
+        ],
+        bump = market.bump,
+        constraint = market.global == global.key() @ TerminatorError::InvalidAccountInput,
+        constraint = market.creator == creator.key() @ TerminatorError::Unauthorized,
+    )]
+    pub market: Account<'info, Market>,
+
+    /// Platform treasury (holds the collected creation fee)
+    #[account(
+        mut,
+        seeds = [PLATFORM_TREASURY_SEED.as_bytes()],
+        bump = global.platform_treasury_bump,
+    )]
+    pub platform_treasury: InterfaceAccount<'info, TokenAccount>,
+
+    /// Creator's USDC account
+    #[account(
+        mut,
+        constraint = creator_usdc_account.mint == global.usdc_mint @ TerminatorError::InvalidTokenMint,
+    )]
+    pub creator_usdc_account: InterfaceAccount<'info, TokenAccount>,
+
+    #[account(address = global.usdc_mint @ TerminatorError::InvalidUsdcMint)]
+    pub usdc_mint: InterfaceAccount<'info, Mint>,
+
+    pub token_program: Interface<'info, TokenInterface>,
+}
+
+pub fn handler_claim_creation_fee_refund(ctx: Context<ClaimCreationFeeRefund>) -> Result<()> {
+    require!(
+        ctx.accounts.market.is_creation_fee_refundable(),
+        TerminatorError::CreationFeeNotRefundable
+    );
+    let amount = ctx.accounts.market.creation_fee_paid;
+    require!(
+        ctx.accounts.platform_treasury.amount >= amount,
+        TerminatorError::InsufficientVaultBalance
+    );
+
+    let global_seeds = &[GLOBAL_SEED.as_bytes(), &[ctx.accounts.global.bump]];
+    let signer_seeds = &[&global_seeds[..]];
+    let transfer_ctx = CpiContext::new_with_signer(
+        ctx.accounts.token_program.to_account_info(),
+        TransferChecked {
+            from: ctx.accounts.platform_treasury.to_account_info(),
+            mint: ctx.accounts.usdc_mint.to_account_info(),
+            to: ctx.accounts.creator_usdc_account.to_account_info(),
+            authority: ctx.accounts.global.to_account_info(),
+        },
+        signer_seeds,
+    );
+    token_interface::transfer_checked(transfer_ctx, amount, ctx.accounts.usdc_mint.decimals)?;
+
+    let clock = Clock::get()?;
+    let market = &mut ctx.accounts.market;
+    market.creation_fee_refunded = true;
+
+    emit!(CreationFeeRefunded {
+        market: market.key(),
+        creator: market.creator,
+        amount,
+        total_volume: market.total_volume,
+        refund_eligible_volume: market.refund_eligible_volume,
+        timestamp: clock.unix_timestamp,
+    });
+
+    msg!("Creation fee refunded: {} USDC", amount as f64 / 1_000_000.0);
+
+    Ok(())
+}
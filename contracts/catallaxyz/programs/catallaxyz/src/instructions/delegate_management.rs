@@ -0,0 +1,120 @@
+//! Delegate Management Instructions
+//!
+//! Makers approve or revoke keys that may sign orders on their behalf.
+//! Fill paths only accept `order.signer != order.maker` if the signer is an
+//! active, unexpired delegate with remaining spend cap.
+
+use anchor_lang::prelude::*;
+use crate::errors::TerminatorError;
+use crate::events::{DelegateAdded, DelegateRevoked};
+use crate::states::DelegateRegistry;
+
+// ============================================
+// Add Delegate
+// ============================================
+
+#[derive(Accounts)]
+pub struct AddDelegate<'info> {
+    /// Maker granting the delegation
+    #[account(mut)]
+    pub maker: Signer<'info>,
+
+    /// Maker's delegate registry
+    #[account(
+        init_if_needed,
+        payer = maker,
+        space = DelegateRegistry::INIT_SPACE,
+        seeds = [DelegateRegistry::SEED_PREFIX, maker.key().as_ref()],
+        bump,
+    )]
+    pub delegate_registry: Account<'info, DelegateRegistry>,
+
+    pub system_program: Program<'info, System>,
+}
+
+/// Parameters for add_delegate instruction
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug)]
+pub struct AddDelegateParams {
+    /// Key allowed to sign orders for the maker
+    pub signer: Pubkey,
+    /// Maximum USDC notional the delegate may trade (0 = unlimited)
+    pub spend_cap: u64,
+    /// Expiry timestamp (0 = never expires)
+    pub expires_at: i64,
+}
+
+pub fn handler_add_delegate(ctx: Context<AddDelegate>, params: AddDelegateParams) -> Result<()> {
+    let clock = Clock::get()?;
+    let registry = &mut ctx.accounts.delegate_registry;
+
+    // Initialize if new account
+    if registry.maker == Pubkey::default() {
+        registry.maker = ctx.accounts.maker.key();
+        registry.bump = ctx.bumps.delegate_registry;
+    }
+
+    require!(
+        registry.maker == ctx.accounts.maker.key(),
+        TerminatorError::Unauthorized
+    );
+    require!(
+        params.expires_at == 0 || params.expires_at > clock.unix_timestamp,
+        TerminatorError::InvalidInput
+    );
+
+    registry.upsert(params.signer, params.spend_cap, params.expires_at)?;
+
+    emit!(DelegateAdded {
+        maker: registry.maker,
+        signer: params.signer,
+        spend_cap: params.spend_cap,
+        expires_at: params.expires_at,
+        timestamp: clock.unix_timestamp,
+    });
+
+    msg!("Delegate added: {}", params.signer);
+
+    Ok(())
+}
+
+// ============================================
+// Revoke Delegate
+// ============================================
+
+#[derive(Accounts)]
+pub struct RevokeDelegate<'info> {
+    /// Maker revoking the delegation
+    pub maker: Signer<'info>,
+
+    /// Maker's delegate registry
+    #[account(
+        mut,
+        seeds = [DelegateRegistry::SEED_PREFIX, maker.key().as_ref()],
+        bump = delegate_registry.bump,
+        constraint = delegate_registry.maker == maker.key() @ TerminatorError::Unauthorized,
+    )]
+    pub delegate_registry: Account<'info, DelegateRegistry>,
+}
+
+/// Parameters for revoke_delegate instruction
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug)]
+pub struct RevokeDelegateParams {
+    pub signer: Pubkey,
+}
+
+pub fn handler_revoke_delegate(ctx: Context<RevokeDelegate>, params: RevokeDelegateParams) -> Result<()> {
+    let clock = Clock::get()?;
+    let registry = &mut ctx.accounts.delegate_registry;
+
+    registry.remove(&params.signer)?;
+
+    emit!(DelegateRevoked {
+        maker: registry.maker,
+        signer: params.signer,
+        timestamp: clock.unix_timestamp,
+    });
+
+    msg!("Delegate revoked: {}", params.signer);
+
+    Ok(())
+}
@@ -0,0 +1,136 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::TokenAccount;
+use crate::constants::{GLOBAL_SEED, MARKET_SEED};
+use crate::errors::TerminatorError;
+use crate::events::{MarketDelisted, MarketTerminated};
+use crate::states::{delist_reason, global::Global, market::Market, role, RoleAssignment};
+
+/// Delist a market (market moderator)
+///
+/// Moderation for markets with spam, prohibited or unresolvable questions.
+///
+/// When delisted:
+/// - No new deposits or splits
+/// - Trading, withdrawals, merges and redemption continue, so users can exit
+/// - Indexers hide the market (`MarketDelisted` event / `is_delisted` flag)
+///
+/// With `terminate`, an active market is also terminated at its last traded
+/// price so positions become redeemable immediately. The creator incentive is
+/// not paid out for delisted markets.
+#[derive(Accounts)]
+pub struct DelistMarket<'info> {
+    /// Market moderator (or authority)
+    #[account(
+        constraint = global.has_role(&authority.key(), moderator_role.as_deref(), role::MARKET_MODERATOR)
+            @ TerminatorError::Unauthorized
+    )]
+    pub authority: Signer<'info>,
+
+    /// Global state
+    #[account(
+        seeds = [GLOBAL_SEED.as_bytes()],
+        bump = global.bump
+    )]
+    pub global: Box<Account<'info, Global>>,
+
+    /// Signer's market moderator role assignment (omitted when the signer is the authority)
+    #[account(
+        seeds = [RoleAssignment::SEED_PREFIX, &[role::MARKET_MODERATOR], authority.key().as_ref()],
+        bump = moderator_role.bump,
+    )]
+    pub moderator_role: Option<Account<'info, RoleAssignment>>,
+
+    /// Market to delist
+    #[account(
+        mut,
+        seeds = [
+            MARKET_SEED.as_bytes(),
+            market.creator.as_ref(),
+            market.market_id.as_ref(),
+        ],
+        bump = market.bump,
+        constraint = market.global == global.key() @ TerminatorError::InvalidAccountInput,
+        constraint = !market.is_delisted @ TerminatorError::MarketAlreadyDelisted,
+    )]
+    pub market: Box<Account<'info, Market>>,
+
+    /// Market USDC vault (locks the redeemable amount when terminating)
+    #[account(
+        seeds = [b"market_vault", market.key().as_ref()],
+        bump,
+        constraint = market_usdc_vault.mint == global.usdc_mint @ TerminatorError::InvalidTokenMint,
+        constraint = market_usdc_vault.owner == market.key() @ TerminatorError::Unauthorized
+    )]
+    pub market_usdc_vault: Box<InterfaceAccount<'info, TokenAccount>>,
+}
+
+/// Parameters for delist_market instruction
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug)]
+pub struct DelistMarketParams {
+    /// Reason code (see `delist_reason`)
+    pub reason: u8,
+    /// Also terminate the market at its last traded price
+    pub terminate: bool,
+}
+
+pub fn handler(ctx: Context<DelistMarket>, params: DelistMarketParams) -> Result<()> {
+    require!(
+        delist_reason::is_valid(params.reason),
+        TerminatorError::InvalidDelistReason
+    );
+
+    let clock = Clock::get()?;
+    let vault_balance = ctx.accounts.market_usdc_vault.amount;
+    let market = &mut ctx.accounts.market;
+
+    market.delist(params.reason, clock.unix_timestamp);
+
+    if params.terminate {
+        // Final prices from the last observed trade (0.5 if the market never traded)
+        let (yes_price, no_price) = crate::utils::derive_final_prices(
+            market.last_trade_yes_price,
+            market.last_trade_no_price,
+        );
+        market.terminate_market(yes_price, no_price, clock.slot, clock.unix_timestamp)?;
+
+        // Vault/position invariant checks (post-termination)
+        market.verify_position_invariants()?;
+        require!(
+            market.total_position_collateral == market.total_yes_supply,
+            TerminatorError::InvalidInput
+        );
+        require!(
+            vault_balance == market.total_position_collateral,
+            TerminatorError::InsufficientVaultBalance
+        );
+
+        // Lock total redeemable USDC for redemption tracking
+        market.total_redeemable_usdc = vault_balance;
+        market.total_redeemed_usdc = 0;
+
+        emit!(MarketTerminated {
+            market: market.key(),
+            reason: 2,
+            final_yes_price: yes_price,
+            final_no_price: no_price,
+            termination_slot: clock.slot,
+            timestamp: clock.unix_timestamp,
+        });
+    }
+
+    emit!(MarketDelisted {
+        market: market.key(),
+        reason: params.reason,
+        terminated: params.terminate,
+        delisted_by: ctx.accounts.authority.key(),
+        delisted_at: clock.unix_timestamp,
+    });
+
+    msg!("Market delisted by moderator: {}", market.key());
+    msg!("Reason: {}", params.reason);
+    if params.terminate {
+        msg!("Market terminated; positions are redeemable at the last traded price");
+    }
+
+    Ok(())
+}
@@ -28,8 +28,8 @@ pub struct DepositUsdc<'info> {
         ],
         bump = market.bump,
         constraint = market.global == global.key() @ TerminatorError::InvalidAccountInput,
-        // Prevent deposits to terminated/settled/paused markets
-        constraint = market.can_trade() @ TerminatorError::MarketNotActive,
+        // Prevent deposits to terminated/settled/paused/delisted markets
+        constraint = market.can_deposit() @ TerminatorError::MarketNotActive,
     )]
     pub market: Box<Account<'info, Market>>,
 
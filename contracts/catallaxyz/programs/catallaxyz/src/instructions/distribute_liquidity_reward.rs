@@ -3,7 +3,7 @@ use anchor_spl::token_interface::{self as token_interface, Mint, TokenAccount, T
 use crate::constants::{GLOBAL_SEED, REWARD_TREASURY_SEED};
 use crate::errors::TerminatorError;
 use crate::events::LiquidityRewardDistributed;
-use crate::states::global::Global;
+use crate::states::{global::Global, role, RoleAssignment};
 
 /// Distribute liquidity reward to a recipient (admin only)
 #[derive(AnchorSerialize, AnchorDeserialize, Clone)]
@@ -14,10 +14,11 @@ pub struct DistributeLiquidityRewardParams {
 
 #[derive(Accounts)]
 pub struct DistributeLiquidityReward<'info> {
-    /// Global authority (program admin)
+    /// Treasurer (or authority)
     #[account(
         mut,
-        constraint = authority.key() == global.authority @ TerminatorError::Unauthorized
+        constraint = global.has_role(&authority.key(), treasurer_role.as_deref(), role::TREASURER)
+            @ TerminatorError::Unauthorized
     )]
     pub authority: Signer<'info>,
 
@@ -29,6 +30,13 @@ pub struct DistributeLiquidityReward<'info> {
     )]
     pub global: Account<'info, Global>,
 
+    /// Signer's treasurer role assignment (omitted when the signer is the authority)
+    #[account(
+        seeds = [RoleAssignment::SEED_PREFIX, &[role::TREASURER], authority.key().as_ref()],
+        bump = treasurer_role.bump,
+    )]
+    pub treasurer_role: Option<Account<'info, RoleAssignment>>,
+
     /// Reward treasury (holds liquidity rewards)
     #[account(
         mut,
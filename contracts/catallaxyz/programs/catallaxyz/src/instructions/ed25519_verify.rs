@@ -2,6 +2,15 @@
 //! 
 //! Shared module for Ed25519 signature verification used by fill_order and match_orders.
 //! Extracts common verification logic to avoid code duplication.
+//! 
+//! Signatures are collected from every Ed25519 instruction preceding the current
+//! one, including batched instructions with `num_signatures > 1`
+//! (`load_preceding_ed25519_entries`, `require_ed25519_signature`).
+//! 
+//! Orders are verified with `require_order_signature`, which accepts a signature
+//! over the raw order hash or over the human-readable off-chain message
+//! (`offchain_message`), and the legacy order hash format while
+//! `Global::accept_legacy_order_hash` is set.
 
 use anchor_lang::prelude::*;
 use anchor_lang::solana_program::sysvar::instructions::{
@@ -9,9 +18,11 @@ use anchor_lang::solana_program::sysvar::instructions::{
 };
 use core::str::FromStr;
 use crate::errors::TerminatorError;
+use crate::instructions::offchain_message::order_offchain_message;
+use crate::states::{Order, hash_order_legacy};
 
 /// Ed25519 program ID
-const ED25519_PROGRAM_ID: &str = "Ed25519SigVerify111111111111111111111111111";
+pub(crate) const ED25519_PROGRAM_ID: &str = "Ed25519SigVerify111111111111111111111111111";
 
 /// Read u16 from instruction data at offset
 pub fn read_u16(data: &[u8], offset: &mut usize) -> Result<u16> {
@@ -22,106 +33,137 @@ pub fn read_u16(data: &[u8], offset: &mut usize) -> Result<u16> {
     Ok(value)
 }
 
-/// Verify Ed25519 signature at a specific instruction index
+/// Size of one signature offsets entry in Ed25519 instruction data
+const SIGNATURE_OFFSETS_SIZE: usize = 14;
+
+/// Offsets header start (after num_signatures and padding bytes)
+const SIGNATURE_OFFSETS_START: usize = 2;
+
+/// A single signature verified by an Ed25519 precompile instruction
+#[derive(Clone, Debug)]
+pub struct Ed25519Entry {
+    pub pubkey: Pubkey,
+    pub signature: [u8; 64],
+    pub message: Vec<u8>,
+}
+
+/// Resolve a byte range referenced by an Ed25519 offsets entry
 /// 
-/// # Arguments
-/// * `instructions` - Instructions sysvar account
-/// * `ix_index` - Index of the Ed25519 instruction to verify
-/// * `expected_pubkey` - Expected signer public key
-/// * `expected_msg` - Expected message that was signed
-/// * `expected_sig` - Expected signature (64 bytes)
-pub fn verify_ed25519_at_index(
+/// `ix_index == u16::MAX` refers to the Ed25519 instruction's own data.
+/// Any other value refers to another instruction in the transaction, which lets
+/// clients point at signature/pubkey bytes already present in our instruction data
+/// instead of duplicating them.
+fn read_ed25519_slice(
     instructions: &AccountInfo,
-    ix_index: usize,
-    expected_pubkey: &Pubkey,
-    expected_msg: &[u8],
-    expected_sig: &[u8; 64],
-) -> Result<()> {
-    let ed25519_ix = load_instruction_at_checked(ix_index, instructions)?;
+    own_data: &[u8],
+    ix_index: u16,
+    offset: u16,
+    len: usize,
+) -> Result<Vec<u8>> {
+    const INSTRUCTION_DATA_INDEX: u16 = u16::MAX;
+    let start = offset as usize;
+    let end = start.saturating_add(len);
+    if ix_index == INSTRUCTION_DATA_INDEX {
+        require!(end <= own_data.len(), TerminatorError::InvalidSignature);
+        Ok(own_data[start..end].to_vec())
+    } else {
+        let ix = load_instruction_at_checked(ix_index as usize, instructions)?;
+        require!(end <= ix.data.len(), TerminatorError::InvalidSignature);
+        Ok(ix.data[start..end].to_vec())
+    }
+}
+
+/// Load every signature verified by Ed25519 instructions preceding the current one
+/// 
+/// Supports batched Ed25519 instructions (`num_signatures > 1`) and any number of
+/// Ed25519 instructions, so callers do not depend on fixed instruction offsets.
+/// Non-Ed25519 instructions are skipped.
+pub fn load_preceding_ed25519_entries(instructions: &AccountInfo) -> Result<Vec<Ed25519Entry>> {
+    let current_index = load_current_index_checked(instructions)?;
     let ed25519_program_id =
         Pubkey::from_str(ED25519_PROGRAM_ID)
             .map_err(|_| TerminatorError::InvalidSignature)?;
-    require!(
-        ed25519_ix.program_id == ed25519_program_id,
-        TerminatorError::InvalidSignature
-    );
 
-    let data = ed25519_ix.data.as_slice();
-    require!(data.len() >= 2, TerminatorError::InvalidSignature);
-    let num_signatures = data[0];
-    require!(num_signatures == 1, TerminatorError::InvalidSignature);
+    let mut entries = Vec::new();
+    for ix_index in 0..current_index as usize {
+        let ix = load_instruction_at_checked(ix_index, instructions)?;
+        if ix.program_id != ed25519_program_id {
+            continue;
+        }
 
-    let mut offset = 2;
-    let sig_offset = read_u16(data, &mut offset)?;
-    let sig_ix_index = read_u16(data, &mut offset)?;
-    let pubkey_offset = read_u16(data, &mut offset)?;
-    let pubkey_ix_index = read_u16(data, &mut offset)?;
-    let msg_offset = read_u16(data, &mut offset)?;
-    let msg_size = read_u16(data, &mut offset)?;
-    let msg_ix_index = read_u16(data, &mut offset)?;
+        let data = ix.data.as_slice();
+        require!(data.len() >= SIGNATURE_OFFSETS_START, TerminatorError::InvalidSignature);
+        let num_signatures = data[0] as usize;
 
-    const INSTRUCTION_DATA_INDEX: u16 = u16::MAX;
-    require!(
-        sig_ix_index == INSTRUCTION_DATA_INDEX
-            && pubkey_ix_index == INSTRUCTION_DATA_INDEX
-            && msg_ix_index == INSTRUCTION_DATA_INDEX,
-        TerminatorError::InvalidSignature
-    );
+        for sig_index in 0..num_signatures {
+            let mut offset = SIGNATURE_OFFSETS_START
+                .saturating_add(sig_index.saturating_mul(SIGNATURE_OFFSETS_SIZE));
+            let sig_offset = read_u16(data, &mut offset)?;
+            let sig_ix_index = read_u16(data, &mut offset)?;
+            let pubkey_offset = read_u16(data, &mut offset)?;
+            let pubkey_ix_index = read_u16(data, &mut offset)?;
+            let msg_offset = read_u16(data, &mut offset)?;
+            let msg_size = read_u16(data, &mut offset)?;
+            let msg_ix_index = read_u16(data, &mut offset)?;
 
-    let sig_start = sig_offset as usize;
-    let sig_end = sig_start.saturating_add(64);
-    let pk_start = pubkey_offset as usize;
-    let pk_end = pk_start.saturating_add(32);
-    let msg_start = msg_offset as usize;
-    let msg_end = msg_start.saturating_add(msg_size as usize);
+            let signature = read_ed25519_slice(instructions, data, sig_ix_index, sig_offset, 64)?;
+            let pubkey = read_ed25519_slice(instructions, data, pubkey_ix_index, pubkey_offset, 32)?;
+            let message = read_ed25519_slice(instructions, data, msg_ix_index, msg_offset, msg_size as usize)?;
 
-    require!(
-        sig_end <= data.len() && pk_end <= data.len() && msg_end <= data.len(),
-        TerminatorError::InvalidSignature
-    );
-    require!(msg_size as usize == expected_msg.len(), TerminatorError::InvalidSignature);
-    require!(
-        data[sig_start..sig_end] == expected_sig[..],
-        TerminatorError::InvalidSignature
-    );
-    require!(
-        data[pk_start..pk_end] == expected_pubkey.to_bytes(),
-        TerminatorError::InvalidSignature
-    );
-    require!(
-        &data[msg_start..msg_end] == expected_msg,
-        TerminatorError::InvalidSignature
-    );
+            entries.push(Ed25519Entry {
+                pubkey: Pubkey::try_from(pubkey.as_slice())
+                    .map_err(|_| TerminatorError::InvalidSignature)?,
+                signature: signature
+                    .try_into()
+                    .map_err(|_| TerminatorError::InvalidSignature)?,
+                message,
+            });
+        }
+    }
 
-    Ok(())
+    Ok(entries)
 }
 
-/// Verify Ed25519 signature from the preceding instruction
-/// 
-/// # Arguments
-/// * `instructions` - Instructions sysvar account
-/// * `expected_pubkey` - Expected signer public key
-/// * `expected_msg` - Expected message that was signed
-/// * `expected_sig` - Expected signature (64 bytes)
-pub fn verify_ed25519_preceding(
-    instructions: &AccountInfo,
+/// Require that a signature by `expected_pubkey` over `expected_msg` was verified
+/// by one of the preceding Ed25519 instructions
+pub fn require_ed25519_signature(
+    entries: &[Ed25519Entry],
     expected_pubkey: &Pubkey,
     expected_msg: &[u8],
     expected_sig: &[u8; 64],
 ) -> Result<()> {
-    let current_index = load_current_index_checked(instructions)?;
-    require!(current_index > 0, TerminatorError::InvalidSignature);
-    
-    verify_ed25519_at_index(
-        instructions,
-        (current_index - 1) as usize,
-        expected_pubkey,
-        expected_msg,
-        expected_sig,
-    )
+    require!(
+        entries.iter().any(|entry| {
+            entry.pubkey == *expected_pubkey
+                && entry.signature == *expected_sig
+                && entry.message.as_slice() == expected_msg
+        }),
+        TerminatorError::InvalidSignature
+    );
+    Ok(())
 }
 
-/// Get the current instruction index
-pub fn get_current_instruction_index(instructions: &AccountInfo) -> Result<u16> {
-    load_current_index_checked(instructions).map_err(|e| e.into())
+/// Require a signature by `order.signer` over the order
+///
+/// `order_hash` is the hash in the current signing domain. Accepted messages:
+/// - the raw 32-byte order hash
+/// - the Solana off-chain message describing the order (`order_offchain_message`)
+/// - during migration (`accept_legacy`), the legacy v1 order hash
+pub fn require_order_signature(
+    entries: &[Ed25519Entry],
+    order: &Order,
+    order_hash: &[u8; 32],
+    accept_legacy: bool,
+    signature: &[u8; 64],
+) -> Result<()> {
+    if require_ed25519_signature(entries, &order.signer, order_hash, signature).is_ok() {
+        return Ok(());
+    }
+    let offchain_message = order_offchain_message(order, order_hash);
+    if require_ed25519_signature(entries, &order.signer, &offchain_message, signature).is_ok() {
+        return Ok(());
+    }
+    require!(accept_legacy, TerminatorError::InvalidSignature);
+    require_ed25519_signature(entries, &order.signer, &hash_order_legacy(order), signature)
 }
+
@@ -0,0 +1,339 @@
+//! Fill Multi-Leg Instruction (cross-market)
+//!
+//! Fills up to `MAX_LEGS` signed orders across DIFFERENT markets atomically
+//! against the operator, e.g. buy YES in market A and NO in market B as one
+//! hedged spread trade.
+//!
+//! Every leg must fill its full requested amount at the order's limit price;
+//! if any leg cannot, the whole instruction fails.
+//!
+//! As in `fill_order`, each leg counts as taker volume in its maker's
+//! `UserStats` and gets the maker's volume tier discount; a leg's USDC fee is
+//! moved from its market vault to the platform treasury and the maker's
+//! referrer accrues a share of it.
+//!
+//! Signatures may be batched into one or more Ed25519 instructions anywhere
+//! before this instruction.
+
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::sysvar::instructions::ID as INSTRUCTIONS_ID;
+use anchor_spl::token_interface::{Mint, TokenAccount, TokenInterface};
+use crate::constants::{GLOBAL_SEED, PLATFORM_TREASURY_SEED};
+use crate::errors::TerminatorError;
+use crate::events::OrderFilled;
+use crate::states::{
+    Global, Market, UserBalance, UserNonce, UserPosition, RoleAssignment,
+    SignedOrder, hash_order, role,
+};
+use crate::instructions::calculator::{
+    apply_tier_discount, calculate_taking_amount, calculate_order_fee, validate_order, validate_stop_trigger, validate_taker,
+    CancellationScope,
+};
+use crate::instructions::ed25519_verify::{load_preceding_ed25519_entries, require_order_signature};
+use crate::instructions::fill_order::{
+    execute_operator_fill, operator_fill_usdc_fee, order_asset_ids, record_taker_volume, taker_discount_rate,
+    FillAmounts,
+};
+use crate::instructions::order_accounts::{
+    load_cancel_bitmap, load_market_nonce, load_or_init_order_status, peek_user_stats, save_user_stats,
+};
+use crate::instructions::referral::{accrue_referral_fee, load_referral_accounts};
+use crate::instructions::signer_auth::{authorize_order_signer, optional_account, order_notional};
+use crate::instructions::treasury_utils::collect_trading_fee;
+
+/// Maximum number of legs in a single instruction
+pub const MAX_LEGS: usize = 4;
+
+/// Remaining accounts per leg:
+/// market, market_usdc_vault, maker, maker_nonce, maker_market_nonce, maker_cancel_bitmap,
+/// maker_balance, maker_position, maker_stats, maker_referral, referrer_rewards,
+/// operator_balance, operator_position, order_status, signer_authorization
+pub const ACCOUNTS_PER_LEG: usize = 15;
+
+/// A single leg of a multi-leg fill
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug)]
+pub struct FillLeg {
+    /// Signed order from maker
+    pub signed_order: SignedOrder,
+    /// Amount to fill (in maker_amount units); must fill completely
+    pub fill_amount: u64,
+}
+
+/// Parameters for fill_multi_leg instruction
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug)]
+pub struct FillMultiLegParams {
+    /// Legs to execute, one per market
+    pub legs: Vec<FillLeg>,
+}
+
+#[derive(Accounts)]
+pub struct FillMultiLeg<'info> {
+    /// Operator executing the fills (counterparty for every leg)
+    #[account(mut)]
+    pub operator: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [GLOBAL_SEED.as_bytes()],
+        bump = global.bump,
+        constraint = global.is_trading_allowed() @ TerminatorError::TradingPaused,
+        constraint = global.has_role(&operator.key(), operator_role.as_deref(), role::OPERATOR)
+            @ TerminatorError::NotOperator,
+    )]
+    pub global: Box<Account<'info, Global>>,
+
+    /// Signer's operator role assignment (omitted when the signer is the authority)
+    #[account(
+        seeds = [RoleAssignment::SEED_PREFIX, &[role::OPERATOR], operator.key().as_ref()],
+        bump = operator_role.bump,
+    )]
+    pub operator_role: Option<Account<'info, RoleAssignment>>,
+
+    /// CHECK: instructions sysvar for Ed25519 verification
+    #[account(address = INSTRUCTIONS_ID)]
+    pub instructions: AccountInfo<'info>,
+
+    pub system_program: Program<'info, System>,
+
+    /// Platform treasury (receives USDC fees)
+    #[account(
+        mut,
+        seeds = [PLATFORM_TREASURY_SEED.as_bytes()],
+        bump = global.platform_treasury_bump,
+    )]
+    pub platform_treasury: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    #[account(address = global.usdc_mint @ TerminatorError::InvalidUsdcMint)]
+    pub usdc_mint: Box<InterfaceAccount<'info, Mint>>,
+
+    pub token_program: Interface<'info, TokenInterface>,
+    // Remaining accounts, for each leg (ACCOUNTS_PER_LEG accounts):
+    // - market (Market)
+    // - market_usdc_vault (the market's USDC vault)
+    // - maker (UncheckedAccount)
+    // - maker_nonce (UserNonce)
+    // - maker_market_nonce (MarketNonce PDA, may be uninitialized)
+    // - maker_cancel_bitmap (CancelBitmap page for the order's salt, may be uninitialized)
+    // - maker_balance (UserBalance)
+    // - maker_position (UserPosition)
+    // - maker_stats (UserStats, created if missing)
+    // - maker_referral (Referral PDA, may be uninitialized)
+    // - referrer_rewards (ReferrerRewards of the maker's referrer, or program ID if no referral)
+    // - operator_balance (UserBalance)
+    // - operator_position (UserPosition)
+    // - order_status (OrderStatus, created if missing)
+    // - signer_authorization (DelegateRegistry or SessionKey, or program ID if not needed)
+}
+
+pub fn handler<'info>(
+    ctx: Context<'_, '_, 'info, 'info, FillMultiLeg<'info>>,
+    params: FillMultiLegParams,
+) -> Result<()> {
+    let clock = Clock::get()?;
+    let legs_count = params.legs.len();
+
+    require!(legs_count > 0, TerminatorError::InvalidInput);
+    require!(legs_count <= MAX_LEGS, TerminatorError::InvalidInput);
+
+    let remaining_accounts: &'info [AccountInfo<'info>] = ctx.remaining_accounts;
+    require!(
+        remaining_accounts.len() == legs_count * ACCOUNTS_PER_LEG,
+        TerminatorError::InvalidAccountInput
+    );
+
+    // Collect all signatures verified by preceding Ed25519 instructions
+    let signatures = load_preceding_ed25519_entries(&ctx.accounts.instructions)?;
+
+    let operator_key = ctx.accounts.operator.key();
+    let global_key = ctx.accounts.global.key();
+    let order_domain = ctx.accounts.global.order_domain();
+    let accept_legacy = ctx.accounts.global.accept_legacy_order_hash;
+    let operator_info = ctx.accounts.operator.to_account_info();
+    let system_program_info = ctx.accounts.system_program.to_account_info();
+
+    // Each leg must be in a different market (accounts are loaded once per leg)
+    let mut leg_markets: Vec<Pubkey> = Vec::with_capacity(legs_count);
+
+    for (leg, accounts) in params.legs.iter().zip(remaining_accounts.chunks(ACCOUNTS_PER_LEG)) {
+        let order = &leg.signed_order.order;
+
+        // ============================================
+        // Load Leg Accounts
+        // ============================================
+
+        let mut market: Account<Market> = Account::try_from(&accounts[0])?;
+        let market_key = market.key();
+        require!(market.global == global_key, TerminatorError::InvalidAccountInput);
+        require!(market.can_trade(), TerminatorError::MarketNotActive);
+        require!(!leg_markets.contains(&market_key), TerminatorError::InvalidAccountInput);
+        leg_markets.push(market_key);
+        let (vault_key, _) = Pubkey::find_program_address(&[b"market_vault", market_key.as_ref()], &crate::ID);
+        require!(accounts[1].key() == vault_key, TerminatorError::InvalidAccountInput);
+
+        let maker = accounts[2].key();
+        require!(maker == order.maker, TerminatorError::InvalidAccountInput);
+        require!(maker != operator_key, TerminatorError::InvalidAccountInput);
+
+        let maker_nonce: Account<UserNonce> = Account::try_from(&accounts[3])?;
+        let maker_market_nonce = load_market_nonce(&accounts[4], &maker, &market_key)?;
+        let maker_cancel_bitmap = load_cancel_bitmap(&accounts[5], &maker, order.salt)?;
+        let mut maker_balance: Account<UserBalance> = Account::try_from(&accounts[6])?;
+        let mut maker_position: Account<UserPosition> = Account::try_from(&accounts[7])?;
+        let mut maker_stats = peek_user_stats(&accounts[8], &maker)?;
+        let maker_referral = load_referral_accounts(&accounts[9], optional_account(&accounts[10]), &maker)?;
+        let mut operator_balance: Account<UserBalance> = Account::try_from(&accounts[11])?;
+        let mut operator_position: Account<UserPosition> = Account::try_from(&accounts[12])?;
+
+        require!(maker_nonce.user == maker, TerminatorError::Unauthorized);
+        require!(
+            maker_balance.user == maker && maker_position.user == maker,
+            TerminatorError::Unauthorized
+        );
+        require!(
+            operator_balance.user == operator_key && operator_position.user == operator_key,
+            TerminatorError::Unauthorized
+        );
+        require!(
+            maker_balance.market == market_key
+                && maker_position.market == market_key
+                && operator_balance.market == market_key
+                && operator_position.market == market_key,
+            TerminatorError::InvalidAccountInput
+        );
+
+        // ============================================
+        // Order Validation
+        // ============================================
+
+        let order_hash = hash_order(order, &order_domain);
+        validate_order(
+            order,
+            &order_hash,
+            clock.unix_timestamp,
+            &CancellationScope {
+                user_nonce: maker_nonce.current_nonce,
+                market_nonce: maker_market_nonce.as_ref(),
+                cancel_bitmap: maker_cancel_bitmap.as_ref(),
+            },
+        )?;
+        require!(order.market == market_key, TerminatorError::InvalidMarket);
+        validate_taker(order, &operator_key)?;
+        validate_stop_trigger(order, &market)?;
+
+        require_order_signature(
+            &signatures,
+            order,
+            &order_hash,
+            accept_legacy,
+            &leg.signed_order.signature,
+        )?;
+
+        let mut order_status = load_or_init_order_status(
+            &accounts[13],
+            &order_hash,
+            order.maker_amount,
+            &operator_info,
+            &system_program_info,
+        )?;
+
+        // Leg must fill completely at the order's limit
+        require!(leg.fill_amount > 0, TerminatorError::InvalidAmount);
+        require!(
+            order_status.is_fillable() && order_status.remaining >= leg.fill_amount,
+            TerminatorError::OrderNotFillable
+        );
+        order_status.fill(leg.fill_amount)?;
+
+        // ============================================
+        // Execute Transfer
+        // ============================================
+
+        let taking = calculate_taking_amount(leg.fill_amount, order.maker_amount, order.taker_amount)?;
+        let fee = apply_tier_discount(
+            calculate_order_fee(order, taking, market.fee_override.as_ref())?,
+            taker_discount_rate(&ctx.accounts.global, &maker_stats.stats, clock.unix_timestamp),
+        )?;
+
+        // Signer must be the maker or an authorized delegate (spend tracked in USDC)
+        let notional = order_notional(order, leg.fill_amount, taking);
+        let authorization = authorize_order_signer(
+            order,
+            optional_account(&accounts[14]),
+            clock.unix_timestamp,
+            notional,
+        )?;
+
+        execute_operator_fill(
+            order,
+            FillAmounts { making: leg.fill_amount, taking, fee },
+            &mut maker_balance,
+            &mut maker_position,
+            &mut operator_balance,
+            &mut operator_position,
+        )?;
+        authorization.commit()?;
+        record_taker_volume(&ctx.accounts.global, &mut maker_stats.stats, clock.unix_timestamp, notional);
+
+        // Collect the USDC fee into the platform treasury, then accrue the referral share
+        let usdc_fee = operator_fill_usdc_fee(order, fee);
+        collect_trading_fee(
+            &mut ctx.accounts.global,
+            &market,
+            &accounts[1],
+            &ctx.accounts.platform_treasury.to_account_info(),
+            &ctx.accounts.usdc_mint.to_account_info(),
+            &ctx.accounts.token_program.to_account_info(),
+            usdc_fee,
+        )?;
+        let referral_fee = accrue_referral_fee(
+            &ctx.accounts.global,
+            market.fee_override.as_ref(),
+            maker_referral.as_ref(),
+            market_key,
+            usdc_fee,
+            clock.unix_timestamp,
+        )?;
+        ctx.accounts.global.reserve_referral_rewards(referral_fee)?;
+
+        // Update market stats
+        market.record_activity(clock.unix_timestamp, clock.slot);
+        let refund_notional = if market.is_refund_eligible_fill(&order.maker, &ctx.accounts.operator.key()) {
+            notional
+        } else {
+            0
+        };
+        market.record_volume(notional, refund_notional)?;
+        market.record_token_last_price(order.token_id, order.calculate_price())?;
+        market.total_trades = market.total_trades
+            .checked_add(1)
+            .ok_or(TerminatorError::ArithmeticOverflow)?;
+
+        // Persist leg accounts
+        market.exit(&crate::ID)?;
+        maker_balance.exit(&crate::ID)?;
+        maker_position.exit(&crate::ID)?;
+        operator_balance.exit(&crate::ID)?;
+        operator_position.exit(&crate::ID)?;
+        order_status.exit(&crate::ID)?;
+        save_user_stats(&accounts[8], &maker_stats, &operator_info, &system_program_info)?;
+
+        let (maker_asset_id, taker_asset_id) = order_asset_ids(order);
+        emit!(OrderFilled {
+            order_hash,
+            maker: order.maker,
+            taker: operator_key,
+            maker_asset_id,
+            taker_asset_id,
+            maker_amount_filled: leg.fill_amount,
+            taker_amount_filled: taking,
+            fee,
+            market: market_key,
+            slot: clock.slot,
+            timestamp: clock.unix_timestamp,
+        });
+    }
+
+    msg!("Filled {} legs across {} markets", legs_count, leg_markets.len());
+
+    Ok(())
+}
@@ -7,19 +7,36 @@
 //! - Order is signed by the maker
 //! - Operator validates and executes the fill
 //! - Assets are transferred atomically
+//! 
+//! The order takes the operator's liquidity, so its USDC notional counts as
+//! taker volume in the maker's `UserStats` and its fee gets the maker's
+//! volume tier discount.
+//! 
+//! A SELL order's fee is deducted from its USDC proceeds and moved from the
+//! market vault to the platform treasury; the maker's referrer accrues a share
+//! of it. A BUY order's fee is deducted from the outcome tokens it receives
+//! and is not collected as platform revenue.
 
 use anchor_lang::prelude::*;
 use anchor_lang::solana_program::sysvar::instructions::ID as INSTRUCTIONS_ID;
-use crate::constants::{GLOBAL_SEED, MARKET_SEED};
+use anchor_spl::token_interface::{Mint, TokenAccount, TokenInterface};
+use crate::constants::{GLOBAL_SEED, MARKET_SEED, PLATFORM_TREASURY_SEED};
 use crate::errors::TerminatorError;
-use crate::events::OrderFilled;
+use crate::events::{OrderFilled, UserFeeTierChanged};
 use crate::states::{
-    Global, Market, UserBalance, UserPosition, 
-    SignedOrder, OrderStatus, UserNonce,
-    hash_order, token_id,
+    Global, Market, UserBalance, UserPosition, UserStats, RoleAssignment, Referral,
+    Order, SignedOrder, OrderStatus, UserNonce, MarketNonce, CancelBitmap,
+    hash_order, token_id, role,
 };
-use crate::instructions::calculator::{calculate_taking_amount, calculate_fee, validate_order, validate_taker};
-use crate::instructions::ed25519_verify::verify_ed25519_preceding;
+use crate::instructions::calculator::{
+    apply_tier_discount, calculate_taking_amount, calculate_order_fee, validate_order, validate_stop_trigger, validate_taker,
+    CancellationScope,
+};
+use crate::instructions::ed25519_verify::{load_preceding_ed25519_entries, require_order_signature};
+use crate::instructions::order_accounts::{read_cancel_bitmap, read_market_nonce};
+use crate::instructions::referral::{accrue_referral_fee, load_referral_accounts};
+use crate::instructions::treasury_utils::collect_trading_fee;
+use crate::instructions::signer_auth::{authorize_order_signer, order_notional};
 
 /// Parameters for fill_order instruction
 #[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug)]
@@ -33,7 +50,7 @@ pub struct FillOrderParams {
 #[derive(Accounts)]
 #[instruction(params: FillOrderParams)]
 pub struct FillOrder<'info> {
-    /// Operator executing the fill (must be in global.operators)
+    /// Operator executing the fill (must hold the operator role)
     #[account(mut)]
     pub operator: Signer<'info>,
 
@@ -42,10 +59,18 @@ pub struct FillOrder<'info> {
         seeds = [GLOBAL_SEED.as_bytes()],
         bump = global.bump,
         constraint = global.is_trading_allowed() @ TerminatorError::TradingPaused,
-        constraint = global.is_operator(&operator.key()) @ TerminatorError::NotOperator,
+        constraint = global.has_role(&operator.key(), operator_role.as_deref(), role::OPERATOR)
+            @ TerminatorError::NotOperator,
     )]
     pub global: Box<Account<'info, Global>>,
 
+    /// Signer's operator role assignment (omitted when the signer is the authority)
+    #[account(
+        seeds = [RoleAssignment::SEED_PREFIX, &[role::OPERATOR], operator.key().as_ref()],
+        bump = operator_role.bump,
+    )]
+    pub operator_role: Option<Account<'info, RoleAssignment>>,
+
     #[account(
         mut,
         seeds = [
@@ -64,7 +89,7 @@ pub struct FillOrder<'info> {
         init_if_needed,
         payer = operator,
         space = OrderStatus::INIT_SPACE,
-        seeds = [OrderStatus::SEED_PREFIX, &hash_order(&params.signed_order.order)],
+        seeds = [OrderStatus::SEED_PREFIX, &hash_order(&params.signed_order.order, &global.order_domain())],
         bump,
     )]
     pub order_status: Box<Account<'info, OrderStatus>>,
@@ -76,6 +101,24 @@ pub struct FillOrder<'info> {
     )]
     pub maker_nonce: Box<Account<'info, UserNonce>>,
 
+    /// CHECK: maker's MarketNonce for this market (may be uninitialized)
+    #[account(
+        seeds = [MarketNonce::SEED_PREFIX, maker.key().as_ref(), market.key().as_ref()],
+        bump,
+    )]
+    pub maker_market_nonce: UncheckedAccount<'info>,
+
+    /// CHECK: maker's CancelBitmap page for the order's salt (may be uninitialized)
+    #[account(
+        seeds = [
+            CancelBitmap::SEED_PREFIX,
+            maker.key().as_ref(),
+            &CancelBitmap::page_of(params.signed_order.order.salt).to_le_bytes(),
+        ],
+        bump,
+    )]
+    pub maker_cancel_bitmap: UncheckedAccount<'info>,
+
     /// Maker's USDC balance
     #[account(
         mut,
@@ -94,6 +137,16 @@ pub struct FillOrder<'info> {
     )]
     pub maker_position: Box<Account<'info, UserPosition>>,
 
+    /// Maker's volume stats for fee tiers (created on first fill)
+    #[account(
+        init_if_needed,
+        payer = operator,
+        space = UserStats::INIT_SPACE,
+        seeds = [UserStats::SEED_PREFIX, maker.key().as_ref()],
+        bump,
+    )]
+    pub maker_stats: Box<Account<'info, UserStats>>,
+
     /// Operator's USDC balance (as counterparty)
     #[account(
         mut,
@@ -121,6 +174,43 @@ pub struct FillOrder<'info> {
     pub instructions: AccountInfo<'info>,
 
     pub system_program: Program<'info, System>,
+
+    /// CHECK: maker's DelegateRegistry or SessionKey, required if order.signer != order.maker
+    /// (validated in signer_auth)
+    #[account(mut)]
+    pub signer_authorization: Option<UncheckedAccount<'info>>,
+
+    /// CHECK: maker's Referral PDA (may be uninitialized if the maker has no referrer)
+    #[account(
+        seeds = [Referral::SEED_PREFIX, maker.key().as_ref()],
+        bump,
+    )]
+    pub maker_referral: UncheckedAccount<'info>,
+
+    /// CHECK: rewards of the maker's referrer, required if maker_referral exists
+    /// (validated in referral)
+    #[account(mut)]
+    pub referrer_rewards: Option<UncheckedAccount<'info>>,
+
+    #[account(
+        mut,
+        seeds = [b"market_vault", market.key().as_ref()],
+        bump,
+    )]
+    pub market_usdc_vault: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    /// Platform treasury (receives USDC fees)
+    #[account(
+        mut,
+        seeds = [PLATFORM_TREASURY_SEED.as_bytes()],
+        bump = global.platform_treasury_bump,
+    )]
+    pub platform_treasury: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    #[account(address = global.usdc_mint @ TerminatorError::InvalidUsdcMint)]
+    pub usdc_mint: Box<InterfaceAccount<'info, Mint>>,
+
+    pub token_program: Interface<'info, TokenInterface>,
 }
 
 pub fn handler(ctx: Context<FillOrder>, params: FillOrderParams) -> Result<()> {
@@ -132,8 +222,21 @@ pub fn handler(ctx: Context<FillOrder>, params: FillOrderParams) -> Result<()> {
     // Order Validation
     // ============================================
     
-    // Validate order fields
-    validate_order(order, clock.unix_timestamp, ctx.accounts.maker_nonce.current_nonce)?;
+    let order_hash = hash_order(order, &ctx.accounts.global.order_domain());
+    
+    // Validate order fields and cancellation scope (global + market + salt bitmap)
+    let maker_market_nonce = read_market_nonce(&ctx.accounts.maker_market_nonce)?;
+    let maker_cancel_bitmap = read_cancel_bitmap(&ctx.accounts.maker_cancel_bitmap)?;
+    validate_order(
+        order,
+        &order_hash,
+        clock.unix_timestamp,
+        &CancellationScope {
+            user_nonce: ctx.accounts.maker_nonce.current_nonce,
+            market_nonce: maker_market_nonce.as_ref(),
+            cancel_bitmap: maker_cancel_bitmap.as_ref(),
+        },
+    )?;
     
     // Validate order is for this market
     require!(
@@ -144,12 +247,16 @@ pub fn handler(ctx: Context<FillOrder>, params: FillOrderParams) -> Result<()> {
     // Validate taker (operator is the taker in fill_order)
     validate_taker(order, &ctx.accounts.operator.key())?;
     
-    // Verify maker's signature on the order
-    let order_hash = hash_order(order);
-    verify_ed25519_preceding(
-        &ctx.accounts.instructions,
-        &order.signer, // signer signs the order (can be maker or delegate)
+    // Stop orders only execute once the last price has crossed the trigger
+    validate_stop_trigger(order, &ctx.accounts.market)?;
+    
+    // Verify maker's signature on the order (signer can be maker or delegate)
+    let signatures = load_preceding_ed25519_entries(&ctx.accounts.instructions)?;
+    require_order_signature(
+        &signatures,
+        order,
         &order_hash,
+        ctx.accounts.global.accept_legacy_order_hash,
         &params.signed_order.signature,
     )?;
     
@@ -161,7 +268,7 @@ pub fn handler(ctx: Context<FillOrder>, params: FillOrderParams) -> Result<()> {
     
     // Initialize if new order
     if order_status.order_hash == [0u8; 32] {
-        order_status.init(order_hash, order.maker_amount, ctx.bumps.order_status);
+        order_status.init(order_hash, order.maker_amount, ctx.accounts.operator.key(), ctx.bumps.order_status);
     } else {
         // Verify order hash matches
         require!(
@@ -187,135 +294,70 @@ pub fn handler(ctx: Context<FillOrder>, params: FillOrderParams) -> Result<()> {
     // Calculate taking amount from fill amount
     let taking_amount = calculate_taking_amount(actual_fill, order.maker_amount, order.taker_amount)?;
     
-    // Calculate fee (charged on proceeds)
-    let fee = calculate_fee(
-        order.fee_rate_bps,
-        taking_amount,
-        order.maker_amount,
-        order.taker_amount,
-        order.side,
+    // Calculate fee (charged on proceeds), less the maker's volume tier discount
+    let maker_stats = &mut ctx.accounts.maker_stats;
+    if maker_stats.user == Pubkey::default() {
+        maker_stats.init(order.maker, ctx.bumps.maker_stats);
+    }
+    let fee = apply_tier_discount(
+        calculate_order_fee(order, taking_amount, ctx.accounts.market.fee_override.as_ref())?,
+        taker_discount_rate(&ctx.accounts.global, maker_stats, clock.unix_timestamp),
+    )?;
+    
+    // Signer must be the maker or an authorized delegate (spend tracked in USDC)
+    let notional = order_notional(order, actual_fill, taking_amount);
+    let authorization = authorize_order_signer(
+        order,
+        ctx.accounts.signer_authorization.as_ref().map(|account| account.as_ref()),
+        clock.unix_timestamp,
+        notional,
+    )?;
+    
+    // Maker's referral (required whenever the maker registered a referrer)
+    let maker_referral = load_referral_accounts(
+        &ctx.accounts.maker_referral,
+        ctx.accounts.referrer_rewards.as_ref().map(|account| account.as_ref()),
+        &order.maker,
     )?;
     
     // ============================================
     // Execute Transfer
     // ============================================
     
-    let maker_balance = &mut ctx.accounts.maker_balance;
-    let maker_position = &mut ctx.accounts.maker_position;
-    let operator_balance = &mut ctx.accounts.operator_balance;
-    let operator_position = &mut ctx.accounts.operator_position;
-    
     // Determine asset IDs for the trade
-    let (maker_asset_id, taker_asset_id) = if order.is_buy() {
-        // Maker BUY: maker gives USDC (0), receives tokens (1 or 2)
-        (token_id::USDC, order.token_id)
-    } else {
-        // Maker SELL: maker gives tokens (1 or 2), receives USDC (0)
-        (order.token_id, token_id::USDC)
-    };
+    let (maker_asset_id, taker_asset_id) = order_asset_ids(order);
     
-    if order.is_buy() {
-        // Maker is buying tokens
-        // - Maker pays USDC (actual_fill)
-        // - Maker receives tokens (taking_amount - fee)
-        // - Operator receives USDC (actual_fill)
-        // - Operator pays tokens (taking_amount)
-        
-        require!(
-            maker_balance.usdc_balance >= actual_fill,
-            TerminatorError::InsufficientBalance
-        );
-        
-        // Check operator has tokens
-        if order.token_id == token_id::YES {
-            require!(
-                operator_position.yes_balance >= taking_amount,
-                TerminatorError::InsufficientOutcomeTokens
-            );
-        } else {
-            require!(
-                operator_position.no_balance >= taking_amount,
-                TerminatorError::InsufficientOutcomeTokens
-            );
-        }
-        
-        // Execute transfers
-        maker_balance.usdc_balance = maker_balance.usdc_balance
-            .checked_sub(actual_fill)
-            .ok_or(TerminatorError::InsufficientBalance)?;
-        
-        operator_balance.usdc_balance = operator_balance.usdc_balance
-            .checked_add(actual_fill)
-            .ok_or(TerminatorError::ArithmeticOverflow)?;
-        
-        let tokens_to_maker = taking_amount.saturating_sub(fee);
-        if order.token_id == token_id::YES {
-            maker_position.yes_balance = maker_position.yes_balance
-                .checked_add(tokens_to_maker)
-                .ok_or(TerminatorError::ArithmeticOverflow)?;
-            operator_position.yes_balance = operator_position.yes_balance
-                .checked_sub(taking_amount)
-                .ok_or(TerminatorError::InsufficientOutcomeTokens)?;
-        } else {
-            maker_position.no_balance = maker_position.no_balance
-                .checked_add(tokens_to_maker)
-                .ok_or(TerminatorError::ArithmeticOverflow)?;
-            operator_position.no_balance = operator_position.no_balance
-                .checked_sub(taking_amount)
-                .ok_or(TerminatorError::InsufficientOutcomeTokens)?;
-        }
-        
-    } else {
-        // Maker is selling tokens
-        // - Maker pays tokens (actual_fill)
-        // - Maker receives USDC (taking_amount - fee)
-        // - Operator receives tokens (actual_fill)
-        // - Operator pays USDC (taking_amount)
-        
-        // Check maker has tokens
-        if order.token_id == token_id::YES {
-            require!(
-                maker_position.yes_balance >= actual_fill,
-                TerminatorError::InsufficientOutcomeTokens
-            );
-        } else {
-            require!(
-                maker_position.no_balance >= actual_fill,
-                TerminatorError::InsufficientOutcomeTokens
-            );
-        }
-        
-        require!(
-            operator_balance.usdc_balance >= taking_amount,
-            TerminatorError::InsufficientBalance
-        );
-        
-        // Execute transfers
-        let usdc_to_maker = taking_amount.saturating_sub(fee);
-        maker_balance.usdc_balance = maker_balance.usdc_balance
-            .checked_add(usdc_to_maker)
-            .ok_or(TerminatorError::ArithmeticOverflow)?;
-        
-        operator_balance.usdc_balance = operator_balance.usdc_balance
-            .checked_sub(taking_amount)
-            .ok_or(TerminatorError::InsufficientBalance)?;
-        
-        if order.token_id == token_id::YES {
-            maker_position.yes_balance = maker_position.yes_balance
-                .checked_sub(actual_fill)
-                .ok_or(TerminatorError::InsufficientOutcomeTokens)?;
-            operator_position.yes_balance = operator_position.yes_balance
-                .checked_add(actual_fill)
-                .ok_or(TerminatorError::ArithmeticOverflow)?;
-        } else {
-            maker_position.no_balance = maker_position.no_balance
-                .checked_sub(actual_fill)
-                .ok_or(TerminatorError::InsufficientOutcomeTokens)?;
-            operator_position.no_balance = operator_position.no_balance
-                .checked_add(actual_fill)
-                .ok_or(TerminatorError::ArithmeticOverflow)?;
-        }
-    }
+    execute_operator_fill(
+        order,
+        FillAmounts { making: actual_fill, taking: taking_amount, fee },
+        &mut ctx.accounts.maker_balance,
+        &mut ctx.accounts.maker_position,
+        &mut ctx.accounts.operator_balance,
+        &mut ctx.accounts.operator_position,
+    )?;
+    authorization.commit()?;
+    record_taker_volume(&ctx.accounts.global, maker_stats, clock.unix_timestamp, notional);
+    
+    // Collect the USDC fee into the platform treasury, then accrue the referral share
+    let usdc_fee = operator_fill_usdc_fee(order, fee);
+    collect_trading_fee(
+        &mut ctx.accounts.global,
+        &ctx.accounts.market,
+        &ctx.accounts.market_usdc_vault.to_account_info(),
+        &ctx.accounts.platform_treasury.to_account_info(),
+        &ctx.accounts.usdc_mint.to_account_info(),
+        &ctx.accounts.token_program.to_account_info(),
+        usdc_fee,
+    )?;
+    let referral_fee = accrue_referral_fee(
+        &ctx.accounts.global,
+        ctx.accounts.market.fee_override.as_ref(),
+        maker_referral.as_ref(),
+        ctx.accounts.market.key(),
+        usdc_fee,
+        clock.unix_timestamp,
+    )?;
+    ctx.accounts.global.reserve_referral_rewards(referral_fee)?;
     
     // ============================================
     // Update Market Stats
@@ -323,14 +365,15 @@ pub fn handler(ctx: Context<FillOrder>, params: FillOrderParams) -> Result<()> {
     
     let market = &mut ctx.accounts.market;
     market.record_activity(clock.unix_timestamp, clock.slot);
+    let refund_notional = if market.is_refund_eligible_fill(&order.maker, &ctx.accounts.operator.key()) {
+        notional
+    } else {
+        0
+    };
+    market.record_volume(notional, refund_notional)?;
     
-    // Calculate price for stats
-    let price = order.calculate_price();
-    if order.token_id == token_id::YES {
-        market.record_binary_last_price(0, price)?;
-    } else if order.token_id == token_id::NO {
-        market.record_binary_last_price(1, price)?;
-    }
+    // Record execution price for stats and stop triggers
+    market.record_token_last_price(order.token_id, order.calculate_price())?;
     
     market.total_trades = market.total_trades
         .checked_add(1)
@@ -358,3 +401,127 @@ pub fn handler(ctx: Context<FillOrder>, params: FillOrderParams) -> Result<()> {
     
     Ok(())
 }
+
+/// USDC part of an operator fill's fee
+/// 
+/// A SELL's fee is withheld from its USDC proceeds and can be collected into
+/// the platform treasury; a BUY's fee is withheld from the outcome tokens.
+/// BUY fees are therefore excluded: they are never moved to the treasury,
+/// counted in `total_trading_fees_collected` or shared with referrers.
+pub fn operator_fill_usdc_fee(order: &Order, fee: u64) -> u64 {
+    if order.is_sell() { fee } else { 0 }
+}
+
+/// Fee discount for a taker's current volume tier (scaled by 10^6)
+pub fn taker_discount_rate(global: &Global, stats: &UserStats, now: i64) -> u32 {
+    let tiers = &global.fee_tiers;
+    tiers.discount_rate(tiers.tier_for(stats.rolling_volume(now)))
+}
+
+/// Add a fill's notional to the taker's rolling volume and emit
+/// UserFeeTierChanged if that moves the taker to another tier
+pub fn record_taker_volume(global: &Global, stats: &mut UserStats, now: i64, notional: u64) {
+    stats.record_taker_volume(now, notional);
+    
+    let rolling_volume = stats.rolling_volume(now);
+    let new_tier = global.fee_tiers.tier_for(rolling_volume);
+    if new_tier != stats.tier {
+        emit!(UserFeeTierChanged {
+            user: stats.user,
+            old_tier: stats.tier,
+            new_tier,
+            rolling_volume,
+            timestamp: now,
+        });
+        msg!("Fee tier changed: {} -> {}", stats.tier, new_tier);
+        stats.tier = new_tier;
+    }
+}
+
+/// Amounts for a single order fill (maker_amount units, taker_amount units, fee)
+#[derive(Clone, Copy, Debug)]
+pub struct FillAmounts {
+    /// Amount filled from the order's maker side
+    pub making: u64,
+    /// Amount the order receives before fee
+    pub taking: u64,
+    /// Fee deducted from the order's proceeds
+    pub fee: u64,
+}
+
+/// Asset IDs (maker gives, maker receives) for an order
+pub fn order_asset_ids(order: &Order) -> (u8, u8) {
+    if order.is_buy() {
+        // Maker BUY: maker gives USDC (0), receives tokens (1 or 2)
+        (token_id::USDC, order.token_id)
+    } else {
+        // Maker SELL: maker gives tokens (1 or 2), receives USDC (0)
+        (order.token_id, token_id::USDC)
+    }
+}
+
+/// Execute an order fill against the operator as counterparty
+/// 
+/// All balances are computed first and only written once every check has
+/// passed, so a failed fill leaves the accounts untouched (used by batch fills).
+/// 
+/// - Maker BUY: maker pays `making` USDC, receives `taking - fee` tokens;
+///   operator receives `making` USDC, pays `taking` tokens
+/// - Maker SELL: maker pays `making` tokens, receives `taking - fee` USDC;
+///   operator receives `making` tokens, pays `taking` USDC
+pub fn execute_operator_fill(
+    order: &Order,
+    amounts: FillAmounts,
+    maker_balance: &mut UserBalance,
+    maker_position: &mut UserPosition,
+    operator_balance: &mut UserBalance,
+    operator_position: &mut UserPosition,
+) -> Result<()> {
+    let is_yes = order.token_id == token_id::YES;
+    let mut maker_usdc = maker_balance.usdc_balance;
+    let mut operator_usdc = operator_balance.usdc_balance;
+    let mut maker_tokens = if is_yes { maker_position.yes_balance } else { maker_position.no_balance };
+    let mut operator_tokens = if is_yes { operator_position.yes_balance } else { operator_position.no_balance };
+    let proceeds = amounts.taking.saturating_sub(amounts.fee);
+    
+    if order.is_buy() {
+        maker_usdc = maker_usdc
+            .checked_sub(amounts.making)
+            .ok_or(TerminatorError::InsufficientBalance)?;
+        operator_tokens = operator_tokens
+            .checked_sub(amounts.taking)
+            .ok_or(TerminatorError::InsufficientOutcomeTokens)?;
+        operator_usdc = operator_usdc
+            .checked_add(amounts.making)
+            .ok_or(TerminatorError::ArithmeticOverflow)?;
+        maker_tokens = maker_tokens
+            .checked_add(proceeds)
+            .ok_or(TerminatorError::ArithmeticOverflow)?;
+    } else {
+        maker_tokens = maker_tokens
+            .checked_sub(amounts.making)
+            .ok_or(TerminatorError::InsufficientOutcomeTokens)?;
+        operator_usdc = operator_usdc
+            .checked_sub(amounts.taking)
+            .ok_or(TerminatorError::InsufficientBalance)?;
+        maker_usdc = maker_usdc
+            .checked_add(proceeds)
+            .ok_or(TerminatorError::ArithmeticOverflow)?;
+        operator_tokens = operator_tokens
+            .checked_add(amounts.making)
+            .ok_or(TerminatorError::ArithmeticOverflow)?;
+    }
+    
+    // Commit
+    maker_balance.usdc_balance = maker_usdc;
+    operator_balance.usdc_balance = operator_usdc;
+    if is_yes {
+        maker_position.yes_balance = maker_tokens;
+        operator_position.yes_balance = operator_tokens;
+    } else {
+        maker_position.no_balance = maker_tokens;
+        operator_position.no_balance = operator_tokens;
+    }
+    
+    Ok(())
+}
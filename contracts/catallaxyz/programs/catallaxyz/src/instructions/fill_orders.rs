@@ -0,0 +1,624 @@
+//! Fill Orders Instruction (batch)
+//!
+//! Fills several independent signed orders in one market against the operator.
+//! Each order is processed exactly like `fill_order`; the operator is the
+//! counterparty for every fill, so each fill counts as taker volume in its
+//! maker's `UserStats` and gets the maker's volume tier discount. USDC fees of
+//! the filled orders are moved to the platform treasury in one transfer, and
+//! each maker's referrer accrues a share of that maker's fees.
+//!
+//! Semantics are selected by `all_or_nothing`:
+//! - true: any failing order aborts the whole instruction
+//! - false (best-effort): failing orders are skipped and logged, the rest fill
+//!
+//! Maker accounts use the compact layout from `order_accounts`. Signatures may be
+//! batched into one or more Ed25519 instructions anywhere before this instruction.
+
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::sysvar::instructions::ID as INSTRUCTIONS_ID;
+use anchor_spl::token_interface::{Mint, TokenAccount, TokenInterface};
+use crate::constants::{GLOBAL_SEED, MARKET_SEED, PLATFORM_TREASURY_SEED, PRICE_SCALE};
+use crate::errors::TerminatorError;
+use crate::events::OrderFilled;
+use crate::states::{
+    Global, Market, UserBalance, UserPosition, UserStats, RoleAssignment,
+    SignedOrder, hash_order, role,
+};
+use crate::instructions::calculator::{
+    apply_tier_discount, calculate_taking_amount, calculate_order_fee, validate_order, validate_stop_trigger, validate_taker,
+    CancellationScope,
+};
+use crate::instructions::ed25519_verify::{
+    load_preceding_ed25519_entries, require_order_signature, Ed25519Entry,
+};
+use crate::instructions::fill_order::{
+    execute_operator_fill, operator_fill_usdc_fee, order_asset_ids, record_taker_volume, taker_discount_rate,
+    FillAmounts,
+};
+use crate::instructions::referral::{accrue_referral_fee, load_referral_accounts};
+use crate::instructions::signer_auth::{authorize_order_signer, optional_account, order_notional};
+use crate::instructions::treasury_utils::collect_trading_fee;
+use crate::instructions::order_accounts::{
+    load_cancel_bitmap, load_makers, peek_order_status, peek_user_stats, save_order_status,
+    save_user_stats, MakerAccounts, ACCOUNTS_PER_MAKER, ACCOUNTS_PER_MAKER_FEE, ACCOUNTS_PER_MAKER_ORDER,
+};
+
+/// Maximum number of orders that can be filled in a single instruction
+pub const MAX_FILL_ORDERS: usize = 16;
+
+/// Parameters for fill_orders instruction
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug)]
+pub struct FillOrdersParams {
+    /// Signed orders from makers
+    pub orders: Vec<SignedOrder>,
+    /// Amount to fill for each order (in maker_amount units)
+    pub fill_amounts: Vec<u64>,
+    /// For each order, index of its maker group in remaining accounts
+    pub maker_indices: Vec<u8>,
+    /// Abort on the first failing order (true) or skip it (false)
+    pub all_or_nothing: bool,
+}
+
+#[derive(Accounts)]
+pub struct FillOrders<'info> {
+    /// Operator executing the fills (must hold the operator role)
+    #[account(mut)]
+    pub operator: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [GLOBAL_SEED.as_bytes()],
+        bump = global.bump,
+        constraint = global.is_trading_allowed() @ TerminatorError::TradingPaused,
+        constraint = global.has_role(&operator.key(), operator_role.as_deref(), role::OPERATOR)
+            @ TerminatorError::NotOperator,
+    )]
+    pub global: Box<Account<'info, Global>>,
+
+    /// Signer's operator role assignment (omitted when the signer is the authority)
+    #[account(
+        seeds = [RoleAssignment::SEED_PREFIX, &[role::OPERATOR], operator.key().as_ref()],
+        bump = operator_role.bump,
+    )]
+    pub operator_role: Option<Account<'info, RoleAssignment>>,
+
+    #[account(
+        mut,
+        seeds = [
+            MARKET_SEED.as_bytes(),
+            market.creator.as_ref(),
+            market.market_id.as_ref(),
+        ],
+        bump = market.bump,
+        constraint = market.global == global.key() @ TerminatorError::InvalidAccountInput,
+        constraint = market.can_trade() @ TerminatorError::MarketNotActive,
+    )]
+    pub market: Box<Account<'info, Market>>,
+
+    /// Operator's USDC balance (as counterparty)
+    #[account(
+        mut,
+        seeds = [b"user_balance", market.key().as_ref(), operator.key().as_ref()],
+        bump = operator_balance.bump,
+        constraint = operator_balance.user == operator.key() @ TerminatorError::Unauthorized,
+    )]
+    pub operator_balance: Box<Account<'info, UserBalance>>,
+
+    /// Operator's position (as counterparty)
+    #[account(
+        mut,
+        seeds = [b"user_position", market.key().as_ref(), operator.key().as_ref()],
+        bump = operator_position.bump,
+        constraint = operator_position.user == operator.key() @ TerminatorError::Unauthorized,
+    )]
+    pub operator_position: Box<Account<'info, UserPosition>>,
+
+    /// CHECK: instructions sysvar for Ed25519 verification
+    #[account(address = INSTRUCTIONS_ID)]
+    pub instructions: AccountInfo<'info>,
+
+    pub system_program: Program<'info, System>,
+
+    #[account(
+        mut,
+        seeds = [b"market_vault", market.key().as_ref()],
+        bump,
+    )]
+    pub market_usdc_vault: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    /// Platform treasury (receives USDC fees)
+    #[account(
+        mut,
+        seeds = [PLATFORM_TREASURY_SEED.as_bytes()],
+        bump = global.platform_treasury_bump,
+    )]
+    pub platform_treasury: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    #[account(address = global.usdc_mint @ TerminatorError::InvalidUsdcMint)]
+    pub usdc_mint: Box<InterfaceAccount<'info, Mint>>,
+
+    pub token_program: Interface<'info, TokenInterface>,
+    // Remaining accounts (compact layout, see order_accounts):
+    // For each UNIQUE maker (ACCOUNTS_PER_MAKER accounts):
+    // - maker (UncheckedAccount)
+    // - maker_nonce (UserNonce)
+    // - maker_market_nonce (MarketNonce PDA, may be uninitialized)
+    // - maker_balance (UserBalance)
+    // - maker_position (UserPosition)
+    // - signer_authorization (DelegateRegistry or SessionKey, or program ID if not needed)
+    // Then for each maker group, in the same order (ACCOUNTS_PER_MAKER_FEE accounts):
+    // - maker_stats (UserStats, created on the maker's first fill)
+    // - maker_referral (Referral PDA, may be uninitialized)
+    // - referrer_rewards (ReferrerRewards of the maker's referrer, or program ID if no referral)
+    // Then for each order (up to MAX_FILL_ORDERS):
+    // - order_status (OrderStatus, created if missing)
+    // - cancel_bitmap (maker's CancelBitmap page for the salt, may be uninitialized)
+}
+
+/// Shared state for processing one order of the batch
+struct BatchFillContext<'a, 'info> {
+    global: &'a Global,
+    operator: &'a AccountInfo<'info>,
+    system_program: &'a AccountInfo<'info>,
+    market: &'a mut Market,
+    market_key: Pubkey,
+    operator_balance: &'a mut UserBalance,
+    operator_position: &'a mut UserPosition,
+    signatures: &'a [Ed25519Entry],
+    accept_legacy: bool,
+    now: i64,
+}
+
+pub fn handler<'info>(
+    ctx: Context<'_, '_, 'info, 'info, FillOrders<'info>>,
+    params: FillOrdersParams,
+) -> Result<()> {
+    let clock = Clock::get()?;
+    let orders_count = params.orders.len();
+
+    // Validate counts
+    require!(orders_count > 0, TerminatorError::InvalidInput);
+    require!(orders_count <= MAX_FILL_ORDERS, TerminatorError::InvalidInput);
+    require!(
+        params.fill_amounts.len() == orders_count
+            && params.maker_indices.len() == orders_count,
+        TerminatorError::InvalidInput
+    );
+
+    // Each order may appear once (its status account is loaded once)
+    let order_hashes: Vec<[u8; 32]> = params.orders.iter()
+        .map(|signed_order| hash_order(&signed_order.order, &ctx.accounts.global.order_domain()))
+        .collect();
+    for (i, hash) in order_hashes.iter().enumerate() {
+        require!(
+            !order_hashes[..i].contains(hash),
+            TerminatorError::InvalidInput
+        );
+    }
+
+    // Collect all signatures verified by preceding Ed25519 instructions
+    let signatures = load_preceding_ed25519_entries(&ctx.accounts.instructions)?;
+
+    // ============================================
+    // Load Maker Accounts (compact layout)
+    // ============================================
+
+    let remaining_accounts: &'info [AccountInfo<'info>] = ctx.remaining_accounts;
+    let status_start = remaining_accounts
+        .len()
+        .checked_sub(orders_count * ACCOUNTS_PER_MAKER_ORDER)
+        .ok_or(TerminatorError::InvalidAccountInput)?;
+    require!(
+        status_start.is_multiple_of(ACCOUNTS_PER_MAKER + ACCOUNTS_PER_MAKER_FEE),
+        TerminatorError::InvalidAccountInput
+    );
+    let maker_count = status_start / (ACCOUNTS_PER_MAKER + ACCOUNTS_PER_MAKER_FEE);
+    require!(
+        maker_count > 0 && maker_count <= orders_count,
+        TerminatorError::InvalidAccountInput
+    );
+    let fee_start = maker_count * ACCOUNTS_PER_MAKER;
+
+    let operator_key = ctx.accounts.operator.key();
+    let market_key = ctx.accounts.market.key();
+    let mut makers = load_makers(
+        &remaining_accounts[..fee_start],
+        &market_key,
+        &[operator_key],
+    )?;
+    let fee_accounts: Vec<&'info [AccountInfo<'info>]> = remaining_accounts[fee_start..status_start]
+        .chunks(ACCOUNTS_PER_MAKER_FEE)
+        .collect();
+    // Stats are created only for makers with at least one fill
+    let mut maker_stats = fee_accounts.iter()
+        .zip(makers.iter())
+        .map(|(accounts, maker)| peek_user_stats(&accounts[0], &maker.maker))
+        .collect::<Result<Vec<_>>>()?;
+    let maker_referrals = fee_accounts.iter()
+        .zip(makers.iter())
+        .map(|(accounts, maker)| load_referral_accounts(&accounts[1], optional_account(&accounts[2]), &maker.maker))
+        .collect::<Result<Vec<_>>>()?;
+    let mut maker_used = vec![false; makers.len()];
+    let mut maker_filled = vec![false; makers.len()];
+
+    // ============================================
+    // Process Orders
+    // ============================================
+
+    let operator_info = ctx.accounts.operator.to_account_info();
+    let system_program_info = ctx.accounts.system_program.to_account_info();
+    let fee_override = ctx.accounts.market.fee_override;
+    let mut batch = BatchFillContext {
+        global: &ctx.accounts.global,
+        operator: &operator_info,
+        system_program: &system_program_info,
+        market: &mut ctx.accounts.market,
+        market_key,
+        operator_balance: &mut ctx.accounts.operator_balance,
+        operator_position: &mut ctx.accounts.operator_position,
+        signatures: &signatures,
+        accept_legacy: ctx.accounts.global.accept_legacy_order_hash,
+        now: clock.unix_timestamp,
+    };
+
+    let mut filled_count = 0u64;
+    let mut filled_notional = 0u64;
+    let mut refund_notional = 0u64;
+    let mut collected_fee = 0u64;
+    let mut accrued_referral_fee = 0u64;
+    for (i, signed_order) in params.orders.iter().enumerate() {
+        let maker_index = params.maker_indices[i] as usize;
+        require!(maker_index < makers.len(), TerminatorError::InvalidAccountInput);
+        maker_used[maker_index] = true;
+
+        let result = fill_one(
+            &mut batch,
+            signed_order,
+            params.fill_amounts[i],
+            &order_hashes[i],
+            &mut makers[maker_index],
+            &mut maker_stats[maker_index].stats,
+            &remaining_accounts[status_start + i * ACCOUNTS_PER_MAKER_ORDER..][..ACCOUNTS_PER_MAKER_ORDER],
+        );
+
+        match result {
+            Ok(amounts) => {
+                filled_count += 1;
+                maker_filled[maker_index] = true;
+                let notional = order_notional(&signed_order.order, amounts.making, amounts.taking);
+                filled_notional = filled_notional
+                    .checked_add(notional)
+                    .ok_or(TerminatorError::ArithmeticOverflow)?;
+                if batch.market.is_refund_eligible_fill(&signed_order.order.maker, &operator_key) {
+                    refund_notional = refund_notional
+                        .checked_add(notional)
+                        .ok_or(TerminatorError::ArithmeticOverflow)?;
+                }
+                let usdc_fee = operator_fill_usdc_fee(&signed_order.order, amounts.fee);
+                collected_fee = collected_fee
+                    .checked_add(usdc_fee)
+                    .ok_or(TerminatorError::ArithmeticOverflow)?;
+                let referral_fee = accrue_referral_fee(
+                    batch.global,
+                    fee_override.as_ref(),
+                    maker_referrals[maker_index].as_ref(),
+                    market_key,
+                    usdc_fee,
+                    clock.unix_timestamp,
+                )?;
+                accrued_referral_fee = accrued_referral_fee
+                    .checked_add(referral_fee)
+                    .ok_or(TerminatorError::ArithmeticOverflow)?;
+                let (maker_asset_id, taker_asset_id) = order_asset_ids(&signed_order.order);
+                emit!(OrderFilled {
+                    order_hash: order_hashes[i],
+                    maker: signed_order.order.maker,
+                    taker: operator_key,
+                    maker_asset_id,
+                    taker_asset_id,
+                    maker_amount_filled: amounts.making,
+                    taker_amount_filled: amounts.taking,
+                    fee: amounts.fee,
+                    market: market_key,
+                    slot: clock.slot,
+                    timestamp: clock.unix_timestamp,
+                });
+            }
+            Err(err) if !params.all_or_nothing => {
+                msg!("Skipping order {}: {}", i, err);
+            }
+            Err(err) => return Err(err),
+        }
+    }
+
+    // Every maker group must be referenced by at least one order
+    require!(
+        maker_used.iter().all(|used| *used),
+        TerminatorError::InvalidAccountInput
+    );
+
+    // Save maker accounts back (once per unique maker)
+    for maker in makers.iter() {
+        maker.exit()?;
+    }
+    for (i, stats) in maker_stats.iter().enumerate() {
+        if maker_filled[i] {
+            save_user_stats(
+                &fee_accounts[i][0],
+                stats,
+                &operator_info,
+                &system_program_info,
+            )?;
+        }
+    }
+
+    // Collect the USDC fees of all fills into the platform treasury
+    collect_trading_fee(
+        &mut ctx.accounts.global,
+        &ctx.accounts.market,
+        &ctx.accounts.market_usdc_vault.to_account_info(),
+        &ctx.accounts.platform_treasury.to_account_info(),
+        &ctx.accounts.usdc_mint.to_account_info(),
+        &ctx.accounts.token_program.to_account_info(),
+        collected_fee,
+    )?;
+    ctx.accounts.global.reserve_referral_rewards(accrued_referral_fee)?;
+
+    // ============================================
+    // Update Market Stats
+    // ============================================
+
+    if filled_count > 0 {
+        let market = &mut ctx.accounts.market;
+        market.record_activity(clock.unix_timestamp, clock.slot);
+        market.record_volume(filled_notional, refund_notional)?;
+        market.total_trades = market.total_trades
+            .checked_add(filled_count)
+            .ok_or(TerminatorError::ArithmeticOverflow)?;
+    }
+
+    msg!("Filled {} of {} orders", filled_count, orders_count);
+
+    Ok(())
+}
+
+/// Validate and fill a single order of the batch
+///
+/// The fill is dry-run on copies of the balances and order status; the order
+/// status is created and state is written only once every check (signer
+/// authorization, fee, balances) has passed, so an order skipped in
+/// best-effort mode leaves no account or partial state behind.
+fn fill_one<'info>(
+    batch: &mut BatchFillContext<'_, 'info>,
+    signed_order: &SignedOrder,
+    fill_amount: u64,
+    order_hash: &[u8; 32],
+    maker: &mut MakerAccounts<'info>,
+    maker_stats: &mut UserStats,
+    order_accounts: &'info [AccountInfo<'info>],
+) -> Result<FillAmounts> {
+    let order = &signed_order.order;
+
+    // Verify maker group matches order
+    require!(maker.maker == order.maker, TerminatorError::InvalidAccountInput);
+
+    let cancel_bitmap = load_cancel_bitmap(&order_accounts[1], &order.maker, order.salt)?;
+    validate_order(
+        order,
+        order_hash,
+        batch.now,
+        &CancellationScope {
+            user_nonce: maker.nonce.current_nonce,
+            market_nonce: maker.market_nonce.as_ref(),
+            cancel_bitmap: cancel_bitmap.as_ref(),
+        },
+    )?;
+    require!(order.market == batch.market_key, TerminatorError::InvalidMarket);
+    validate_taker(order, batch.operator.key)?;
+    validate_stop_trigger(order, batch.market)?;
+
+    require_order_signature(
+        batch.signatures,
+        order,
+        order_hash,
+        batch.accept_legacy,
+        &signed_order.signature,
+    )?;
+
+    let mut order_status = peek_order_status(
+        &order_accounts[0],
+        order_hash,
+        order.maker_amount,
+        batch.operator.key,
+    )?;
+    require!(order_status.status.is_fillable(), TerminatorError::OrderNotFillable);
+
+    // Calculate amounts (fill capped at remaining)
+    let making = fill_amount.min(order_status.status.remaining);
+    require!(making > 0, TerminatorError::InvalidAmount);
+    let taking = calculate_taking_amount(making, order.maker_amount, order.taker_amount)?;
+    // Fee (charged on proceeds), less the maker's volume tier discount
+    let fee = apply_tier_discount(
+        calculate_order_fee(order, taking, batch.market.fee_override.as_ref())?,
+        taker_discount_rate(batch.global, maker_stats, batch.now),
+    )?;
+    let amounts = FillAmounts { making, taking, fee };
+    let price = order.calculate_price();
+    require!(price <= PRICE_SCALE, TerminatorError::InvalidInput);
+
+    // Signer must be the maker or an authorized delegate (spend tracked in USDC)
+    let notional = order_notional(order, making, taking);
+    let authorization = authorize_order_signer(
+        order,
+        maker.authorization,
+        batch.now,
+        notional,
+    )?;
+
+    // Dry-run the fill on copies
+    let mut maker_balance = (*maker.balance).clone();
+    let mut maker_position = (*maker.position).clone();
+    let mut operator_balance = batch.operator_balance.clone();
+    let mut operator_position = batch.operator_position.clone();
+    execute_operator_fill(
+        order,
+        amounts,
+        &mut maker_balance,
+        &mut maker_position,
+        &mut operator_balance,
+        &mut operator_position,
+    )?;
+    order_status.status.fill(making)?;
+
+    // Every check passed: create the order status and write state
+    save_order_status(&order_accounts[0], &order_status, batch.operator, batch.system_program)?;
+    authorization.commit()?;
+    *maker.balance = maker_balance;
+    *maker.position = maker_position;
+    *batch.operator_balance = operator_balance;
+    *batch.operator_position = operator_position;
+    record_taker_volume(batch.global, maker_stats, batch.now, notional);
+
+    // Record execution price for stats and stop triggers
+    batch.market.record_token_last_price(order.token_id, price)?;
+
+    Ok(amounts)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_utils::{global, leak_account, serialized};
+    use crate::states::{find_cancel_bitmap_pda, token_id, Order, OrderStatus, UserNonce, STATS_WINDOW_DAYS};
+
+    fn program_account<T: AccountSerialize>(value: &T) -> &'static AccountInfo<'static> {
+        leak_account(Pubkey::new_unique(), crate::ID, 1_000_000, serialized(value))
+    }
+
+    fn buy_yes(maker: Pubkey, market: Pubkey, salt: u64, maker_amount: u64) -> Order {
+        Order {
+            salt,
+            maker,
+            signer: maker,
+            taker: Pubkey::default(),
+            market,
+            token_id: token_id::YES,
+            maker_amount,
+            taker_amount: maker_amount * 2,
+            expiration: 0,
+            nonce: 0,
+            fee_rate_bps: 0,
+            side: 0,
+            trigger_price: 0,
+            trigger_direction: 0,
+        }
+    }
+
+    /// Status and cancel bitmap accounts for one order
+    fn order_accounts(
+        order: &Order,
+        order_hash: &[u8; 32],
+        status: Option<&OrderStatus>,
+    ) -> &'static [AccountInfo<'static>] {
+        let (status_key, _) = Pubkey::find_program_address(&[OrderStatus::SEED_PREFIX, order_hash], &crate::ID);
+        let status_info = match status {
+            Some(status) => leak_account(status_key, crate::ID, 1_000_000, serialized(status)),
+            None => leak_account(status_key, anchor_lang::system_program::ID, 0, Vec::new()),
+        };
+        let (bitmap_key, _) = find_cancel_bitmap_pda(&order.maker, order.salt, &crate::ID);
+        let bitmap_info = leak_account(bitmap_key, anchor_lang::system_program::ID, 0, Vec::new());
+        Box::leak(vec![status_info.clone(), bitmap_info.clone()].into_boxed_slice())
+    }
+
+    #[test]
+    fn test_best_effort_skip_leaves_no_state() {
+        let maker_key = Pubkey::new_unique();
+        let operator_key = Pubkey::new_unique();
+        let market_key = Pubkey::new_unique();
+
+        let mut maker = MakerAccounts {
+            maker: maker_key,
+            nonce: Account::try_from(program_account(&UserNonce { user: maker_key, current_nonce: 0, bump: 0 })).unwrap(),
+            market_nonce: None,
+            balance: Account::try_from(program_account(&UserBalance {
+                user: maker_key, market: market_key, usdc_balance: 1_000, bump: 0,
+            })).unwrap(),
+            position: Account::try_from(program_account(&UserPosition {
+                user: maker_key, market: market_key, yes_balance: 0, no_balance: 0, bump: 0,
+            })).unwrap(),
+            authorization: None,
+        };
+
+        // Order A has a partially filled status; order B's status does not exist yet
+        let order_a = buy_yes(maker_key, market_key, 1, 500);
+        let order_b = buy_yes(maker_key, market_key, 2, 5_000);
+        let (hash_a, hash_b) = ([1u8; 32], [2u8; 32]);
+        let status_a = OrderStatus {
+            order_hash: hash_a,
+            is_filled_or_cancelled: false,
+            remaining: 500,
+            payer: operator_key,
+            bump: 0,
+        };
+        let accounts_a = order_accounts(&order_a, &hash_a, Some(&status_a));
+        let accounts_b = order_accounts(&order_b, &hash_b, None);
+
+        let signature = [9u8; 64];
+        let signatures = vec![
+            Ed25519Entry { pubkey: maker_key, signature, message: hash_a.to_vec() },
+            Ed25519Entry { pubkey: maker_key, signature, message: hash_b.to_vec() },
+        ];
+
+        let operator_info = leak_account(operator_key, anchor_lang::system_program::ID, 1_000_000_000, Vec::new());
+        let system_program_info = leak_account(
+            anchor_lang::system_program::ID, Pubkey::default(), 1, Vec::new(),
+        );
+        let global = global(Pubkey::new_unique());
+        let mut maker_stats = UserStats {
+            user: maker_key, last_day: 0, daily_taker_volume: [0; STATS_WINDOW_DAYS], tier: 0, bump: 0,
+        };
+        let mut market = Market::try_deserialize_unchecked(&mut &vec![0u8; Market::INIT_SPACE][..]).unwrap();
+        let mut operator_balance = UserBalance { user: operator_key, market: market_key, usdc_balance: 0, bump: 0 };
+        let mut operator_position = UserPosition {
+            user: operator_key, market: market_key, yes_balance: 100_000, no_balance: 0, bump: 0,
+        };
+        let mut batch = BatchFillContext {
+            global: &global,
+            operator: operator_info,
+            system_program: system_program_info,
+            market: &mut market,
+            market_key,
+            operator_balance: &mut operator_balance,
+            operator_position: &mut operator_position,
+            signatures: &signatures,
+            accept_legacy: false,
+            now: 1_700_000_000,
+        };
+
+        let signed_a = SignedOrder { order: order_a, signature };
+        let amounts = fill_one(&mut batch, &signed_a, 500, &hash_a, &mut maker, &mut maker_stats, accounts_a).unwrap();
+        assert_eq!((amounts.making, amounts.taking), (500, 1_000));
+
+        // B passes signature and status checks, then fails the balance check:
+        // its status must not have been created and no balance may move
+        let signed_b = SignedOrder { order: order_b, signature };
+        assert_eq!(
+            fill_one(&mut batch, &signed_b, 5_000, &hash_b, &mut maker, &mut maker_stats, accounts_b).unwrap_err(),
+            error!(TerminatorError::InsufficientBalance)
+        );
+        assert_eq!(accounts_b[0].lamports(), 0);
+        assert!(accounts_b[0].data_is_empty());
+        assert_eq!(accounts_b[0].owner, &anchor_lang::system_program::ID);
+
+        assert_eq!(maker.balance.usdc_balance, 500);
+        assert_eq!(maker.position.yes_balance, 1_000);
+        assert_eq!(batch.operator_balance.usdc_balance, 500);
+        assert_eq!(batch.operator_position.yes_balance, 99_000);
+        assert_eq!(maker_stats.rolling_volume(1_700_000_000), 500);
+
+        let data = accounts_a[0].try_borrow_data().unwrap();
+        let status_a = OrderStatus::try_deserialize(&mut &data[..]).unwrap();
+        assert_eq!(status_a.remaining, 0);
+        assert!(!status_a.is_fillable());
+    }
+}
@@ -7,7 +7,7 @@ use anchor_lang::prelude::*;
 use crate::constants::GLOBAL_SEED;
 use crate::errors::TerminatorError;
 use crate::events::{GlobalTradingPaused, GlobalTradingUnpaused};
-use crate::states::Global;
+use crate::states::{role, Global, RoleAssignment};
 
 // ============================================
 // Pause Trading
@@ -15,9 +15,10 @@ use crate::states::Global;
 
 #[derive(Accounts)]
 pub struct PauseTrading<'info> {
-    /// Admin (authority)
+    /// Pauser (or authority)
     #[account(
-        constraint = admin.key() == global.authority @ TerminatorError::NotAdmin
+        constraint = global.has_role(&admin.key(), pauser_role.as_deref(), role::PAUSER)
+            @ TerminatorError::NotAdmin
     )]
     pub admin: Signer<'info>,
 
@@ -27,6 +28,13 @@ pub struct PauseTrading<'info> {
         bump = global.bump,
     )]
     pub global: Account<'info, Global>,
+
+    /// Signer's pauser role assignment (omitted when the signer is the authority)
+    #[account(
+        seeds = [RoleAssignment::SEED_PREFIX, &[role::PAUSER], admin.key().as_ref()],
+        bump = pauser_role.bump,
+    )]
+    pub pauser_role: Option<Account<'info, RoleAssignment>>,
 }
 
 pub fn handler_pause_trading(ctx: Context<PauseTrading>) -> Result<()> {
@@ -59,9 +67,10 @@ pub fn handler_pause_trading(ctx: Context<PauseTrading>) -> Result<()> {
 
 #[derive(Accounts)]
 pub struct UnpauseTrading<'info> {
-    /// Admin (authority)
+    /// Pauser (or authority)
     #[account(
-        constraint = admin.key() == global.authority @ TerminatorError::NotAdmin
+        constraint = global.has_role(&admin.key(), pauser_role.as_deref(), role::PAUSER)
+            @ TerminatorError::NotAdmin
     )]
     pub admin: Signer<'info>,
 
@@ -71,6 +80,13 @@ pub struct UnpauseTrading<'info> {
         bump = global.bump,
     )]
     pub global: Account<'info, Global>,
+
+    /// Signer's pauser role assignment (omitted when the signer is the authority)
+    #[account(
+        seeds = [RoleAssignment::SEED_PREFIX, &[role::PAUSER], admin.key().as_ref()],
+        bump = pauser_role.bump,
+    )]
+    pub pauser_role: Option<Account<'info, RoleAssignment>>,
 }
 
 pub fn handler_unpause_trading(ctx: Context<UnpauseTrading>) -> Result<()> {
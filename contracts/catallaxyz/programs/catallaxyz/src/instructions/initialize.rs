@@ -1,12 +1,14 @@
 use anchor_lang::prelude::*;
-use crate::constants::GLOBAL_SEED;
-use crate::states::global::{Global, default_fees};
+use crate::constants::{GLOBAL_SEED, MARKET_CREATION_FEE};
+use crate::errors::TerminatorError;
+use crate::states::global::{Global, default_fees, DEFAULT_GOVERNANCE_DELAY, LEGACY_MAX_OPERATORS};
+use crate::states::{cluster, FeeCurve, FeeTierTable};
 
 #[derive(AnchorSerialize, AnchorDeserialize)]
 pub struct InitializeParams {
     pub usdc_mint: Pubkey,
-    /// Optional keeper wallet for automated tasks. If None, defaults to authority.
-    pub keeper: Option<Pubkey>,
+    /// Cluster tag bound into order hashes (see `order_types::cluster`)
+    pub cluster: u8,
 }
 
 #[derive(Accounts)]
@@ -27,15 +29,16 @@ pub struct Initialize<'info> {
 }
 
 pub fn handler(ctx: Context<Initialize>, params: InitializeParams) -> Result<()> {
+    require!(cluster::is_valid(params.cluster), TerminatorError::InvalidInput);
+    
     let global = &mut ctx.accounts.global;
     global.authority = ctx.accounts.authority.key();
     global.usdc_mint = params.usdc_mint;
-    // Set keeper to provided value or default to authority
-    global.keeper = params.keeper.unwrap_or(ctx.accounts.authority.key());
     global.bump = ctx.bumps.global;
     global.platform_treasury_bump = 0; // Will be set by init_platform_treasury
     global.total_trading_fees_collected = 0;
     global.total_creation_fees_collected = 0;
+    global.layout_version = Global::LAYOUT_VERSION;
     
     // Initialize global fee rates with defaults
     global.center_taker_fee_rate = default_fees::CENTER_TAKER_FEE_RATE;
@@ -43,6 +46,9 @@ pub fn handler(ctx: Context<Initialize>, params: InitializeParams) -> Result<()>
     global.platform_fee_rate = default_fees::PLATFORM_FEE_RATE;
     global.maker_rebate_rate = default_fees::MAKER_REBATE_RATE;
     global.creator_incentive_rate = default_fees::CREATOR_INCENTIVE_RATE;
+    global.fee_curve = FeeCurve::default();
+    global.fee_tiers = FeeTierTable::default();
+    global.referral_share_rate = default_fees::REFERRAL_SHARE_RATE;
     
     // ============================================
     // Exchange (Polymarket-style) Initialization
@@ -51,9 +57,40 @@ pub fn handler(ctx: Context<Initialize>, params: InitializeParams) -> Result<()>
     // Trading is enabled by default
     global.trading_paused = false;
     
-    // No operators initially (authority is always an implicit operator)
-    global.operator_count = 0;
-    global.operators = [Pubkey::default(); 10];
+    // No roles granted initially; the authority implicitly holds every role
+    // (keepers/operators are added with grant_role)
+    global.legacy_keeper = Pubkey::default();
+    global.legacy_operator_count = 0;
+    global.legacy_operators = [Pubkey::default(); LEGACY_MAX_OPERATORS];
+    
+    // Orders are signed over the structured domain; legacy hashes are off
+    global.cluster = params.cluster;
+    global.accept_legacy_order_hash = false;
+    
+    // No authority transfer pending, no timelock
+    global.pending_authority = Pubkey::default();
+    global.pending_authority_eta = 0;
+    global.authority_transfer_delay = 0;
+    
+    // Fee and market parameter changes are timelocked by default
+    global.governance_delay = DEFAULT_GOVERNANCE_DELAY;
+    global.pending_fee_rates = None;
+    global.pending_fee_tiers = FeeTierTable::default();
+    global.pending_fee_rates_eta = 0;
+    global.pending_governance_delay = None;
+    global.pending_governance_delay_eta = 0;
+    global.pending_authority_transfer_delay = None;
+    global.pending_authority_transfer_delay_eta = 0;
+    
+    // Market creation fee in USDC; no refunds or alternative token until configured
+    global.creation_fee = MARKET_CREATION_FEE;
+    global.creation_fee_refund_volume = 0;
+    global.alt_fee_mint = Pubkey::default();
+    global.alt_fee_recipient = Pubkey::default();
+    global.alt_fee_rate = 0;
+    
+    // No referral rewards accrued yet
+    global.referral_rewards_outstanding = 0;
 
     Ok(())
 }
@@ -0,0 +1,233 @@
+//! Market Fee Override Instructions
+//!
+//! Per-market replacement for the global fee curve and split, e.g. zero-fee
+//! promotional markets or high-fee exotic markets. Like global fee changes,
+//! overrides are timelocked by `Global::governance_delay`:
+//! 1. the fee admin (any valid rates) or the market creator (rates within the
+//!    global configuration, see `FeeRates::is_within`) proposes an override,
+//!    or None to clear it
+//! 2. anyone executes it once the eta has passed
+//!
+//! The fee admin or the creator can cancel a pending proposal. The proposer of
+//! the pending and the current override is recorded: the creator cannot
+//! cancel, replace or clear an override the fee admin proposed.
+//!
+//! Fill paths charge the override curve on the proceeds. Orders whose signed
+//! fee rate does not cover the override are rejected with
+//! `OrderFeeBelowMarketFee` (see `calculate_order_fee`).
+
+use anchor_lang::prelude::*;
+use crate::constants::GLOBAL_SEED;
+use crate::errors::TerminatorError;
+use crate::events::{
+    MarketFeeOverrideProposalCancelled, MarketFeeOverrideProposed, MarketFeeOverrideUpdated,
+};
+use crate::states::{role, FeeRates, Global, Market, RoleAssignment};
+
+// ============================================
+// Propose Market Fee Override
+// ============================================
+
+#[derive(Accounts)]
+pub struct ProposeMarketFeeOverride<'info> {
+    /// Fee admin (or authority) or market creator
+    #[account(
+        constraint = proposer.key() == market.creator
+            || global.has_role(&proposer.key(), fee_admin_role.as_deref(), role::FEE_ADMIN)
+            @ TerminatorError::Unauthorized
+    )]
+    pub proposer: Signer<'info>,
+
+    #[account(
+        seeds = [GLOBAL_SEED.as_bytes()],
+        bump = global.bump,
+    )]
+    pub global: Account<'info, Global>,
+
+    /// Signer's fee admin role assignment (omitted for the authority or the creator)
+    #[account(
+        seeds = [RoleAssignment::SEED_PREFIX, &[role::FEE_ADMIN], proposer.key().as_ref()],
+        bump = fee_admin_role.bump,
+    )]
+    pub fee_admin_role: Option<Account<'info, RoleAssignment>>,
+
+    #[account(
+        mut,
+        constraint = market.global == global.key() @ TerminatorError::InvalidAccountInput,
+        constraint = market.is_active() @ TerminatorError::MarketNotActive,
+    )]
+    pub market: Account<'info, Market>,
+}
+
+/// Parameters for propose_market_fee_override instruction
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug)]
+pub struct ProposeMarketFeeOverrideParams {
+    /// New override; None reverts the market to the global rates
+    pub fee_override: Option<FeeRates>,
+}
+
+pub fn handler_propose_market_fee_override(
+    ctx: Context<ProposeMarketFeeOverride>,
+    params: ProposeMarketFeeOverrideParams,
+) -> Result<()> {
+    let clock = Clock::get()?;
+    let global = &ctx.accounts.global;
+    let proposer = ctx.accounts.proposer.key();
+    let is_fee_admin = global.has_role(
+        &proposer,
+        ctx.accounts.fee_admin_role.as_deref(),
+        role::FEE_ADMIN,
+    );
+    let market = &mut ctx.accounts.market;
+
+    if !is_fee_admin {
+        // Creators cannot replace or clear the fee admin's overrides
+        require!(
+            market.pending_fee_override_eta == 0
+                || market.pending_fee_override_proposer == market.creator,
+            TerminatorError::FeeOverrideSetByFeeAdmin
+        );
+        require!(
+            market.fee_override_proposer == Pubkey::default()
+                || market.fee_override_proposer == market.creator,
+            TerminatorError::FeeOverrideSetByFeeAdmin
+        );
+    }
+
+    if let Some(rates) = &params.fee_override {
+        rates.validate()?;
+
+        // Creators can only propose rates within the global configuration
+        require!(
+            is_fee_admin || rates.is_within(&global.fee_rates()),
+            TerminatorError::FeeOverrideOutOfBounds
+        );
+    }
+
+    let eta = clock.unix_timestamp
+        .checked_add(global.governance_delay)
+        .ok_or(TerminatorError::ArithmeticOverflow)?;
+    market.pending_fee_override = params.fee_override;
+    market.pending_fee_override_eta = eta;
+    market.pending_fee_override_proposer = proposer;
+
+    emit!(MarketFeeOverrideProposed {
+        market: market.key(),
+        proposed_by: proposer,
+        fee_override: params.fee_override,
+        eta,
+        timestamp: clock.unix_timestamp,
+    });
+
+    msg!("Market fee override proposed: {} (eta {})", market.key(), eta);
+
+    Ok(())
+}
+
+// ============================================
+// Execute Market Fee Override (permissionless)
+// ============================================
+
+#[derive(Accounts)]
+pub struct ExecuteMarketFeeOverride<'info> {
+    /// Anyone can execute a proposal once its timelock has passed
+    pub executor: Signer<'info>,
+
+    #[account(
+        mut,
+        constraint = market.pending_fee_override_eta != 0 @ TerminatorError::NoPendingProposal,
+    )]
+    pub market: Account<'info, Market>,
+}
+
+pub fn handler_execute_market_fee_override(ctx: Context<ExecuteMarketFeeOverride>) -> Result<()> {
+    let market = &mut ctx.accounts.market;
+    let clock = Clock::get()?;
+
+    require!(
+        clock.unix_timestamp >= market.pending_fee_override_eta,
+        TerminatorError::GovernanceTimelockActive
+    );
+
+    market.fee_override = market.pending_fee_override.take();
+    market.pending_fee_override_eta = 0;
+    market.fee_override_proposer = market.pending_fee_override_proposer;
+    market.pending_fee_override_proposer = Pubkey::default();
+
+    emit!(MarketFeeOverrideUpdated {
+        market: market.key(),
+        updated_by: ctx.accounts.executor.key(),
+        fee_override: market.fee_override,
+        timestamp: clock.unix_timestamp,
+    });
+
+    msg!("Market fee override updated: {}", market.key());
+
+    Ok(())
+}
+
+// ============================================
+// Cancel Market Fee Override
+// ============================================
+
+#[derive(Accounts)]
+pub struct CancelMarketFeeOverride<'info> {
+    /// Fee admin (or authority) or market creator
+    #[account(
+        constraint = authority.key() == market.creator
+            || global.has_role(&authority.key(), fee_admin_role.as_deref(), role::FEE_ADMIN)
+            @ TerminatorError::Unauthorized
+    )]
+    pub authority: Signer<'info>,
+
+    #[account(
+        seeds = [GLOBAL_SEED.as_bytes()],
+        bump = global.bump,
+    )]
+    pub global: Account<'info, Global>,
+
+    /// Signer's fee admin role assignment (omitted for the authority or the creator)
+    #[account(
+        seeds = [RoleAssignment::SEED_PREFIX, &[role::FEE_ADMIN], authority.key().as_ref()],
+        bump = fee_admin_role.bump,
+    )]
+    pub fee_admin_role: Option<Account<'info, RoleAssignment>>,
+
+    #[account(
+        mut,
+        constraint = market.global == global.key() @ TerminatorError::InvalidAccountInput,
+        constraint = market.pending_fee_override_eta != 0 @ TerminatorError::NoPendingProposal,
+    )]
+    pub market: Account<'info, Market>,
+}
+
+pub fn handler_cancel_market_fee_override(ctx: Context<CancelMarketFeeOverride>) -> Result<()> {
+    let authority = ctx.accounts.authority.key();
+    let is_fee_admin = ctx.accounts.global.has_role(
+        &authority,
+        ctx.accounts.fee_admin_role.as_deref(),
+        role::FEE_ADMIN,
+    );
+    let market = &mut ctx.accounts.market;
+    let clock = Clock::get()?;
+
+    // Creators can only cancel their own proposals
+    require!(
+        is_fee_admin || market.pending_fee_override_proposer == market.creator,
+        TerminatorError::FeeOverrideSetByFeeAdmin
+    );
+
+    market.pending_fee_override = None;
+    market.pending_fee_override_eta = 0;
+    market.pending_fee_override_proposer = Pubkey::default();
+
+    emit!(MarketFeeOverrideProposalCancelled {
+        market: market.key(),
+        cancelled_by: authority,
+        timestamp: clock.unix_timestamp,
+    });
+
+    msg!("Pending market fee override cancelled: {}", market.key());
+
+    Ok(())
+}
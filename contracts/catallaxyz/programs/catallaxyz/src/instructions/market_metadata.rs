@@ -0,0 +1,173 @@
+//! Market Metadata Instructions
+//!
+//! The creator owns a market's question and descriptions:
+//! - `update_market_metadata` edits them while the market has no trades and
+//!   no positions (see `Market::is_metadata_editable`)
+//! - `append_market_clarification` appends to the bounded, append-only
+//!   `MarketClarifications` log once the metadata is frozen
+//!
+//! Both emit events carrying the new text so traders can follow the history.
+
+use anchor_lang::prelude::*;
+use crate::constants::{MARKET_SEED, MAX_DESCRIPTION_LEN, MAX_OUTCOME_DESCRIPTION_LEN, MAX_QUESTION_LEN};
+use crate::errors::TerminatorError;
+use crate::events::{MarketClarificationAdded, MarketMetadataUpdated};
+use crate::states::{Market, MarketClarifications};
+
+// ============================================
+// Update Market Metadata
+// ============================================
+
+#[derive(Accounts)]
+pub struct UpdateMarketMetadata<'info> {
+    pub creator: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [
+            MARKET_SEED.as_bytes(),
+            market.creator.as_ref(),
+            market.market_id.as_ref(),
+        ],
+        bump = market.bump,
+        constraint = market.creator == creator.key() @ TerminatorError::Unauthorized,
+        constraint = market.is_metadata_editable() @ TerminatorError::MarketMetadataFrozen,
+    )]
+    pub market: Account<'info, Market>,
+}
+
+/// Parameters for update_market_metadata instruction (None = unchanged)
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug)]
+pub struct UpdateMarketMetadataParams {
+    pub question: Option<String>,
+    pub description: Option<String>,
+    pub yes_description: Option<String>,
+    pub no_description: Option<String>,
+}
+
+pub fn handler_update_market_metadata(
+    ctx: Context<UpdateMarketMetadata>,
+    params: UpdateMarketMetadataParams,
+) -> Result<()> {
+    require!(
+        params.question.is_some()
+            || params.description.is_some()
+            || params.yes_description.is_some()
+            || params.no_description.is_some(),
+        TerminatorError::InvalidInput
+    );
+    require!(
+        params.question.as_ref().is_none_or(|q| q.len() <= MAX_QUESTION_LEN),
+        TerminatorError::InvalidInput
+    );
+    require!(
+        params.description.as_ref().is_none_or(|d| d.len() <= MAX_DESCRIPTION_LEN),
+        TerminatorError::InvalidInput
+    );
+    require!(
+        params.yes_description.as_ref().is_none_or(|d| d.len() <= MAX_OUTCOME_DESCRIPTION_LEN),
+        TerminatorError::InvalidInput
+    );
+    require!(
+        params.no_description.as_ref().is_none_or(|d| d.len() <= MAX_OUTCOME_DESCRIPTION_LEN),
+        TerminatorError::InvalidInput
+    );
+
+    let clock = Clock::get()?;
+    let market = &mut ctx.accounts.market;
+    if let Some(question) = params.question {
+        market.question = question;
+    }
+    if let Some(description) = params.description {
+        market.description = description;
+    }
+    if let Some(yes_description) = params.yes_description {
+        market.yes_description = yes_description;
+    }
+    if let Some(no_description) = params.no_description {
+        market.no_description = no_description;
+    }
+
+    emit!(MarketMetadataUpdated {
+        market: market.key(),
+        creator: market.creator,
+        question: market.question.clone(),
+        description: market.description.clone(),
+        yes_description: market.yes_description.clone(),
+        no_description: market.no_description.clone(),
+        timestamp: clock.unix_timestamp,
+    });
+
+    msg!("Market metadata updated: {}", market.key());
+
+    Ok(())
+}
+
+// ============================================
+// Append Market Clarification
+// ============================================
+
+#[derive(Accounts)]
+pub struct AppendMarketClarification<'info> {
+    /// Market creator (pays for the clarification log)
+    #[account(mut)]
+    pub creator: Signer<'info>,
+
+    #[account(
+        seeds = [
+            MARKET_SEED.as_bytes(),
+            market.creator.as_ref(),
+            market.market_id.as_ref(),
+        ],
+        bump = market.bump,
+        constraint = market.creator == creator.key() @ TerminatorError::Unauthorized,
+        constraint = market.is_active() @ TerminatorError::MarketNotActive,
+    )]
+    pub market: Account<'info, Market>,
+
+    /// Market clarification log (created with the first clarification)
+    #[account(
+        init_if_needed,
+        payer = creator,
+        space = MarketClarifications::INIT_SPACE,
+        seeds = [MarketClarifications::SEED_PREFIX, market.key().as_ref()],
+        bump,
+    )]
+    pub clarifications: Account<'info, MarketClarifications>,
+
+    pub system_program: Program<'info, System>,
+}
+
+/// Parameters for append_market_clarification instruction
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug)]
+pub struct AppendMarketClarificationParams {
+    /// Clarification text (at most MAX_CLARIFICATION_LEN bytes)
+    pub text: String,
+}
+
+pub fn handler_append_market_clarification(
+    ctx: Context<AppendMarketClarification>,
+    params: AppendMarketClarificationParams,
+) -> Result<()> {
+    let clock = Clock::get()?;
+    let market = ctx.accounts.market.key();
+    let log = &mut ctx.accounts.clarifications;
+    if log.market == Pubkey::default() {
+        log.market = market;
+        log.bump = ctx.bumps.clarifications;
+    }
+
+    let index = log.append(params.text.clone(), clock.unix_timestamp)?;
+
+    emit!(MarketClarificationAdded {
+        market,
+        creator: ctx.accounts.creator.key(),
+        index,
+        text: params.text,
+        timestamp: clock.unix_timestamp,
+    });
+
+    msg!("Clarification {} appended to market {}", index, market);
+
+    Ok(())
+}
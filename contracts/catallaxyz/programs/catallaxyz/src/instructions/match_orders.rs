@@ -7,21 +7,60 @@
 //! - COMPLEMENTARY: Buy vs Sell (direct swap)
 //! - MINT: Buy YES vs Buy NO (mint new tokens from USDC)
 //! - MERGE: Sell YES vs Sell NO (merge tokens back to USDC)
+//! 
+//! Maker accounts use the compact layout from `order_accounts`: each unique maker
+//! is passed once and maker orders reference it by index. Signatures may be batched
+//! into one or more Ed25519 instructions anywhere before this instruction.
+//! 
+//! Hybrid matching: if the operator's balance and position accounts are provided,
+//! whatever part of `taker_fill_amount` the makers did not consume is filled from
+//! operator inventory at the taker's limit price, so the taker gets a single
+//! atomic fill.
+//! 
+//! Fees in a match are charged for the taker order's trade, so every fee gets
+//! the taker's volume tier discount, and the taker order's USDC notional is
+//! added to the taker's `UserStats`.
+//! 
+//! Fees are charged on complementary fills (withheld from the seller's USDC
+//! proceeds) and on a SELL taker's operator fill; MINT and MERGE fills charge
+//! none. Charged fees are moved from the market vault to the platform
+//! treasury, and the taker's referrer accrues a share of them.
 
 use anchor_lang::prelude::*;
 use anchor_lang::solana_program::sysvar::instructions::ID as INSTRUCTIONS_ID;
-use crate::constants::{GLOBAL_SEED, MARKET_SEED, PRICE_SCALE};
+use anchor_spl::token_interface::{Mint, TokenAccount, TokenInterface};
+use crate::constants::{GLOBAL_SEED, MARKET_SEED, PLATFORM_TREASURY_SEED, PRICE_SCALE};
 use crate::errors::TerminatorError;
 use crate::events::{OrderFilled, OrdersMatched};
 use crate::states::{
-    Global, Market, UserBalance, UserPosition,
-    Order, SignedOrder, OrderStatus, UserNonce, MatchType,
-    hash_order, is_crossing, token_id,
+    Global, Market, UserBalance, UserPosition, UserStats, RoleAssignment, Referral,
+    Order, SignedOrder, OrderStatus, UserNonce, MarketNonce, CancelBitmap, MatchType,
+    hash_order, is_crossing, token_id, role,
+};
+use crate::instructions::calculator::{
+    apply_tier_discount, calculate_taking_amount, calculate_order_fee, validate_order, validate_stop_trigger, validate_taker,
+    CancellationScope,
 };
-use crate::instructions::calculator::{calculate_taking_amount, calculate_fee, validate_order, validate_taker};
-use crate::instructions::ed25519_verify::{verify_ed25519_at_index, get_current_instruction_index};
+use crate::instructions::ed25519_verify::{load_preceding_ed25519_entries, require_order_signature};
+use crate::instructions::signer_auth::{authorize_order_signer, order_notional};
+use crate::instructions::referral::{accrue_referral_fee, load_referral_accounts};
+use crate::instructions::treasury_utils::collect_trading_fee;
+use crate::instructions::fill_order::{
+    execute_operator_fill, operator_fill_usdc_fee, order_asset_ids, record_taker_volume, taker_discount_rate,
+    FillAmounts,
+};
+use crate::instructions::order_accounts::{
+    load_cancel_bitmap, load_makers, load_or_init_order_status, peek_user_stats, read_cancel_bitmap,
+    read_market_nonce, ACCOUNTS_PER_MAKER, ACCOUNTS_PER_MAKER_FEE, ACCOUNTS_PER_MAKER_ORDER,
+};
+use crate::instructions::signer_auth::optional_account;
 
 /// Maximum number of maker orders that can be matched in a single instruction
+///
+/// In practice the transaction size limit binds first: every maker order adds its
+/// signed order (245 bytes), fill amount, maker index and Ed25519 offsets entry to
+/// the transaction even when all accounts are in an address lookup table
+/// (see `test_match_orders_transaction_size`).
 pub const MAX_MAKER_ORDERS: usize = 5;
 
 /// Parameters for match_orders instruction
@@ -35,6 +74,8 @@ pub struct MatchOrdersParams {
     pub maker_orders: Vec<SignedOrder>,
     /// Fill amounts for each maker order
     pub maker_fill_amounts: Vec<u64>,
+    /// For each maker order, index of its maker group in remaining accounts
+    pub maker_indices: Vec<u8>,
 }
 
 /// Core accounts for match_orders (fixed accounts)
@@ -50,10 +91,18 @@ pub struct MatchOrders<'info> {
         seeds = [GLOBAL_SEED.as_bytes()],
         bump = global.bump,
         constraint = global.is_trading_allowed() @ TerminatorError::TradingPaused,
-        constraint = global.is_operator(&operator.key()) @ TerminatorError::NotOperator,
+        constraint = global.has_role(&operator.key(), operator_role.as_deref(), role::OPERATOR)
+            @ TerminatorError::NotOperator,
     )]
     pub global: Box<Account<'info, Global>>,
 
+    /// Signer's operator role assignment (omitted when the signer is the authority)
+    #[account(
+        seeds = [RoleAssignment::SEED_PREFIX, &[role::OPERATOR], operator.key().as_ref()],
+        bump = operator_role.bump,
+    )]
+    pub operator_role: Option<Account<'info, RoleAssignment>>,
+
     #[account(
         mut,
         seeds = [
@@ -72,7 +121,7 @@ pub struct MatchOrders<'info> {
         init_if_needed,
         payer = operator,
         space = OrderStatus::INIT_SPACE,
-        seeds = [OrderStatus::SEED_PREFIX, &hash_order(&params.taker_order.order)],
+        seeds = [OrderStatus::SEED_PREFIX, &hash_order(&params.taker_order.order, &global.order_domain())],
         bump,
     )]
     pub taker_order_status: Box<Account<'info, OrderStatus>>,
@@ -84,6 +133,24 @@ pub struct MatchOrders<'info> {
     )]
     pub taker_nonce: Box<Account<'info, UserNonce>>,
 
+    /// CHECK: taker's MarketNonce for this market (may be uninitialized)
+    #[account(
+        seeds = [MarketNonce::SEED_PREFIX, taker.key().as_ref(), market.key().as_ref()],
+        bump,
+    )]
+    pub taker_market_nonce: UncheckedAccount<'info>,
+
+    /// CHECK: taker's CancelBitmap page for the taker order's salt (may be uninitialized)
+    #[account(
+        seeds = [
+            CancelBitmap::SEED_PREFIX,
+            taker.key().as_ref(),
+            &CancelBitmap::page_of(params.taker_order.order.salt).to_le_bytes(),
+        ],
+        bump,
+    )]
+    pub taker_cancel_bitmap: UncheckedAccount<'info>,
+
     /// Taker's USDC balance
     #[account(
         mut,
@@ -102,6 +169,16 @@ pub struct MatchOrders<'info> {
     )]
     pub taker_position: Box<Account<'info, UserPosition>>,
 
+    /// Taker's volume stats for fee tiers (created on first fill)
+    #[account(
+        init_if_needed,
+        payer = operator,
+        space = UserStats::INIT_SPACE,
+        seeds = [UserStats::SEED_PREFIX, taker.key().as_ref()],
+        bump,
+    )]
+    pub taker_stats: Box<Account<'info, UserStats>>,
+
     /// CHECK: taker wallet
     #[account(constraint = taker.key() == params.taker_order.order.maker @ TerminatorError::InvalidAccountInput)]
     pub taker: UncheckedAccount<'info>,
@@ -111,13 +188,76 @@ pub struct MatchOrders<'info> {
     pub instructions: AccountInfo<'info>,
 
     pub system_program: Program<'info, System>,
-    // Remaining accounts:
-    // For each maker order (up to MAX_MAKER_ORDERS):
+
+    /// Operator's USDC balance (optional inventory leg)
+    #[account(
+        mut,
+        seeds = [b"user_balance", market.key().as_ref(), operator.key().as_ref()],
+        bump = operator_balance.bump,
+        constraint = operator_balance.user == operator.key() @ TerminatorError::Unauthorized,
+    )]
+    pub operator_balance: Option<Box<Account<'info, UserBalance>>>,
+
+    /// Operator's position (optional inventory leg)
+    #[account(
+        mut,
+        seeds = [b"user_position", market.key().as_ref(), operator.key().as_ref()],
+        bump = operator_position.bump,
+        constraint = operator_position.user == operator.key() @ TerminatorError::Unauthorized,
+    )]
+    pub operator_position: Option<Box<Account<'info, UserPosition>>>,
+
+    /// CHECK: taker's DelegateRegistry or SessionKey, required if the taker order was signed
+    /// by a delegate (validated in signer_auth)
+    #[account(mut)]
+    pub taker_signer_authorization: Option<UncheckedAccount<'info>>,
+
+    /// CHECK: taker's Referral PDA (may be uninitialized if the taker has no referrer)
+    #[account(
+        seeds = [Referral::SEED_PREFIX, taker.key().as_ref()],
+        bump,
+    )]
+    pub taker_referral: UncheckedAccount<'info>,
+
+    /// CHECK: rewards of the taker's referrer, required if taker_referral exists
+    /// (validated in referral)
+    #[account(mut)]
+    pub referrer_rewards: Option<UncheckedAccount<'info>>,
+
+    #[account(
+        mut,
+        seeds = [b"market_vault", market.key().as_ref()],
+        bump,
+    )]
+    pub market_usdc_vault: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    /// Platform treasury (receives USDC fees)
+    #[account(
+        mut,
+        seeds = [PLATFORM_TREASURY_SEED.as_bytes()],
+        bump = global.platform_treasury_bump,
+    )]
+    pub platform_treasury: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    #[account(address = global.usdc_mint @ TerminatorError::InvalidUsdcMint)]
+    pub usdc_mint: Box<InterfaceAccount<'info, Mint>>,
+
+    pub token_program: Interface<'info, TokenInterface>,
+    // Remaining accounts (compact layout, see order_accounts):
+    // For each UNIQUE maker (ACCOUNTS_PER_MAKER accounts):
     // - maker (UncheckedAccount)
     // - maker_nonce (UserNonce)
+    // - maker_market_nonce (MarketNonce PDA, may be uninitialized)
     // - maker_balance (UserBalance)
     // - maker_position (UserPosition)
-    // - maker_order_status (OrderStatus)
+    // - signer_authorization (DelegateRegistry or SessionKey, or program ID if not needed)
+    // Then for each maker group, in the same order (ACCOUNTS_PER_MAKER_FEE accounts):
+    // - maker_stats (UserStats, may be uninitialized)
+    // - maker_referral (Referral PDA, may be uninitialized)
+    // - referrer_rewards (ReferrerRewards of the maker's referrer, or program ID if no referral)
+    // Then for each maker order (up to MAX_MAKER_ORDERS, ACCOUNTS_PER_MAKER_ORDER accounts):
+    // - maker_order_status (OrderStatus, created if missing)
+    // - maker_cancel_bitmap (CancelBitmap page for the order's salt, may be uninitialized)
 }
 
 pub fn handler<'info>(
@@ -132,7 +272,8 @@ pub fn handler<'info>(
     require!(maker_orders_count > 0, TerminatorError::InvalidInput);
     require!(maker_orders_count <= MAX_MAKER_ORDERS, TerminatorError::InvalidInput);
     require!(
-        params.maker_fill_amounts.len() == maker_orders_count,
+        params.maker_fill_amounts.len() == maker_orders_count
+            && params.maker_indices.len() == maker_orders_count,
         TerminatorError::InvalidInput
     );
     
@@ -140,7 +281,22 @@ pub fn handler<'info>(
     // Validate Taker Order
     // ============================================
     
-    validate_order(taker_order, clock.unix_timestamp, ctx.accounts.taker_nonce.current_nonce)?;
+    let order_domain = ctx.accounts.global.order_domain();
+    let accept_legacy = ctx.accounts.global.accept_legacy_order_hash;
+    let taker_order_hash = hash_order(taker_order, &order_domain);
+    
+    let taker_market_nonce = read_market_nonce(&ctx.accounts.taker_market_nonce)?;
+    let taker_cancel_bitmap = read_cancel_bitmap(&ctx.accounts.taker_cancel_bitmap)?;
+    validate_order(
+        taker_order,
+        &taker_order_hash,
+        clock.unix_timestamp,
+        &CancellationScope {
+            user_nonce: ctx.accounts.taker_nonce.current_nonce,
+            market_nonce: taker_market_nonce.as_ref(),
+            cancel_bitmap: taker_cancel_bitmap.as_ref(),
+        },
+    )?;
     
     require!(
         taker_order.market == ctx.accounts.market.key(),
@@ -153,27 +309,30 @@ pub fn handler<'info>(
         validate_taker(taker_order, &ctx.accounts.operator.key())?;
     }
     
-    // Get current instruction index for signature verification
-    let current_index = get_current_instruction_index(&ctx.accounts.instructions)?;
+    // Stop orders only execute once the last price has crossed the trigger
+    validate_stop_trigger(taker_order, &ctx.accounts.market)?;
     
-    // Verify taker signature (should be at index current_index - (maker_count + 1))
-    let taker_sig_index = current_index
-        .checked_sub((maker_orders_count + 1) as u16)
-        .ok_or(TerminatorError::InvalidSignature)?;
+    // Collect all signatures verified by preceding Ed25519 instructions
+    // (supports batched instructions with num_signatures > 1)
+    let signatures = load_preceding_ed25519_entries(&ctx.accounts.instructions)?;
     
-    let taker_order_hash = hash_order(taker_order);
-    verify_ed25519_at_index(
-        &ctx.accounts.instructions,
-        taker_sig_index as usize,
-        &taker_order.signer,
+    require_order_signature(
+        &signatures,
+        taker_order,
         &taker_order_hash,
+        accept_legacy,
         &params.taker_order.signature,
     )?;
     
     // Initialize/check taker order status
     let taker_order_status = &mut ctx.accounts.taker_order_status;
     if taker_order_status.order_hash == [0u8; 32] {
-        taker_order_status.init(taker_order_hash, taker_order.maker_amount, ctx.bumps.taker_order_status);
+        taker_order_status.init(
+            taker_order_hash,
+            taker_order.maker_amount,
+            ctx.accounts.operator.key(),
+            ctx.bumps.taker_order_status,
+        );
     } else {
         require!(
             taker_order_status.order_hash == taker_order_hash,
@@ -182,95 +341,154 @@ pub fn handler<'info>(
     }
     require!(taker_order_status.is_fillable(), TerminatorError::OrderNotFillable);
     
+    let actual_taker_fill = params.taker_fill_amount.min(taker_order_status.remaining);
+    
+    // Taker's referral (required whenever the taker registered a referrer)
+    let taker_referral = load_referral_accounts(
+        &ctx.accounts.taker_referral,
+        ctx.accounts.referrer_rewards.as_ref().map(|account| account.as_ref()),
+        &taker_order.maker,
+    )?;
+    
+    // Taker's volume tier discount (from volume before this match)
+    let taker_stats = &mut ctx.accounts.taker_stats;
+    if taker_stats.user == Pubkey::default() {
+        taker_stats.init(taker_order.maker, ctx.bumps.taker_stats);
+    }
+    let discount_rate = taker_discount_rate(&ctx.accounts.global, taker_stats, clock.unix_timestamp);
+    
     // ============================================
-    // Process Maker Orders via Remaining Accounts
+    // Load Maker Accounts (compact layout)
     // ============================================
     
-    // Each maker requires 5 accounts:
-    // maker, maker_nonce, maker_balance, maker_position, maker_order_status
-    let accounts_per_maker = 5;
+    let remaining_accounts: &'info [AccountInfo<'info>] = ctx.remaining_accounts;
+    let status_start = remaining_accounts
+        .len()
+        .checked_sub(maker_orders_count * ACCOUNTS_PER_MAKER_ORDER)
+        .ok_or(TerminatorError::InvalidAccountInput)?;
+    require!(
+        status_start.is_multiple_of(ACCOUNTS_PER_MAKER + ACCOUNTS_PER_MAKER_FEE),
+        TerminatorError::InvalidAccountInput
+    );
+    let maker_count = status_start / (ACCOUNTS_PER_MAKER + ACCOUNTS_PER_MAKER_FEE);
     require!(
-        ctx.remaining_accounts.len() == maker_orders_count * accounts_per_maker,
+        maker_count > 0 && maker_count <= maker_orders_count,
         TerminatorError::InvalidAccountInput
     );
+    let fee_start = maker_count * ACCOUNTS_PER_MAKER;
+    
+    // Operator inventory leg requires both operator accounts; the operator then
+    // acts as a counterparty and cannot also be the taker or a maker
+    let operator_key = ctx.accounts.operator.key();
+    let use_operator_inventory = match (&ctx.accounts.operator_balance, &ctx.accounts.operator_position) {
+        (Some(_), Some(_)) => true,
+        (None, None) => false,
+        _ => return err!(TerminatorError::InvalidAccountInput),
+    };
+    if use_operator_inventory {
+        require!(ctx.accounts.taker.key() != operator_key, TerminatorError::InvalidAccountInput);
+    }
+    
+    let market_key = ctx.accounts.market.key();
+    let fee_override = ctx.accounts.market.fee_override;
+    let mut makers = load_makers(
+        &remaining_accounts[..fee_start],
+        &market_key,
+        &[ctx.accounts.taker.key(), operator_key],
+    )?;
+    
+    // Each fee is paid by the selling side at its own tier, and its referrer is credited
+    let fee_accounts: Vec<&'info [AccountInfo<'info>]> = remaining_accounts[fee_start..status_start]
+        .chunks(ACCOUNTS_PER_MAKER_FEE)
+        .collect();
+    let maker_discount_rates = fee_accounts.iter()
+        .zip(makers.iter())
+        .map(|(accounts, maker)| {
+            let pending = peek_user_stats(&accounts[0], &maker.maker)?;
+            Ok(taker_discount_rate(&ctx.accounts.global, &pending.stats, clock.unix_timestamp))
+        })
+        .collect::<Result<Vec<_>>>()?;
+    let maker_referrals = fee_accounts.iter()
+        .zip(makers.iter())
+        .map(|(accounts, maker)| load_referral_accounts(&accounts[1], optional_account(&accounts[2]), &maker.maker))
+        .collect::<Result<Vec<_>>>()?;
+    let mut maker_used = vec![false; makers.len()];
+    let mut maker_order_hashes: Vec<[u8; 32]> = Vec::with_capacity(maker_orders_count);
+    
+    let operator_info = ctx.accounts.operator.to_account_info();
+    let system_program_info = ctx.accounts.system_program.to_account_info();
+    
+    // ============================================
+    // Process Maker Orders
+    // ============================================
     
     let mut total_taker_taking = 0u64;
+    // USDC fees charged across the match (taker and maker fees)
+    let mut total_fee = 0u64;
+    // USDC fees paid by the taker (accrued to the taker's referrer)
+    let mut taker_fee = 0u64;
+    // Amount of the taker order (in taker maker_amount units) consumed by makers
+    let mut total_taker_consumed = 0u64;
+    // Taker amount consumed by fills that don't count toward the creation fee refund
+    let mut refund_excluded_consumed = 0u64;
     let taker_balance = &mut ctx.accounts.taker_balance;
     let taker_position = &mut ctx.accounts.taker_position;
     
-    for (i, (maker_order, maker_fill_amount)) in params.maker_orders.iter()
+    for (i, ((maker_order, maker_fill_amount), maker_index)) in params.maker_orders.iter()
         .zip(params.maker_fill_amounts.iter())
+        .zip(params.maker_indices.iter())
         .enumerate()
     {
         let order = &maker_order.order;
-        let base_idx = i * accounts_per_maker;
+        let maker_index = *maker_index as usize;
+        require!(maker_index < makers.len(), TerminatorError::InvalidAccountInput);
+        maker_used[maker_index] = true;
+        let maker = &mut makers[maker_index];
         
-        // Extract maker accounts from remaining_accounts
-        let maker_info = &ctx.remaining_accounts[base_idx];
-        let maker_nonce_info = &ctx.remaining_accounts[base_idx + 1];
-        let maker_balance_info = &ctx.remaining_accounts[base_idx + 2];
-        let maker_position_info = &ctx.remaining_accounts[base_idx + 3];
-        let maker_order_status_info = &ctx.remaining_accounts[base_idx + 4];
+        // Verify maker group matches order
+        require!(maker.maker == order.maker, TerminatorError::InvalidAccountInput);
         
-        // Verify maker pubkey matches order
+        // Each order may appear once (its status account is loaded once)
+        let maker_order_hash = hash_order(order, &order_domain);
         require!(
-            maker_info.key() == order.maker,
-            TerminatorError::InvalidAccountInput
-        );
-        
-        // Load accounts
-        let maker_nonce: Account<UserNonce> = Account::try_from(maker_nonce_info)?;
-        let mut maker_balance: Account<UserBalance> = Account::try_from(maker_balance_info)?;
-        let mut maker_position: Account<UserPosition> = Account::try_from(maker_position_info)?;
-        let mut maker_order_status: Account<OrderStatus> = Account::try_from(maker_order_status_info)?;
-        
-        // AUDIT FIX C-C2: Validate maker accounts belong to correct market and user
-        require!(
-            maker_balance.market == ctx.accounts.market.key(),
-            TerminatorError::InvalidAccountInput
-        );
-        require!(
-            maker_position.market == ctx.accounts.market.key(),
-            TerminatorError::InvalidAccountInput
-        );
-        require!(
-            maker_balance.user == order.maker,
-            TerminatorError::Unauthorized
-        );
-        require!(
-            maker_position.user == order.maker,
-            TerminatorError::Unauthorized
+            !maker_order_hashes.contains(&maker_order_hash),
+            TerminatorError::InvalidInput
         );
+        maker_order_hashes.push(maker_order_hash);
         
         // Validate maker order
-        validate_order(order, clock.unix_timestamp, maker_nonce.current_nonce)?;
-        require!(order.market == ctx.accounts.market.key(), TerminatorError::InvalidMarket);
+        let order_accounts = &remaining_accounts[status_start + i * ACCOUNTS_PER_MAKER_ORDER..];
+        let maker_cancel_bitmap = load_cancel_bitmap(&order_accounts[1], &order.maker, order.salt)?;
+        validate_order(
+            order,
+            &maker_order_hash,
+            clock.unix_timestamp,
+            &CancellationScope {
+                user_nonce: maker.nonce.current_nonce,
+                market_nonce: maker.market_nonce.as_ref(),
+                cancel_bitmap: maker_cancel_bitmap.as_ref(),
+            },
+        )?;
+        require!(order.market == market_key, TerminatorError::InvalidMarket);
+        validate_stop_trigger(order, &ctx.accounts.market)?;
         
         // Verify maker signature
-        let maker_sig_index = current_index
-            .checked_sub((maker_orders_count - i) as u16)
-            .ok_or(TerminatorError::InvalidSignature)?;
-        
-        let maker_order_hash = hash_order(order);
-        verify_ed25519_at_index(
-            &ctx.accounts.instructions,
-            maker_sig_index as usize,
-            &order.signer,
+        require_order_signature(
+            &signatures,
+            order,
             &maker_order_hash,
+            accept_legacy,
             &maker_order.signature,
         )?;
         
-        // Initialize/check maker order status
-        if maker_order_status.order_hash == [0u8; 32] {
-            maker_order_status.order_hash = maker_order_hash;
-            maker_order_status.remaining = order.maker_amount;
-            maker_order_status.is_filled_or_cancelled = false;
-        } else {
-            require!(
-                maker_order_status.order_hash == maker_order_hash,
-                TerminatorError::OrderHashMismatch
-            );
-        }
+        // Load or create maker order status
+        let mut maker_order_status = load_or_init_order_status(
+            &order_accounts[0],
+            &maker_order_hash,
+            order.maker_amount,
+            &operator_info,
+            &system_program_info,
+        )?;
         require!(maker_order_status.is_fillable(), TerminatorError::OrderNotFillable);
         
         // Determine match type
@@ -286,10 +504,33 @@ pub fn handler<'info>(
         // Calculate fill amounts
         let actual_maker_fill = (*maker_fill_amount).min(maker_order_status.remaining);
         let taking_amount = calculate_taking_amount(actual_maker_fill, order.maker_amount, order.taker_amount)?;
-        let fee = calculate_fee(order.fee_rate_bps, taking_amount, order.maker_amount, order.taker_amount, order.side)?;
+        
+        // Only complementary fills charge a fee, paid by the seller at the seller's
+        // signed rate and volume tier
+        let fee = match match_type {
+            MatchType::Complementary if taker_order.is_sell() => apply_tier_discount(
+                calculate_order_fee(taker_order, taking_amount, fee_override.as_ref())?,
+                discount_rate,
+            )?,
+            MatchType::Complementary => apply_tier_discount(
+                calculate_order_fee(order, taking_amount, fee_override.as_ref())?,
+                maker_discount_rates[maker_index],
+            )?,
+            MatchType::Mint | MatchType::Merge => 0,
+        };
+        
+        // Maker signer must be the maker or an authorized delegate
+        // (committed immediately so later orders from the same maker see the spend)
+        authorize_order_signer(
+            order,
+            maker.authorization,
+            clock.unix_timestamp,
+            order_notional(order, actual_maker_fill, taking_amount),
+        )?
+        .commit()?;
         
         // Execute transfer based on match type
-        match match_type {
+        let taker_consumed = match match_type {
             MatchType::Complementary => {
                 execute_complementary_match(
                     taker_order,
@@ -299,9 +540,9 @@ pub fn handler<'info>(
                     fee,
                     taker_balance,
                     taker_position,
-                    &mut maker_balance,
-                    &mut maker_position,
-                )?;
+                    &mut maker.balance,
+                    &mut maker.position,
+                )?
             }
             MatchType::Mint => {
                 execute_mint_match(
@@ -312,10 +553,10 @@ pub fn handler<'info>(
                     fee,
                     taker_balance,
                     taker_position,
-                    &mut maker_balance,
-                    &mut maker_position,
+                    &mut maker.balance,
+                    &mut maker.position,
                     &mut ctx.accounts.market,
-                )?;
+                )?
             }
             MatchType::Merge => {
                 execute_merge_match(
@@ -326,12 +567,15 @@ pub fn handler<'info>(
                     fee,
                     taker_balance,
                     taker_position,
-                    &mut maker_balance,
-                    &mut maker_position,
+                    &mut maker.balance,
+                    &mut maker.position,
                     &mut ctx.accounts.market,
-                )?;
+                )?
             }
-        }
+        };
+        
+        // Record execution price (maker's limit) so stop triggers see this trade
+        ctx.accounts.market.record_token_last_price(order.token_id, order.calculate_price())?;
         
         // Update maker order status
         maker_order_status.remaining = maker_order_status.remaining.saturating_sub(actual_maker_fill);
@@ -342,10 +586,36 @@ pub fn handler<'info>(
         total_taker_taking = total_taker_taking
             .checked_add(taking_amount)
             .ok_or(TerminatorError::ArithmeticOverflow)?;
+        total_fee = total_fee
+            .checked_add(fee)
+            .ok_or(TerminatorError::ArithmeticOverflow)?;
+        if taker_order.is_sell() {
+            taker_fee = taker_fee
+                .checked_add(fee)
+                .ok_or(TerminatorError::ArithmeticOverflow)?;
+        } else {
+            let referral_fee = accrue_referral_fee(
+                &ctx.accounts.global,
+                fee_override.as_ref(),
+                maker_referrals[maker_index].as_ref(),
+                market_key,
+                fee,
+                clock.unix_timestamp,
+            )?;
+            ctx.accounts.global.reserve_referral_rewards(referral_fee)?;
+        }
+        total_taker_consumed = total_taker_consumed
+            .checked_add(taker_consumed)
+            .ok_or(TerminatorError::ArithmeticOverflow)?;
+        // Fee-free mint/merge matches would make wash volume free
+        if match_type != MatchType::Complementary
+            || !ctx.accounts.market.is_refund_eligible_fill(&order.maker, &taker_order.maker)
+        {
+            refund_excluded_consumed = refund_excluded_consumed
+                .checked_add(taker_consumed)
+                .ok_or(TerminatorError::ArithmeticOverflow)?;
+        }
         
-        // Save maker accounts back
-        maker_balance.exit(&crate::ID)?;
-        maker_position.exit(&crate::ID)?;
         maker_order_status.exit(&crate::ID)?;
         
         // Emit individual fill event
@@ -358,24 +628,137 @@ pub fn handler<'info>(
             maker_amount_filled: actual_maker_fill,
             taker_amount_filled: taking_amount,
             fee,
-            market: ctx.accounts.market.key(),
+            market: market_key,
             slot: clock.slot,
             timestamp: clock.unix_timestamp,
         });
     }
     
+    // Every maker group must be referenced by at least one order
+    require!(
+        maker_used.iter().all(|used| *used),
+        TerminatorError::InvalidAccountInput
+    );
+    
+    // Save maker accounts back (once per unique maker)
+    for maker in makers.iter() {
+        maker.exit()?;
+    }
+    
+    // Makers cannot consume more of the taker order than is being filled
+    require!(total_taker_consumed <= actual_taker_fill, TerminatorError::FillAmountExceedsRemaining);
+    let mut taker_filled = total_taker_consumed;
+    let mut trades = maker_orders_count as u64;
+    
+    // ============================================
+    // Operator Inventory Leg (hybrid matching)
+    // ============================================
+    
+    if let (Some(operator_balance), Some(operator_position)) =
+        (ctx.accounts.operator_balance.as_mut(), ctx.accounts.operator_position.as_mut())
+    {
+        let operator_fill = actual_taker_fill.saturating_sub(total_taker_consumed);
+        if operator_fill > 0 {
+            // Fill the remainder at the taker's own limit price
+            let taking = calculate_taking_amount(operator_fill, taker_order.maker_amount, taker_order.taker_amount)?;
+            let fee = apply_tier_discount(
+                calculate_order_fee(taker_order, taking, fee_override.as_ref())?,
+                discount_rate,
+            )?;
+            execute_operator_fill(
+                taker_order,
+                FillAmounts { making: operator_fill, taking, fee },
+                taker_balance,
+                taker_position,
+                operator_balance,
+                operator_position,
+            )?;
+            
+            ctx.accounts.market.record_token_last_price(taker_order.token_id, taker_order.calculate_price())?;
+            trades += 1;
+            taker_filled = actual_taker_fill;
+            let usdc_fee = operator_fill_usdc_fee(taker_order, fee);
+            total_fee = total_fee
+                .checked_add(usdc_fee)
+                .ok_or(TerminatorError::ArithmeticOverflow)?;
+            taker_fee = taker_fee
+                .checked_add(usdc_fee)
+                .ok_or(TerminatorError::ArithmeticOverflow)?;
+            
+            let (maker_asset_id, taker_asset_id) = order_asset_ids(taker_order);
+            emit!(OrderFilled {
+                order_hash: taker_order_hash,
+                maker: taker_order.maker,
+                taker: operator_key,
+                maker_asset_id,
+                taker_asset_id,
+                maker_amount_filled: operator_fill,
+                taker_amount_filled: taking,
+                fee,
+                market: market_key,
+                slot: clock.slot,
+                timestamp: clock.unix_timestamp,
+            });
+            
+            msg!("Operator filled taker remainder: {}", operator_fill);
+        }
+    }
+    
+    // Taker signer must be the maker or an authorized delegate (spend tracked in USDC
+    // on the amount actually filled)
+    let taker_taking = calculate_taking_amount(taker_filled, taker_order.maker_amount, taker_order.taker_amount)?;
+    let taker_notional = order_notional(taker_order, taker_filled, taker_taking);
+    authorize_order_signer(
+        taker_order,
+        ctx.accounts.taker_signer_authorization.as_ref().map(|account| account.as_ref()),
+        clock.unix_timestamp,
+        taker_notional,
+    )?
+    .commit()?;
+    
     // Update taker order status
-    let actual_taker_fill = params.taker_fill_amount.min(taker_order_status.remaining);
-    taker_order_status.remaining = taker_order_status.remaining.saturating_sub(actual_taker_fill);
+    taker_order_status.remaining = taker_order_status.remaining.saturating_sub(taker_filled);
     if taker_order_status.remaining == 0 {
         taker_order_status.is_filled_or_cancelled = true;
     }
     
+    record_taker_volume(&ctx.accounts.global, &mut ctx.accounts.taker_stats, clock.unix_timestamp, taker_notional);
+    collect_trading_fee(
+        &mut ctx.accounts.global,
+        &ctx.accounts.market,
+        &ctx.accounts.market_usdc_vault.to_account_info(),
+        &ctx.accounts.platform_treasury.to_account_info(),
+        &ctx.accounts.usdc_mint.to_account_info(),
+        &ctx.accounts.token_program.to_account_info(),
+        total_fee,
+    )?;
+    let referral_fee = accrue_referral_fee(
+        &ctx.accounts.global,
+        fee_override.as_ref(),
+        taker_referral.as_ref(),
+        market_key,
+        taker_fee,
+        clock.unix_timestamp,
+    )?;
+    ctx.accounts.global.reserve_referral_rewards(referral_fee)?;
+    
     // Update market stats
     let market = &mut ctx.accounts.market;
     market.record_activity(clock.unix_timestamp, clock.slot);
+    // The operator leg counts unless the creator is the taker
+    let refund_fill = taker_filled.saturating_sub(refund_excluded_consumed);
+    let refund_notional = if taker_order.maker != market.creator {
+        order_notional(
+            taker_order,
+            refund_fill,
+            calculate_taking_amount(refund_fill, taker_order.maker_amount, taker_order.taker_amount)?,
+        )
+    } else {
+        0
+    };
+    market.record_volume(taker_notional, refund_notional)?;
     market.total_trades = market.total_trades
-        .checked_add(maker_orders_count as u64)
+        .checked_add(trades)
         .ok_or(TerminatorError::ArithmeticOverflow)?;
     
     // Emit match event
@@ -384,7 +767,7 @@ pub fn handler<'info>(
         taker_maker: taker_order.maker,
         maker_asset_id: if taker_order.is_buy() { token_id::USDC } else { taker_order.token_id },
         taker_asset_id: if taker_order.is_buy() { taker_order.token_id } else { token_id::USDC },
-        maker_amount_filled: actual_taker_fill,
+        maker_amount_filled: taker_filled,
         taker_amount_filled: total_taker_taking,
         maker_orders_count: maker_orders_count as u8,
         market: market.key(),
@@ -398,6 +781,8 @@ pub fn handler<'info>(
 }
 
 /// Execute a complementary match (Buy vs Sell)
+/// Returns the amount of the taker order consumed (in taker maker_amount units)
+#[allow(clippy::too_many_arguments)]
 fn execute_complementary_match(
     taker_order: &Order,
     maker_order: &Order,
@@ -408,7 +793,7 @@ fn execute_complementary_match(
     taker_position: &mut Account<UserPosition>,
     maker_balance: &mut Account<UserBalance>,
     maker_position: &mut Account<UserPosition>,
-) -> Result<()> {
+) -> Result<u64> {
     if taker_order.is_buy() {
         // Taker buys tokens from maker who sells
         // Taker gives USDC, receives tokens
@@ -467,11 +852,14 @@ fn execute_complementary_match(
         }
     }
     
-    Ok(())
+    // Taker BUY pays USDC, taker SELL gives tokens
+    Ok(if taker_order.is_buy() { taking_amount } else { maker_fill })
 }
 
 /// Execute a mint match (Buy YES vs Buy NO)
 /// Both parties want to buy tokens, so we mint new YES+NO from their USDC
+/// Returns the USDC paid by the taker
+#[allow(clippy::too_many_arguments)]
 fn execute_mint_match(
     taker_order: &Order,
     maker_order: &Order,
@@ -483,7 +871,7 @@ fn execute_mint_match(
     maker_balance: &mut Account<UserBalance>,
     maker_position: &mut Account<UserPosition>,
     market: &mut Account<Market>,
-) -> Result<()> {
+) -> Result<u64> {
     // In a mint match, both orders are BUY orders for complementary tokens
     // We take USDC from both and mint YES+NO
     
@@ -542,11 +930,13 @@ fn execute_mint_match(
         .checked_add(mint_amount)
         .ok_or(TerminatorError::ArithmeticOverflow)?;
     
-    Ok(())
+    Ok(taker_usdc_needed)
 }
 
 /// Execute a merge match (Sell YES vs Sell NO)
 /// Both parties want to sell tokens, so we merge YES+NO back to USDC
+/// Returns the tokens given by the taker
+#[allow(clippy::too_many_arguments)]
 fn execute_merge_match(
     taker_order: &Order,
     maker_order: &Order,
@@ -558,7 +948,7 @@ fn execute_merge_match(
     maker_balance: &mut Account<UserBalance>,
     maker_position: &mut Account<UserPosition>,
     market: &mut Account<Market>,
-) -> Result<()> {
+) -> Result<u64> {
     // In a merge match, both orders are SELL orders for complementary tokens
     // We take tokens from both and return USDC
     
@@ -621,5 +1011,141 @@ fn execute_merge_match(
         .checked_sub(merge_amount)
         .ok_or(TerminatorError::ArithmeticOverflow)?;
     
-    Ok(())
+    Ok(merge_amount)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use anchor_lang::solana_program::instruction::{AccountMeta, Instruction};
+    use anchor_lang::{InstructionData, ToAccountMetas};
+    use core::str::FromStr;
+    use solana_hash::Hash;
+    use solana_message::{v0, AddressLookupTableAccount, VersionedMessage};
+    use crate::instructions::ed25519_verify::ED25519_PROGRAM_ID;
+    use crate::states::{Order, SignedOrder};
+
+    /// Maximum serialized transaction size (IPv6 MTU minus headers)
+    const PACKET_DATA_SIZE: usize = 1232;
+
+    fn signed_order(maker: Pubkey, market: Pubkey) -> SignedOrder {
+        SignedOrder {
+            order: Order {
+                salt: 1,
+                maker,
+                signer: maker,
+                taker: Pubkey::default(),
+                market,
+                token_id: 0,
+                maker_amount: 1_000_000,
+                taker_amount: 500_000,
+                expiration: 0,
+                nonce: 0,
+                fee_rate_bps: 0,
+                side: 0,
+                trigger_price: 0,
+                trigger_direction: 0,
+            },
+            signature: [1u8; 64],
+        }
+    }
+
+    /// Serialized size of a signed v0 transaction matching `makers` distinct makers,
+    /// with every non-program account in an address lookup table and one batched
+    /// Ed25519 instruction that reads signatures and pubkeys from the match data
+    fn match_transaction_size(makers: usize) -> usize {
+        let operator = Pubkey::new_unique();
+        let market = Pubkey::new_unique();
+        let maker_keys: Vec<Pubkey> = (0..makers).map(|_| Pubkey::new_unique()).collect();
+        let params = MatchOrdersParams {
+            taker_order: signed_order(Pubkey::new_unique(), market),
+            taker_fill_amount: 1_000_000,
+            maker_orders: maker_keys.iter().map(|maker| signed_order(*maker, market)).collect(),
+            maker_fill_amounts: vec![500_000; makers],
+            maker_indices: (0..makers as u8).collect(),
+        };
+
+        let mut metas = crate::accounts::MatchOrders {
+            operator,
+            global: Pubkey::new_unique(),
+            operator_role: None,
+            market,
+            taker_order_status: Pubkey::new_unique(),
+            taker_nonce: Pubkey::new_unique(),
+            taker_market_nonce: Pubkey::new_unique(),
+            taker_cancel_bitmap: Pubkey::new_unique(),
+            taker_balance: Pubkey::new_unique(),
+            taker_position: Pubkey::new_unique(),
+            taker_stats: Pubkey::new_unique(),
+            taker: Pubkey::new_unique(),
+            instructions: INSTRUCTIONS_ID,
+            system_program: Pubkey::new_unique(),
+            operator_balance: None,
+            operator_position: None,
+            taker_signer_authorization: None,
+            taker_referral: Pubkey::new_unique(),
+            referrer_rewards: None,
+            market_usdc_vault: Pubkey::new_unique(),
+            platform_treasury: Pubkey::new_unique(),
+            usdc_mint: Pubkey::new_unique(),
+            token_program: Pubkey::new_unique(),
+        }
+        .to_account_metas(None);
+        for maker in &maker_keys {
+            metas.push(AccountMeta::new_readonly(*maker, false));
+            for _ in 1..ACCOUNTS_PER_MAKER {
+                metas.push(AccountMeta::new(Pubkey::new_unique(), false));
+            }
+        }
+        for _ in 0..makers * ACCOUNTS_PER_MAKER_FEE {
+            metas.push(AccountMeta::new(Pubkey::new_unique(), false));
+        }
+        for _ in 0..makers * ACCOUNTS_PER_MAKER_ORDER {
+            metas.push(AccountMeta::new(Pubkey::new_unique(), false));
+        }
+        let match_ix = Instruction {
+            program_id: crate::ID,
+            accounts: metas,
+            data: crate::instruction::MatchOrders { params }.data(),
+        };
+
+        // Offsets entries reference signature/pubkey bytes in the match instruction;
+        // only the 32-byte order hashes are carried in the Ed25519 instruction.
+        let signatures = makers + 1;
+        let mut ed25519_data = vec![signatures as u8, 0];
+        ed25519_data.resize(2 + signatures * (14 + 32), 0);
+        let ed25519_ix = Instruction {
+            program_id: Pubkey::from_str(ED25519_PROGRAM_ID).unwrap(),
+            accounts: vec![],
+            data: ed25519_data,
+        };
+
+        let lookup_table = AddressLookupTableAccount {
+            key: Pubkey::new_unique(),
+            addresses: match_ix
+                .accounts
+                .iter()
+                .map(|meta| meta.pubkey)
+                .filter(|key| *key != operator)
+                .collect(),
+        };
+        let message = v0::Message::try_compile(
+            &operator,
+            &[ed25519_ix, match_ix],
+            &[lookup_table],
+            Hash::default(),
+        )
+        .unwrap();
+        // compact-u16 signature count + one operator signature
+        1 + 64 + VersionedMessage::V0(message).serialize().len()
+    }
+
+    #[test]
+    fn test_match_orders_transaction_size() {
+        // A single-maker match must stay submittable
+        assert!(match_transaction_size(1) <= PACKET_DATA_SIZE);
+        // Each further maker order costs roughly a signed order plus its offsets entry
+        let per_maker = match_transaction_size(2) - match_transaction_size(1);
+        assert!(per_maker > SignedOrder::SERIALIZED_SIZE);
+    }
 }
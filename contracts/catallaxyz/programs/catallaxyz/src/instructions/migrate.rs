@@ -0,0 +1,188 @@
+//! Account Migration Instructions
+//!
+//! Fields added after the initial deployment are appended to `Global` and
+//! `Market`, behind a `layout_version` byte. Accounts created earlier are too
+//! small for the current layout; these instructions realloc them and rewrite
+//! them from the layout they were created with (see `states::legacy`):
+//! - `migrate_global` (authority) sets the order signing cluster, which the
+//!   initial deployment did not have, and the appended fields' defaults
+//! - `migrate_market` (anyone, after migrate_global) funds the larger rent
+//!   and sets the appended fields' defaults
+//!
+//! Pause trading before upgrading the program, migrate Global and every
+//! market, re-grant the keeper and operators (kept in `legacy_keeper` and
+//! `legacy_operators`) with grant_role, then unpause.
+
+use anchor_lang::prelude::*;
+use anchor_lang::system_program::{self, Transfer};
+use crate::constants::GLOBAL_SEED;
+use crate::errors::TerminatorError;
+use crate::events::AccountMigrated;
+use crate::states::legacy::{GlobalV0, MarketV0};
+use crate::states::{cluster, Global, Market};
+
+// ============================================
+// Migrate Global
+// ============================================
+
+#[derive(Accounts)]
+pub struct MigrateGlobal<'info> {
+    /// Authority recorded in the Global account (pays the extra rent)
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    /// CHECK: Global in any layout version; deserialized by the handler
+    #[account(
+        mut,
+        seeds = [GLOBAL_SEED.as_bytes()],
+        bump,
+        owner = crate::ID,
+    )]
+    pub global: UncheckedAccount<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+/// Parameters for migrate_global instruction
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug)]
+pub struct MigrateGlobalParams {
+    /// Cluster tag bound into order hashes (see `order_types::cluster`)
+    pub cluster: u8,
+    /// Keep accepting orders signed over the legacy order hash
+    pub accept_legacy_order_hash: bool,
+}
+
+pub fn handler_migrate_global(ctx: Context<MigrateGlobal>, params: MigrateGlobalParams) -> Result<()> {
+    require!(cluster::is_valid(params.cluster), TerminatorError::InvalidInput);
+
+    let info = ctx.accounts.global.to_account_info();
+    let from_version = if info.data_len() < 8 + Global::INIT_SPACE {
+        0
+    } else {
+        Global::try_deserialize(&mut &info.try_borrow_data()?[..])?.layout_version
+    };
+    let global = match from_version {
+        0 => read_legacy::<GlobalV0>(&info, Global::DISCRIMINATOR)?
+            .into_current(params.cluster, params.accept_legacy_order_hash),
+        _ => return err!(TerminatorError::AccountAlreadyMigrated),
+    };
+    require_keys_eq!(global.authority, ctx.accounts.authority.key(), TerminatorError::NotAdmin);
+
+    resize_account(
+        &info,
+        &ctx.accounts.authority.to_account_info(),
+        &ctx.accounts.system_program.to_account_info(),
+        8 + Global::INIT_SPACE,
+    )?;
+    global.try_serialize(&mut &mut info.try_borrow_mut_data()?[..])?;
+
+    let clock = Clock::get()?;
+    emit!(AccountMigrated {
+        account: info.key(),
+        from_version,
+        to_version: Global::LAYOUT_VERSION,
+        payer: ctx.accounts.authority.key(),
+        timestamp: clock.unix_timestamp,
+    });
+
+    msg!("Global migrated to layout version {}", Global::LAYOUT_VERSION);
+
+    Ok(())
+}
+
+// ============================================
+// Migrate Market
+// ============================================
+
+#[derive(Accounts)]
+pub struct MigrateMarket<'info> {
+    /// Pays the extra rent
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    /// Global (must already be migrated)
+    #[account(
+        seeds = [GLOBAL_SEED.as_bytes()],
+        bump = global.bump,
+    )]
+    pub global: Account<'info, Global>,
+
+    /// CHECK: Market in any layout version; deserialized by the handler
+    #[account(mut, owner = crate::ID)]
+    pub market: UncheckedAccount<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn handler_migrate_market(ctx: Context<MigrateMarket>) -> Result<()> {
+    let clock = Clock::get()?;
+    let info = ctx.accounts.market.to_account_info();
+    let from_version = if info.data_len() < 8 + Market::INIT_SPACE {
+        0
+    } else {
+        Market::try_deserialize(&mut &info.try_borrow_data()?[..])?.layout_version
+    };
+    let market = match from_version {
+        0 => read_legacy::<MarketV0>(&info, Market::DISCRIMINATOR)?.into_current(clock.unix_timestamp),
+        _ => return err!(TerminatorError::AccountAlreadyMigrated),
+    };
+    require_keys_eq!(market.global, ctx.accounts.global.key(), TerminatorError::InvalidAccountInput);
+
+    resize_account(
+        &info,
+        &ctx.accounts.payer.to_account_info(),
+        &ctx.accounts.system_program.to_account_info(),
+        8 + Market::INIT_SPACE,
+    )?;
+    market.try_serialize(&mut &mut info.try_borrow_mut_data()?[..])?;
+
+    emit!(AccountMigrated {
+        account: info.key(),
+        from_version,
+        to_version: Market::LAYOUT_VERSION,
+        payer: ctx.accounts.payer.key(),
+        timestamp: clock.unix_timestamp,
+    });
+
+    msg!("Market migrated to layout version {}", Market::LAYOUT_VERSION);
+
+    Ok(())
+}
+
+// ============================================
+// Helpers
+// ============================================
+
+/// Deserialize an account in a legacy layout after checking its discriminator
+///
+/// Accounts created before `layout_version` existed are smaller than the
+/// current layout. Their tail is not reliably zeroed (an Option that went
+/// from Some to None leaves stale bytes), so they are read in full with the
+/// legacy layout rather than as the current one.
+fn read_legacy<T: AnchorDeserialize>(info: &AccountInfo, discriminator: &[u8]) -> Result<T> {
+    let data = info.try_borrow_data()?;
+    require!(data.starts_with(discriminator), TerminatorError::InvalidAccountInput);
+    T::deserialize(&mut &data[discriminator.len()..])
+        .map_err(|_| TerminatorError::InvalidAccountInput.into())
+}
+
+/// Grow an account to `space` bytes (zero-filled), topping up its rent from `payer`
+fn resize_account<'info>(
+    info: &AccountInfo<'info>,
+    payer: &AccountInfo<'info>,
+    system_program: &AccountInfo<'info>,
+    space: usize,
+) -> Result<()> {
+    let shortfall = Rent::get()?.minimum_balance(space).saturating_sub(info.lamports());
+    if shortfall > 0 {
+        system_program::transfer(
+            CpiContext::new(
+                system_program.clone(),
+                Transfer { from: payer.clone(), to: info.clone() },
+            ),
+            shortfall,
+        )?;
+    }
+    info.resize(space)?;
+    Ok(())
+}
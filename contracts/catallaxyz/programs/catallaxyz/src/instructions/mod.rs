@@ -10,6 +10,7 @@ pub mod create_market;
 pub mod init_market_vault;
 pub mod settle_market;
 pub mod redeem_single_outcome;
+pub mod redeem_all;        // Redeem both outcomes + idle balance in one transfer
 
 pub mod split_position_single; // Split USDC into YES+NO for single question
 pub mod merge_position_single; // Merge YES+NO back to USDC for single question
@@ -23,9 +24,9 @@ pub mod settle_with_randomness;
 
 // Admin instructions
 pub mod terminate_if_inactive;
-pub mod set_keeper;
 pub mod pause_market;
 pub mod resume_market;
+pub mod delist_market;
 pub mod update_fee_rates;
 pub mod update_market_params;
 pub mod withdraw_platform_fees;
@@ -36,13 +37,33 @@ pub mod withdraw_reward_fees;
 // ============================================
 
 pub mod ed25519_verify;    // Shared Ed25519 signature verification
+pub mod offchain_message;  // Human-readable off-chain order messages
 pub mod calculator;        // Fee and price calculation utilities
+pub mod order_accounts;    // Shared remaining-accounts loaders
+pub mod signer_auth;       // Order signer / delegate authorization
 pub mod fill_order;        // Fill single order
+pub mod fill_orders;       // Fill multiple independent orders
+pub mod fill_multi_leg;    // Fill orders across markets atomically
 pub mod match_orders;      // Match taker against makers atomically
 pub mod cancel_order;      // Cancel order on-chain
+pub mod cancel_orders;     // Batch cancel via salt bitmap
+pub mod close_order_status; // Reclaim rent from dead order statuses
+pub mod close_user_accounts; // Reclaim rent from empty user balances/positions
+pub mod close_market;      // Sweep vault dust and close redeemed markets
 pub mod increment_nonce;   // Batch cancel via nonce increment
-pub mod operator_management; // Add/remove operators
+pub mod cancel_market_orders; // Per-market nonce / salt range / hash cancellation
+pub mod delegate_management; // Add/revoke maker delegates
+pub mod session_key_management; // Create/revoke session keys
 pub mod global_pause;      // Pause/unpause global trading
+pub mod set_legacy_order_hash; // Legacy order hash migration flag
+pub mod authority_transfer; // Two-step authority rotation
+pub mod role_management;   // Grant/revoke roles
+pub mod set_governance_delay; // Timelock for fee/parameter proposals
+pub mod market_fee_override; // Per-market fee curve/split overrides
+pub mod referral; // Referrer registration, fee accrual and claims
+pub mod creation_fee; // Configurable creation fee, alternative token, refunds
+pub mod market_metadata; // Creator metadata edits and clarification log
+pub mod migrate; // Realloc accounts created with earlier layouts
 
 // Allow ambiguous glob re-exports since each handler is namespaced by its module
 // and we call them explicitly in lib.rs (e.g., instructions::initialize::handler)
@@ -63,6 +84,8 @@ pub use init_market_vault::*;
 pub use settle_market::*;
 #[allow(ambiguous_glob_reexports)]
 pub use redeem_single_outcome::*;
+#[allow(ambiguous_glob_reexports)]
+pub use redeem_all::*;
 
 #[allow(ambiguous_glob_reexports)]
 pub use split_position_single::*;
@@ -84,12 +107,12 @@ pub use settle_with_randomness::*;
 #[allow(ambiguous_glob_reexports)]
 pub use terminate_if_inactive::*;
 #[allow(ambiguous_glob_reexports)]
-pub use set_keeper::*;
-#[allow(ambiguous_glob_reexports)]
 pub use pause_market::*;
 #[allow(ambiguous_glob_reexports)]
 pub use resume_market::*;
 #[allow(ambiguous_glob_reexports)]
+pub use delist_market::*;
+#[allow(ambiguous_glob_reexports)]
 pub use update_fee_rates::*;
 #[allow(ambiguous_glob_reexports)]
 pub use update_market_params::*;
@@ -102,12 +125,46 @@ pub use withdraw_reward_fees::*;
 #[allow(ambiguous_glob_reexports)]
 pub use fill_order::*;
 #[allow(ambiguous_glob_reexports)]
+pub use fill_orders::*;
+#[allow(ambiguous_glob_reexports)]
+pub use fill_multi_leg::*;
+#[allow(ambiguous_glob_reexports)]
 pub use match_orders::*;
 #[allow(ambiguous_glob_reexports)]
 pub use cancel_order::*;
 #[allow(ambiguous_glob_reexports)]
+pub use cancel_orders::*;
+#[allow(ambiguous_glob_reexports)]
+pub use close_order_status::*;
+#[allow(ambiguous_glob_reexports)]
+pub use close_user_accounts::*;
+#[allow(ambiguous_glob_reexports)]
+pub use close_market::*;
+#[allow(ambiguous_glob_reexports)]
 pub use increment_nonce::*;
 #[allow(ambiguous_glob_reexports)]
-pub use operator_management::*;
+pub use cancel_market_orders::*;
+#[allow(ambiguous_glob_reexports)]
+pub use delegate_management::*;
+#[allow(ambiguous_glob_reexports)]
+pub use session_key_management::*;
+#[allow(ambiguous_glob_reexports)]
+pub use global_pause::*;
+#[allow(ambiguous_glob_reexports)]
+pub use set_legacy_order_hash::*;
+#[allow(ambiguous_glob_reexports)]
+pub use authority_transfer::*;
+#[allow(ambiguous_glob_reexports)]
+pub use role_management::*;
+#[allow(ambiguous_glob_reexports)]
+pub use set_governance_delay::*;
+#[allow(ambiguous_glob_reexports)]
+pub use market_fee_override::*;
+#[allow(ambiguous_glob_reexports)]
+pub use referral::*;
+#[allow(ambiguous_glob_reexports)]
+pub use creation_fee::*;
+#[allow(ambiguous_glob_reexports)]
+pub use market_metadata::*;
 #[allow(ambiguous_glob_reexports)]
-pub use global_pause::*;
\ No newline at end of file
+pub use migrate::*;
@@ -0,0 +1,199 @@
+//! Solana Off-chain Message Format for Orders
+//!
+//! Lets wallets show users a human-readable order instead of an opaque hash.
+//! The Ed25519 instruction carries the full off-chain message; the program
+//! rebuilds the exact bytes from the `Order` and compares them.
+//!
+//! Message layout (Solana off-chain message standard, header version 0):
+//!
+//! | size | field                                               |
+//! |------|-----------------------------------------------------|
+//! | 16   | signing domain `"\xffsolana offchain"`              |
+//! | 1    | header version (0)                                  |
+//! | 32   | application domain (program ID)                     |
+//! | 1    | message format (1 = limited UTF-8)                  |
+//! | 1    | signer count (1)                                    |
+//! | 32   | signer (`order.signer`)                             |
+//! | 2    | body length (u16 little-endian)                     |
+//! | n    | body (multi-line text, see `order_message_body`)    |
+//!
+//! The body ends with the order hash in the current signing domain, so the
+//! signature is still bound to every order field, the program and the cluster.
+
+use crate::constants::PRICE_SCALE;
+use crate::states::{token_id, trigger_direction, Order};
+
+/// Off-chain message signing domain
+pub const OFFCHAIN_SIGNING_DOMAIN: &[u8] = b"\xffsolana offchain";
+
+/// Off-chain message header version
+pub const OFFCHAIN_HEADER_VERSION: u8 = 0;
+
+/// Message format: limited UTF-8 (the body's newlines are outside format 0,
+/// restricted ASCII 0x20..=0x7e)
+pub const OFFCHAIN_FORMAT_LIMITED_UTF8: u8 = 1;
+
+/// Maximum message length (header + body) for the limited UTF-8 format, so
+/// hardware signers can display the whole message
+pub const OFFCHAIN_LIMITED_MAX_LEN: usize = 1232;
+
+/// Format a 6-decimal fixed-point amount (USDC, outcome tokens, prices)
+fn format_fixed6(value: u64) -> String {
+    format!("{}.{:06}", value / PRICE_SCALE, value % PRICE_SCALE)
+}
+
+/// Human-readable order description shown by wallets
+///
+/// Example:
+/// ```text
+/// Catallaxyz order
+/// Market: <market>
+/// Side: BUY
+/// Outcome: YES
+/// Price: 0.500000 USDC
+/// Size: 1.000000 shares
+/// Total: 0.500000 USDC
+/// Expires: never
+/// Fee: 0 bps
+/// Taker: any
+/// Maker: <maker>
+/// Nonce: 0
+/// Salt: 7
+/// Trigger: none
+/// Order hash: <hex>
+/// ```
+pub fn order_message_body(order: &Order, order_hash: &[u8; 32]) -> String {
+    let (shares, usdc) = if order.is_buy() {
+        (order.taker_amount, order.maker_amount)
+    } else {
+        (order.maker_amount, order.taker_amount)
+    };
+    let outcome = match order.token_id {
+        token_id::YES => "YES",
+        token_id::NO => "NO",
+        _ => "USDC",
+    };
+    let expires = if order.expiration == 0 {
+        "never".to_string()
+    } else {
+        order.expiration.to_string()
+    };
+    let taker = if order.is_public() {
+        "any".to_string()
+    } else {
+        order.taker.to_string()
+    };
+    let trigger = match order.trigger_direction {
+        trigger_direction::ABOVE => format!("last price >= {}", format_fixed6(order.trigger_price)),
+        trigger_direction::BELOW => format!("last price <= {}", format_fixed6(order.trigger_price)),
+        _ => "none".to_string(),
+    };
+    let hash_hex: String = order_hash.iter().map(|b| format!("{:02x}", b)).collect();
+
+    format!(
+        "Catallaxyz order\n\
+         Market: {}\n\
+         Side: {}\n\
+         Outcome: {}\n\
+         Price: {} USDC\n\
+         Size: {} shares\n\
+         Total: {} USDC\n\
+         Expires: {}\n\
+         Fee: {} bps\n\
+         Taker: {}\n\
+         Maker: {}\n\
+         Nonce: {}\n\
+         Salt: {}\n\
+         Trigger: {}\n\
+         Order hash: {}",
+        order.market,
+        if order.is_buy() { "BUY" } else { "SELL" },
+        outcome,
+        format_fixed6(order.calculate_price()),
+        format_fixed6(shares),
+        format_fixed6(usdc),
+        expires,
+        order.fee_rate_bps,
+        taker,
+        order.maker,
+        order.nonce,
+        order.salt,
+        trigger,
+        hash_hex,
+    )
+}
+
+/// Full off-chain message (header + body) signed by `order.signer`
+pub fn order_offchain_message(order: &Order, order_hash: &[u8; 32]) -> Vec<u8> {
+    let body = order_message_body(order, order_hash);
+    let mut message = Vec::with_capacity(OFFCHAIN_SIGNING_DOMAIN.len() + 1 + 32 + 1 + 1 + 32 + 2 + body.len());
+    message.extend_from_slice(OFFCHAIN_SIGNING_DOMAIN);
+    message.push(OFFCHAIN_HEADER_VERSION);
+    message.extend_from_slice(crate::ID.as_ref());
+    message.push(OFFCHAIN_FORMAT_LIMITED_UTF8);
+    message.push(1);
+    message.extend_from_slice(order.signer.as_ref());
+    message.extend_from_slice(&(body.len() as u16).to_le_bytes());
+    message.extend_from_slice(body.as_bytes());
+    message
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use anchor_lang::prelude::Pubkey;
+    use crate::states::side;
+
+    #[test]
+    fn test_order_offchain_message() {
+        let order = Order {
+            salt: 7,
+            maker: Pubkey::new_unique(),
+            signer: Pubkey::new_unique(),
+            taker: Pubkey::default(),
+            market: Pubkey::new_unique(),
+            token_id: token_id::YES,
+            maker_amount: 500_000,
+            taker_amount: 1_000_000,
+            expiration: 0,
+            nonce: 3,
+            fee_rate_bps: 25,
+            side: side::BUY,
+            trigger_price: 0,
+            trigger_direction: trigger_direction::NONE,
+        };
+        let hash = [0xabu8; 32];
+
+        let body = order_message_body(&order, &hash);
+        assert!(body.is_ascii());
+        assert!(body.contains("Side: BUY\nOutcome: YES\nPrice: 0.500000 USDC\n"));
+        assert!(body.contains("Size: 1.000000 shares\nTotal: 0.500000 USDC\n"));
+        assert!(body.ends_with(&"ab".repeat(32)));
+
+        let message = order_offchain_message(&order, &hash);
+        assert_eq!(&message[..16], OFFCHAIN_SIGNING_DOMAIN);
+        assert_eq!(message[16], OFFCHAIN_HEADER_VERSION);
+        assert_eq!(&message[17..49], crate::ID.as_ref());
+        assert_eq!(message[49], OFFCHAIN_FORMAT_LIMITED_UTF8);
+        assert_eq!(message[50], 1);
+        assert_eq!(&message[51..83], order.signer.as_ref());
+        assert_eq!(u16::from_le_bytes([message[83], message[84]]) as usize, body.len());
+        assert_eq!(&message[85..], body.as_bytes());
+
+        // Longest possible body (private taker, trigger, max amounts) still fits
+        let order = Order {
+            taker: Pubkey::new_unique(),
+            maker_amount: u64::MAX,
+            taker_amount: u64::MAX,
+            expiration: i64::MAX,
+            nonce: u64::MAX,
+            salt: u64::MAX,
+            fee_rate_bps: u16::MAX,
+            trigger_price: u64::MAX,
+            trigger_direction: trigger_direction::BELOW,
+            side: side::SELL,
+            ..order
+        };
+        assert!(order_offchain_message(&order, &hash).len() <= OFFCHAIN_LIMITED_MAX_LEN);
+    }
+}
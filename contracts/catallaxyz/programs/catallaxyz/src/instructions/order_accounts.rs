@@ -0,0 +1,387 @@
+//! Order Account Helpers
+//!
+//! Shared loaders for exchange accounts passed through remaining accounts.
+//!
+//! Compact maker layout (used by match_orders):
+//! - One group of `ACCOUNTS_PER_MAKER` accounts per UNIQUE maker:
+//!   maker, maker_nonce, maker_market_nonce, maker_balance, maker_position,
+//!   signer_authorization
+//!   - maker_market_nonce is the maker's MarketNonce PDA for this market
+//!     (always required, may be uninitialized)
+//!   - signer_authorization is the maker's DelegateRegistry or SessionKey, or
+//!     the program ID if every order is self-signed
+//! - Followed by `ACCOUNTS_PER_MAKER_FEE` accounts per unique maker (same order as
+//!   the maker groups): maker_stats, maker_referral, referrer_rewards
+//! - Followed by `ACCOUNTS_PER_MAKER_ORDER` accounts per maker order (same order
+//!   as params): order_status, cancel_bitmap
+//!   - cancel_bitmap is the maker's CancelBitmap page for the order's salt
+//!     (always required, may be uninitialized)
+//!
+//! Each maker order references its maker group by index, so a maker with several
+//! resting orders is loaded (and written back) exactly once. Clients should put
+//! these accounts in an address lookup table to keep transactions small.
+
+use anchor_lang::prelude::*;
+use anchor_lang::system_program::{self, Allocate, Assign, CreateAccount, Transfer};
+use crate::errors::TerminatorError;
+use crate::instructions::signer_auth::optional_account;
+use crate::states::{
+    CancelBitmap, MarketNonce, OrderStatus, UserBalance, UserNonce, UserPosition, UserStats,
+    find_cancel_bitmap_pda, find_market_nonce_pda, find_user_stats_pda, CANCEL_BITMAP_BYTES,
+    STATS_WINDOW_DAYS,
+};
+
+/// Accounts per unique maker: maker, maker_nonce, maker_market_nonce,
+/// maker_balance, maker_position, signer_authorization
+pub const ACCOUNTS_PER_MAKER: usize = 6;
+
+/// Accounts per maker group following the compact maker groups:
+/// maker_stats, maker_referral, referrer_rewards
+pub const ACCOUNTS_PER_MAKER_FEE: usize = 3;
+
+/// Accounts per maker order: order_status, cancel_bitmap
+pub const ACCOUNTS_PER_MAKER_ORDER: usize = 2;
+
+/// Accounts loaded once per unique maker
+pub struct MakerAccounts<'info> {
+    pub maker: Pubkey,
+    pub nonce: Account<'info, UserNonce>,
+    /// Maker's MarketNonce for this market (None if not initialized)
+    pub market_nonce: Option<MarketNonce>,
+    pub balance: Account<'info, UserBalance>,
+    pub position: Account<'info, UserPosition>,
+    /// Maker's DelegateRegistry or SessionKey (None if not provided)
+    pub authorization: Option<&'info AccountInfo<'info>>,
+}
+
+impl<'info> MakerAccounts<'info> {
+    /// Persist mutable maker accounts
+    pub fn exit(&self) -> Result<()> {
+        self.balance.exit(&crate::ID)?;
+        self.position.exit(&crate::ID)?;
+        Ok(())
+    }
+}
+
+/// Load deduplicated maker account groups
+///
+/// Validates that every maker is unique, is not one of `excluded` (e.g. the taker,
+/// whose accounts are loaded separately), and that nonce/market nonce/balance/position
+/// accounts belong to the maker and market.
+pub fn load_makers<'info>(
+    accounts: &'info [AccountInfo<'info>],
+    market: &Pubkey,
+    excluded: &[Pubkey],
+) -> Result<Vec<MakerAccounts<'info>>> {
+    require!(
+        accounts.len().is_multiple_of(ACCOUNTS_PER_MAKER),
+        TerminatorError::InvalidAccountInput
+    );
+
+    let mut makers: Vec<MakerAccounts<'info>> = Vec::with_capacity(accounts.len() / ACCOUNTS_PER_MAKER);
+    for group in accounts.chunks(ACCOUNTS_PER_MAKER) {
+        let maker = group[0].key();
+        require!(
+            !excluded.contains(&maker) && makers.iter().all(|m| m.maker != maker),
+            TerminatorError::InvalidAccountInput
+        );
+
+        let nonce: Account<UserNonce> = Account::try_from(&group[1])?;
+        let market_nonce = load_market_nonce(&group[2], &maker, market)?;
+        let balance: Account<UserBalance> = Account::try_from(&group[3])?;
+        let position: Account<UserPosition> = Account::try_from(&group[4])?;
+
+        require!(nonce.user == maker, TerminatorError::Unauthorized);
+        require!(
+            balance.market == *market && position.market == *market,
+            TerminatorError::InvalidAccountInput
+        );
+        require!(
+            balance.user == maker && position.user == maker,
+            TerminatorError::Unauthorized
+        );
+
+        let authorization = optional_account(&group[5]);
+
+        makers.push(MakerAccounts { maker, nonce, market_nonce, balance, position, authorization });
+    }
+
+    Ok(makers)
+}
+
+/// Load a user's MarketNonce PDA, checking its address
+///
+/// Returns None if the account has not been initialized (no market-scoped
+/// cancellations yet).
+pub fn load_market_nonce(
+    info: &AccountInfo,
+    user: &Pubkey,
+    market: &Pubkey,
+) -> Result<Option<MarketNonce>> {
+    let (expected, _) = find_market_nonce_pda(user, market, &crate::ID);
+    require!(info.key() == expected, TerminatorError::InvalidAccountInput);
+    read_market_nonce(info)
+}
+
+/// Read a MarketNonce account whose address was already checked
+pub fn read_market_nonce(info: &AccountInfo) -> Result<Option<MarketNonce>> {
+    if info.owner != &crate::ID || info.data_is_empty() {
+        return Ok(None);
+    }
+    let data = info.try_borrow_data()?;
+    Ok(Some(MarketNonce::try_deserialize(&mut &data[..])?))
+}
+
+/// Load the user's CancelBitmap page for `salt`, checking its address
+///
+/// Returns None if the page has not been initialized (no salt cancelled in it).
+pub fn load_cancel_bitmap(
+    info: &AccountInfo,
+    user: &Pubkey,
+    salt: u64,
+) -> Result<Option<CancelBitmap>> {
+    let (expected, _) = find_cancel_bitmap_pda(user, salt, &crate::ID);
+    require!(info.key() == expected, TerminatorError::InvalidAccountInput);
+    read_cancel_bitmap(info)
+}
+
+/// Read a CancelBitmap account whose address was already checked
+pub fn read_cancel_bitmap(info: &AccountInfo) -> Result<Option<CancelBitmap>> {
+    if info.owner != &crate::ID || info.data_is_empty() {
+        return Ok(None);
+    }
+    let data = info.try_borrow_data()?;
+    Ok(Some(CancelBitmap::try_deserialize(&mut &data[..])?))
+}
+
+/// Load the user's CancelBitmap page, creating it if it does not exist yet
+///
+/// The page is returned by value; callers write it back after setting bits.
+pub fn load_or_init_cancel_bitmap<'info>(
+    info: &AccountInfo<'info>,
+    user: &Pubkey,
+    page: u64,
+    payer: &AccountInfo<'info>,
+    system_program: &AccountInfo<'info>,
+) -> Result<CancelBitmap> {
+    let page_bytes = page.to_le_bytes();
+    let (expected, bump) = Pubkey::find_program_address(
+        &[CancelBitmap::SEED_PREFIX, user.as_ref(), &page_bytes],
+        &crate::ID,
+    );
+    require!(info.key() == expected, TerminatorError::InvalidAccountInput);
+
+    if info.owner == &crate::ID {
+        let data = info.try_borrow_data()?;
+        return CancelBitmap::try_deserialize(&mut &data[..]);
+    }
+
+    let signer_seeds: &[&[u8]] = &[CancelBitmap::SEED_PREFIX, user.as_ref(), &page_bytes, &[bump]];
+    create_pda_account(info, payer, system_program, CancelBitmap::INIT_SPACE, signer_seeds)?;
+
+    Ok(CancelBitmap {
+        user: *user,
+        page,
+        bits: [0u8; CANCEL_BITMAP_BYTES],
+        bump,
+    })
+}
+
+/// Read a UserNonce account whose address was already checked
+pub fn read_user_nonce(info: &AccountInfo) -> Result<Option<UserNonce>> {
+    if info.owner != &crate::ID || info.data_is_empty() {
+        return Ok(None);
+    }
+    let data = info.try_borrow_data()?;
+    Ok(Some(UserNonce::try_deserialize(&mut &data[..])?))
+}
+
+/// OrderStatus read by `peek_order_status`, not yet written back
+pub struct PendingOrderStatus {
+    pub status: OrderStatus,
+    /// False until `save_order_status` creates the account
+    exists: bool,
+}
+
+/// Read an OrderStatus PDA without creating it
+///
+/// Returns the stored status, or a fresh in-memory one (after checking the PDA
+/// address against `order_hash`) if the account does not exist yet. Nothing is
+/// written until `save_order_status`, so callers can finish every check first.
+pub fn peek_order_status(
+    info: &AccountInfo,
+    order_hash: &[u8; 32],
+    maker_amount: u64,
+    payer: &Pubkey,
+) -> Result<PendingOrderStatus> {
+    if info.owner == &crate::ID {
+        let data = info.try_borrow_data()?;
+        let status = OrderStatus::try_deserialize(&mut &data[..])?;
+        require!(
+            status.order_hash == *order_hash,
+            TerminatorError::OrderHashMismatch
+        );
+        return Ok(PendingOrderStatus { status, exists: true });
+    }
+
+    let (expected, bump) = Pubkey::find_program_address(
+        &[OrderStatus::SEED_PREFIX, order_hash],
+        &crate::ID,
+    );
+    require!(info.key() == expected, TerminatorError::InvalidAccountInput);
+
+    Ok(PendingOrderStatus {
+        status: OrderStatus {
+            order_hash: *order_hash,
+            is_filled_or_cancelled: false,
+            remaining: maker_amount,
+            payer: *payer,
+            bump,
+        },
+        exists: false,
+    })
+}
+
+/// Write an OrderStatus from `peek_order_status`, creating the PDA if needed
+///
+/// Rent for a new account is paid by `payer`.
+pub fn save_order_status<'info>(
+    info: &AccountInfo<'info>,
+    pending: &PendingOrderStatus,
+    payer: &AccountInfo<'info>,
+    system_program: &AccountInfo<'info>,
+) -> Result<()> {
+    let status = &pending.status;
+    if !pending.exists {
+        let signer_seeds: &[&[u8]] = &[OrderStatus::SEED_PREFIX, &status.order_hash, &[status.bump]];
+        create_pda_account(info, payer, system_program, OrderStatus::INIT_SPACE, signer_seeds)?;
+    }
+
+    let mut data = info.try_borrow_mut_data()?;
+    status.try_serialize(&mut &mut data[..])
+}
+
+/// Load an OrderStatus PDA, creating it if it does not exist yet
+///
+/// Mirrors `init_if_needed` for accounts passed via remaining accounts:
+/// the PDA address is checked against `order_hash`, rent is paid by `payer`.
+pub fn load_or_init_order_status<'info>(
+    info: &'info AccountInfo<'info>,
+    order_hash: &[u8; 32],
+    maker_amount: u64,
+    payer: &AccountInfo<'info>,
+    system_program: &AccountInfo<'info>,
+) -> Result<Account<'info, OrderStatus>> {
+    let pending = peek_order_status(info, order_hash, maker_amount, payer.key)?;
+    if !pending.exists {
+        save_order_status(info, &pending, payer, system_program)?;
+    }
+
+    Account::try_from(info)
+}
+
+/// UserStats read by `peek_user_stats`, not yet written back
+pub struct PendingUserStats {
+    pub stats: UserStats,
+    /// False until `save_user_stats` creates the account
+    exists: bool,
+}
+
+/// Read a user's UserStats PDA without creating it
+///
+/// Returns the stored stats, or fresh in-memory stats if the account does not
+/// exist yet (the operator pays for it on the user's first fill, like
+/// `init_if_needed` in `fill_order`).
+pub fn peek_user_stats(info: &AccountInfo, user: &Pubkey) -> Result<PendingUserStats> {
+    let (expected, bump) = find_user_stats_pda(user, &crate::ID);
+    require!(info.key() == expected, TerminatorError::InvalidAccountInput);
+
+    if info.owner == &crate::ID {
+        let data = info.try_borrow_data()?;
+        let stats = UserStats::try_deserialize(&mut &data[..])?;
+        return Ok(PendingUserStats { stats, exists: true });
+    }
+
+    Ok(PendingUserStats {
+        stats: UserStats {
+            user: *user,
+            last_day: 0,
+            daily_taker_volume: [0; STATS_WINDOW_DAYS],
+            tier: 0,
+            bump,
+        },
+        exists: false,
+    })
+}
+
+/// Write UserStats from `peek_user_stats`, creating the PDA if needed
+pub fn save_user_stats<'info>(
+    info: &AccountInfo<'info>,
+    pending: &PendingUserStats,
+    payer: &AccountInfo<'info>,
+    system_program: &AccountInfo<'info>,
+) -> Result<()> {
+    let stats = &pending.stats;
+    if !pending.exists {
+        let signer_seeds: &[&[u8]] = &[UserStats::SEED_PREFIX, stats.user.as_ref(), &[stats.bump]];
+        create_pda_account(info, payer, system_program, UserStats::INIT_SPACE, signer_seeds)?;
+    }
+
+    let mut data = info.try_borrow_mut_data()?;
+    stats.try_serialize(&mut &mut data[..])
+}
+
+/// Create a program-owned PDA account (handles pre-funded addresses like Anchor's `init`)
+fn create_pda_account<'info>(
+    info: &AccountInfo<'info>,
+    payer: &AccountInfo<'info>,
+    system_program: &AccountInfo<'info>,
+    space: usize,
+    signer_seeds: &[&[u8]],
+) -> Result<()> {
+    let rent = Rent::get()?.minimum_balance(space);
+    let current_lamports = info.lamports();
+    let signer = &[signer_seeds];
+
+    if current_lamports == 0 {
+        system_program::create_account(
+            CpiContext::new_with_signer(
+                system_program.clone(),
+                CreateAccount { from: payer.clone(), to: info.clone() },
+                signer,
+            ),
+            rent,
+            space as u64,
+            &crate::ID,
+        )?;
+    } else {
+        let shortfall = rent.saturating_sub(current_lamports);
+        if shortfall > 0 {
+            system_program::transfer(
+                CpiContext::new(
+                    system_program.clone(),
+                    Transfer { from: payer.clone(), to: info.clone() },
+                ),
+                shortfall,
+            )?;
+        }
+        system_program::allocate(
+            CpiContext::new_with_signer(
+                system_program.clone(),
+                Allocate { account_to_allocate: info.clone() },
+                signer,
+            ),
+            space as u64,
+        )?;
+        system_program::assign(
+            CpiContext::new_with_signer(
+                system_program.clone(),
+                Assign { account_to_assign: info.clone() },
+                signer,
+            ),
+            &crate::ID,
+        )?;
+    }
+
+    Ok(())
+}
@@ -2,7 +2,7 @@ use anchor_lang::prelude::*;
 use crate::constants::{GLOBAL_SEED, MARKET_SEED};
 use crate::errors::TerminatorError;
 use crate::events::MarketPaused;
-use crate::states::{global::Global, market::Market};
+use crate::states::{global::Global, market::Market, role, RoleAssignment};
 
 /// Pause a market (admin only)
 /// 
@@ -19,9 +19,10 @@ use crate::states::{global::Global, market::Market};
 /// - Users can still merge positions
 #[derive(Accounts)]
 pub struct PauseMarket<'info> {
-    /// Global authority (program admin)
+    /// Pauser (or authority)
     #[account(
-        constraint = authority.key() == global.authority @ TerminatorError::Unauthorized
+        constraint = global.has_role(&authority.key(), pauser_role.as_deref(), role::PAUSER)
+            @ TerminatorError::Unauthorized
     )]
     pub authority: Signer<'info>,
 
@@ -32,6 +33,13 @@ pub struct PauseMarket<'info> {
     )]
     pub global: Account<'info, Global>,
 
+    /// Signer's pauser role assignment (omitted when the signer is the authority)
+    #[account(
+        seeds = [RoleAssignment::SEED_PREFIX, &[role::PAUSER], authority.key().as_ref()],
+        bump = pauser_role.bump,
+    )]
+    pub pauser_role: Option<Account<'info, RoleAssignment>>,
+
     /// Market to pause
     #[account(
         mut,
@@ -0,0 +1,146 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::{self as token_interface, Mint, TokenAccount, TokenInterface, TransferChecked};
+use crate::constants::{GLOBAL_SEED, MARKET_SEED, USDC_DECIMALS};
+use crate::errors::TerminatorError;
+use crate::events::AllRedeemed;
+use crate::states::{Global, Market, UserBalance, UserPosition};
+
+/// Redeem everything a user holds in a settled/terminated market in one step
+///
+/// Replaces merge_position_single + redeem_single_outcome (per outcome) +
+/// withdraw_usdc: both outcome balances are redeemed at the final prices, the
+/// idle trading balance is added, and the total is paid out with one transfer.
+/// Example: 10 YES at 0.8 + 4 NO at 0.2 + 1.5 idle USDC = 10.3 USDC
+#[derive(Accounts)]
+pub struct RedeemAll<'info> {
+    #[account(mut)]
+    pub user: Signer<'info>,
+
+    #[account(
+        seeds = [GLOBAL_SEED.as_bytes()],
+        bump = global.bump,
+    )]
+    pub global: Box<Account<'info, Global>>,
+
+    #[account(
+        mut,
+        seeds = [
+            MARKET_SEED.as_bytes(),
+            market.creator.as_ref(),
+            market.market_id.as_ref(),
+        ],
+        bump = market.bump,
+        constraint = market.global == global.key() @ TerminatorError::InvalidGlobalAccount,
+        constraint = market.can_redeem @ TerminatorError::RedemptionNotAllowed,
+    )]
+    pub market: Box<Account<'info, Market>>,
+
+    #[account(
+        mut,
+        seeds = [b"user_balance", market.key().as_ref(), user.key().as_ref()],
+        bump = user_balance.bump,
+        constraint = user_balance.user == user.key() @ TerminatorError::Unauthorized,
+    )]
+    pub user_balance: Box<Account<'info, UserBalance>>,
+
+    #[account(
+        mut,
+        seeds = [b"user_position", market.key().as_ref(), user.key().as_ref()],
+        bump = user_position.bump,
+        constraint = user_position.user == user.key() @ TerminatorError::Unauthorized,
+    )]
+    pub user_position: Box<Account<'info, UserPosition>>,
+
+    #[account(
+        mut,
+        seeds = [b"market_vault", market.key().as_ref()],
+        bump,
+        constraint = market_usdc_vault.mint == global.usdc_mint @ TerminatorError::InvalidTokenMint,
+        constraint = market_usdc_vault.owner == market.key() @ TerminatorError::Unauthorized,
+    )]
+    pub market_usdc_vault: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    /// User's USDC account to receive the payout
+    #[account(
+        mut,
+        constraint = user_usdc_account.owner == user.key() @ TerminatorError::Unauthorized,
+        constraint = user_usdc_account.mint == global.usdc_mint @ TerminatorError::InvalidTokenMint,
+    )]
+    pub user_usdc_account: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    #[account(address = global.usdc_mint @ TerminatorError::InvalidTokenMint)]
+    pub usdc_mint: Box<InterfaceAccount<'info, Mint>>,
+
+    pub token_program: Interface<'info, TokenInterface>,
+}
+
+pub fn handler(ctx: Context<RedeemAll>) -> Result<()> {
+    let market = &mut ctx.accounts.market;
+    let user_position = &mut ctx.accounts.user_position;
+    let user_balance = &mut ctx.accounts.user_balance;
+
+    let yes_amount = user_position.yes_balance;
+    let no_amount = user_position.no_balance;
+    let idle_usdc = user_balance.usdc_balance;
+
+    // Redeem both outcomes at final prices (burns supply, releases collateral)
+    let redeemed_usdc = market.redeem_positions(yes_amount, no_amount)?;
+    let total_usdc = redeemed_usdc
+        .checked_add(idle_usdc)
+        .ok_or(TerminatorError::ArithmeticOverflow)?;
+    require!(total_usdc > 0, TerminatorError::InvalidAmount);
+    require!(
+        ctx.accounts.market_usdc_vault.amount >= total_usdc,
+        TerminatorError::InsufficientVaultBalance
+    );
+
+    user_position.yes_balance = 0;
+    user_position.no_balance = 0;
+    user_balance.usdc_balance = 0;
+
+    // Single transfer from market vault to user
+    let market_seeds = &[
+        MARKET_SEED.as_bytes(),
+        market.creator.as_ref(),
+        market.market_id.as_ref(),
+        &[market.bump],
+    ];
+    let signer_seeds = &[&market_seeds[..]];
+
+    let transfer_ctx = CpiContext::new_with_signer(
+        ctx.accounts.token_program.to_account_info(),
+        TransferChecked {
+            from: ctx.accounts.market_usdc_vault.to_account_info(),
+            mint: ctx.accounts.usdc_mint.to_account_info(),
+            to: ctx.accounts.user_usdc_account.to_account_info(),
+            authority: market.to_account_info(),
+        },
+        signer_seeds,
+    );
+    token_interface::transfer_checked(transfer_ctx, total_usdc, USDC_DECIMALS)?;
+
+    // Reload vault account after CPI to get fresh balance
+    ctx.accounts.market_usdc_vault.reload()?;
+    market.verify_vault_invariant(ctx.accounts.market_usdc_vault.amount)?;
+
+    emit!(AllRedeemed {
+        market: market.key(),
+        user: ctx.accounts.user.key(),
+        yes_amount,
+        no_amount,
+        redeemed_usdc,
+        idle_usdc,
+        total_usdc,
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+
+    msg!(
+        "Redeemed {} YES + {} NO for {} USDC, plus {} idle USDC",
+        yes_amount,
+        no_amount,
+        redeemed_usdc,
+        idle_usdc
+    );
+
+    Ok(())
+}
@@ -1,6 +1,6 @@
 use anchor_lang::prelude::*;
 use anchor_spl::token_interface::{self as token_interface, TokenInterface, TokenAccount, Mint, TransferChecked};
-use crate::constants::{GLOBAL_SEED, MARKET_SEED, PRICE_SCALE};
+use crate::constants::{GLOBAL_SEED, MARKET_SEED};
 use crate::states::{Global, Market, UserPosition};
 use crate::errors::TerminatorError;
 use crate::events::CtfTokensRedeemed;
@@ -105,12 +105,14 @@ pub fn handler(ctx: Context<RedeemSingleOutcome>, params: RedeemSingleOutcomePar
         TerminatorError::InsufficientOutcomeTokensForRedemption
     );
 
-    // Calculate redemption amount: token_amount * final_price / 1_000_000
-    // final_price is in 10^6 units representing 0.0-1.0 range
-    let usdc_amount = (params.token_amount as u128)
-        .checked_mul(final_price as u128)
-        .and_then(|x| x.checked_div(PRICE_SCALE as u128))
-        .ok_or(TerminatorError::ArithmeticOverflow)? as u64;
+    // Burn the supply at the final price, enforce the redeemable limit and
+    // release collateral
+    let (yes_amount, no_amount) = if params.outcome_type == 0 {
+        (params.token_amount, 0)
+    } else {
+        (0, params.token_amount)
+    };
+    let usdc_amount = market.redeem_positions(yes_amount, no_amount)?;
 
     require!(
         usdc_amount > 0,
@@ -122,33 +124,12 @@ pub fn handler(ctx: Context<RedeemSingleOutcome>, params: RedeemSingleOutcomePar
         TerminatorError::InsufficientVaultBalance
     );
 
-    if params.outcome_type == 0 {
-        user_position.yes_balance = user_position.yes_balance
-            .checked_sub(params.token_amount)
-            .ok_or(TerminatorError::InsufficientOutcomeTokensForRedemption)?;
-        market.total_yes_supply = market.total_yes_supply
-            .checked_sub(params.token_amount)
-            .ok_or(TerminatorError::ArithmeticOverflow)?;
-    } else {
-        user_position.no_balance = user_position.no_balance
-            .checked_sub(params.token_amount)
-            .ok_or(TerminatorError::InsufficientOutcomeTokensForRedemption)?;
-        market.total_no_supply = market.total_no_supply
-            .checked_sub(params.token_amount)
-            .ok_or(TerminatorError::ArithmeticOverflow)?;
-    }
-
-    // Enforce global redeemable limit
-    let remaining = market.total_redeemable_usdc
-        .checked_sub(market.total_redeemed_usdc)
-        .ok_or(TerminatorError::ArithmeticOverflow)?;
-    require!(usdc_amount <= remaining, TerminatorError::InsufficientVaultBalance);
-    market.total_redeemed_usdc = market.total_redeemed_usdc
-        .checked_add(usdc_amount)
-        .ok_or(TerminatorError::ArithmeticOverflow)?;
-    market.total_position_collateral = market.total_position_collateral
-        .checked_sub(usdc_amount)
-        .ok_or(TerminatorError::ArithmeticOverflow)?;
+    user_position.yes_balance = user_position.yes_balance
+        .checked_sub(yes_amount)
+        .ok_or(TerminatorError::InsufficientOutcomeTokensForRedemption)?;
+    user_position.no_balance = user_position.no_balance
+        .checked_sub(no_amount)
+        .ok_or(TerminatorError::InsufficientOutcomeTokensForRedemption)?;
 
     // 2. Transfer USDC from market vault to user
     let market_seeds = &[
@@ -173,10 +154,7 @@ pub fn handler(ctx: Context<RedeemSingleOutcome>, params: RedeemSingleOutcomePar
 
     // Reload vault account after CPI to get fresh balance
     ctx.accounts.market_vault.reload()?;
-    require!(
-        ctx.accounts.market_vault.amount >= market.total_position_collateral,
-        TerminatorError::InsufficientVaultBalance
-    );
+    market.verify_vault_invariant(ctx.accounts.market_vault.amount)?;
 
     emit!(CtfTokensRedeemed {
         market: market.key(),
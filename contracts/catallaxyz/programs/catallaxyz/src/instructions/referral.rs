@@ -0,0 +1,389 @@
+//! Referral Program Instructions
+//!
+//! Users register a referrer once (`Referral` PDA). When a fill path collects
+//! a USDC fee from a referred user into the platform treasury,
+//! `Global::referral_share_rate` of the platform's cut (see `distribute_fee`)
+//! accrues to the referrer's `ReferrerRewards`, which the referrer claims from
+//! the platform treasury.
+//!
+//! The fill paths always take the user's Referral PDA address, initialized or
+//! not, so the operator cannot skip accrual by leaving it out. The referrer's
+//! ReferrerRewards is required exactly when the Referral exists.
+
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::{self as token_interface, Mint, TokenAccount, TokenInterface, TransferChecked};
+use crate::constants::{GLOBAL_SEED, PLATFORM_TREASURY_SEED, USDC_DECIMALS};
+use crate::errors::TerminatorError;
+use crate::events::{ReferralFeeAccrued, ReferralFeesClaimed, ReferralRegistered, ReferralShareRateUpdated};
+use crate::instructions::calculator::distribute_fee;
+use crate::states::{
+    find_referral_pda, find_referrer_rewards_pda, role, FeeRates, Global, Referral, ReferrerRewards,
+    RoleAssignment,
+};
+
+// ============================================
+// Register Referrer
+// ============================================
+
+#[derive(Accounts)]
+#[instruction(params: RegisterReferrerParams)]
+pub struct RegisterReferrer<'info> {
+    /// Referred user (pays for the referral accounts)
+    #[account(mut)]
+    pub user: Signer<'info>,
+
+    /// User's referral (can only be created once)
+    #[account(
+        init,
+        payer = user,
+        space = Referral::INIT_SPACE,
+        seeds = [Referral::SEED_PREFIX, user.key().as_ref()],
+        bump,
+    )]
+    pub referral: Account<'info, Referral>,
+
+    /// Referrer's rewards (created with the referrer's first referral)
+    #[account(
+        init_if_needed,
+        payer = user,
+        space = ReferrerRewards::INIT_SPACE,
+        seeds = [ReferrerRewards::SEED_PREFIX, params.referrer.as_ref()],
+        bump,
+    )]
+    pub referrer_rewards: Account<'info, ReferrerRewards>,
+
+    pub system_program: Program<'info, System>,
+}
+
+/// Parameters for register_referrer instruction
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug)]
+pub struct RegisterReferrerParams {
+    /// Referrer wallet
+    pub referrer: Pubkey,
+}
+
+pub fn handler_register_referrer(
+    ctx: Context<RegisterReferrer>,
+    params: RegisterReferrerParams,
+) -> Result<()> {
+    let user = ctx.accounts.user.key();
+    require!(
+        params.referrer != Pubkey::default() && params.referrer != user,
+        TerminatorError::InvalidReferrer
+    );
+
+    let clock = Clock::get()?;
+    let referral = &mut ctx.accounts.referral;
+    referral.user = user;
+    referral.referrer = params.referrer;
+    referral.registered_at = clock.unix_timestamp;
+    referral.bump = ctx.bumps.referral;
+
+    let rewards = &mut ctx.accounts.referrer_rewards;
+    if rewards.referrer == Pubkey::default() {
+        rewards.referrer = params.referrer;
+        rewards.bump = ctx.bumps.referrer_rewards;
+    }
+    rewards.referred_users = rewards.referred_users.saturating_add(1);
+
+    emit!(ReferralRegistered {
+        user,
+        referrer: params.referrer,
+        timestamp: clock.unix_timestamp,
+    });
+
+    msg!("Referrer registered: {} -> {}", user, params.referrer);
+
+    Ok(())
+}
+
+// ============================================
+// Claim Referral Fees
+// ============================================
+
+#[derive(Accounts)]
+pub struct ClaimReferralFees<'info> {
+    pub referrer: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [GLOBAL_SEED.as_bytes()],
+        bump = global.bump,
+    )]
+    pub global: Account<'info, Global>,
+
+    #[account(
+        mut,
+        seeds = [ReferrerRewards::SEED_PREFIX, referrer.key().as_ref()],
+        bump = referrer_rewards.bump,
+    )]
+    pub referrer_rewards: Account<'info, ReferrerRewards>,
+
+    /// Platform treasury (pays referral fees out of the platform's cut)
+    #[account(
+        mut,
+        seeds = [PLATFORM_TREASURY_SEED.as_bytes()],
+        bump = global.platform_treasury_bump,
+    )]
+    pub platform_treasury: InterfaceAccount<'info, TokenAccount>,
+
+    /// Recipient USDC account
+    #[account(
+        mut,
+        constraint = recipient_usdc_account.mint == global.usdc_mint @ TerminatorError::InvalidTokenMint,
+    )]
+    pub recipient_usdc_account: InterfaceAccount<'info, TokenAccount>,
+
+    #[account(address = global.usdc_mint @ TerminatorError::InvalidUsdcMint)]
+    pub usdc_mint: InterfaceAccount<'info, Mint>,
+
+    pub token_program: Interface<'info, TokenInterface>,
+}
+
+pub fn handler_claim_referral_fees(ctx: Context<ClaimReferralFees>) -> Result<()> {
+    let amount = ctx.accounts.referrer_rewards.accrued;
+    require!(amount > 0, TerminatorError::InvalidAmount);
+    require!(
+        ctx.accounts.platform_treasury.amount >= amount,
+        TerminatorError::InsufficientVaultBalance
+    );
+
+    let global_seeds = &[GLOBAL_SEED.as_bytes(), &[ctx.accounts.global.bump]];
+    let signer_seeds = &[&global_seeds[..]];
+    let transfer_ctx = CpiContext::new_with_signer(
+        ctx.accounts.token_program.to_account_info(),
+        TransferChecked {
+            from: ctx.accounts.platform_treasury.to_account_info(),
+            mint: ctx.accounts.usdc_mint.to_account_info(),
+            to: ctx.accounts.recipient_usdc_account.to_account_info(),
+            authority: ctx.accounts.global.to_account_info(),
+        },
+        signer_seeds,
+    );
+    token_interface::transfer_checked(transfer_ctx, amount, USDC_DECIMALS)?;
+
+    let clock = Clock::get()?;
+    ctx.accounts.global.release_referral_rewards(amount);
+    let rewards = &mut ctx.accounts.referrer_rewards;
+    rewards.accrued = 0;
+    rewards.total_claimed = rewards.total_claimed
+        .checked_add(amount)
+        .ok_or(TerminatorError::ArithmeticOverflow)?;
+
+    emit!(ReferralFeesClaimed {
+        referrer: rewards.referrer,
+        recipient: ctx.accounts.recipient_usdc_account.key(),
+        amount,
+        total_claimed: rewards.total_claimed,
+        timestamp: clock.unix_timestamp,
+    });
+
+    msg!("Referral fees claimed: {} USDC", amount as f64 / 1_000_000.0);
+
+    Ok(())
+}
+
+// ============================================
+// Set Referral Share Rate
+// ============================================
+
+#[derive(Accounts)]
+pub struct SetReferralShareRate<'info> {
+    /// Fee admin (or authority)
+    #[account(
+        constraint = global.has_role(&authority.key(), fee_admin_role.as_deref(), role::FEE_ADMIN)
+            @ TerminatorError::Unauthorized
+    )]
+    pub authority: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [GLOBAL_SEED.as_bytes()],
+        bump = global.bump,
+    )]
+    pub global: Account<'info, Global>,
+
+    /// Signer's fee admin role assignment (omitted when the signer is the authority)
+    #[account(
+        seeds = [RoleAssignment::SEED_PREFIX, &[role::FEE_ADMIN], authority.key().as_ref()],
+        bump = fee_admin_role.bump,
+    )]
+    pub fee_admin_role: Option<Account<'info, RoleAssignment>>,
+}
+
+/// Parameters for set_referral_share_rate instruction
+///
+/// Applies immediately: the share only moves fees between the platform and
+/// referrers, so traders' fees do not change.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug)]
+pub struct SetReferralShareRateParams {
+    /// Referrer's share of the platform fee (scaled by 10^6, 0-1,000,000)
+    pub referral_share_rate: u32,
+}
+
+pub fn handler_set_referral_share_rate(
+    ctx: Context<SetReferralShareRate>,
+    params: SetReferralShareRateParams,
+) -> Result<()> {
+    require!(
+        params.referral_share_rate <= 1_000_000,
+        TerminatorError::InvalidFeeRate
+    );
+
+    let clock = Clock::get()?;
+    ctx.accounts.global.referral_share_rate = params.referral_share_rate;
+
+    emit!(ReferralShareRateUpdated {
+        referral_share_rate: params.referral_share_rate,
+        updated_by: ctx.accounts.authority.key(),
+        timestamp: clock.unix_timestamp,
+    });
+
+    msg!("Referral share rate: {}%", params.referral_share_rate as f64 / 10_000.0);
+
+    Ok(())
+}
+
+// ============================================
+// Fill Path Accrual
+// ============================================
+
+/// A referred user's Referral and their referrer's ReferrerRewards account
+pub struct ReferralAccounts<'a, 'info> {
+    pub referral: Referral,
+    /// Updated in place by `accrue_referral_fee`, so users sharing a referrer
+    /// can accrue in the same instruction
+    rewards: &'a AccountInfo<'info>,
+}
+
+/// Load the Referral of `user` and, if it exists, its referrer's rewards
+///
+/// `referral` must be the user's Referral PDA (initialized or not);
+/// `rewards` is required if and only if the user registered a referrer.
+pub fn load_referral_accounts<'a, 'info>(
+    referral: &AccountInfo<'info>,
+    rewards: Option<&'a AccountInfo<'info>>,
+    user: &Pubkey,
+) -> Result<Option<ReferralAccounts<'a, 'info>>> {
+    let (expected, _) = find_referral_pda(user, &crate::ID);
+    require!(referral.key() == expected, TerminatorError::InvalidAccountInput);
+
+    if referral.owner != &crate::ID || referral.data_is_empty() {
+        require!(rewards.is_none(), TerminatorError::InvalidAccountInput);
+        return Ok(None);
+    }
+    let referral = {
+        let data = referral.try_borrow_data()?;
+        Referral::try_deserialize(&mut &data[..])?
+    };
+
+    let rewards = rewards.ok_or(TerminatorError::InvalidAccountInput)?;
+    require!(rewards.owner == &crate::ID, TerminatorError::InvalidAccountInput);
+    let (expected, _) = find_referrer_rewards_pda(&referral.referrer, &crate::ID);
+    require!(rewards.key() == expected, TerminatorError::InvalidAccountInput);
+    {
+        let data = rewards.try_borrow_data()?;
+        ReferrerRewards::try_deserialize(&mut &data[..])?;
+    }
+
+    Ok(Some(ReferralAccounts { referral, rewards }))
+}
+
+/// Accrue the referrer's share of a USDC fee collected from a referred user
+///
+/// `fee` must be the part of the user's fee that was moved to the platform
+/// treasury (see `collect_trading_fee`); nothing accrues without a referral.
+/// Returns the accrued amount, which the caller reserves with
+/// `Global::reserve_referral_rewards`.
+pub fn accrue_referral_fee(
+    global: &Global,
+    fee_override: Option<&FeeRates>,
+    referral: Option<&ReferralAccounts>,
+    market: Pubkey,
+    fee: u64,
+    now: i64,
+) -> Result<u64> {
+    let Some(ReferralAccounts { referral, rewards: rewards_info }) = referral else {
+        return Ok(0);
+    };
+
+    let (_, _, _, referral_fee) = distribute_fee(global, fee_override, fee, true)?;
+    if referral_fee == 0 {
+        return Ok(0);
+    }
+
+    let mut data = rewards_info.try_borrow_mut_data()?;
+    let mut rewards = ReferrerRewards::try_deserialize(&mut &data[..])?;
+    rewards.accrued = rewards.accrued
+        .checked_add(referral_fee)
+        .ok_or(TerminatorError::ArithmeticOverflow)?;
+    rewards.try_serialize(&mut &mut data[..])?;
+
+    emit!(ReferralFeeAccrued {
+        referrer: referral.referrer,
+        user: referral.user,
+        market,
+        fee,
+        referral_fee,
+        accrued: rewards.accrued,
+        timestamp: now,
+    });
+
+    Ok(referral_fee)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_utils::{global, leak_account, serialized};
+
+    fn referral_info(user: Pubkey, referrer: Pubkey) -> &'static AccountInfo<'static> {
+        let (key, bump) = find_referral_pda(&user, &crate::ID);
+        let referral = Referral { user, referrer, registered_at: 0, bump };
+        leak_account(key, crate::ID, 1_000_000, serialized(&referral))
+    }
+
+    #[test]
+    fn test_referral_required_when_registered() {
+        let referrer = Pubkey::new_unique();
+        let (rewards_key, bump) = find_referrer_rewards_pda(&referrer, &crate::ID);
+        let rewards = ReferrerRewards { referrer, accrued: 0, total_claimed: 0, referred_users: 2, bump };
+        let rewards_info = leak_account(rewards_key, crate::ID, 1_000_000, serialized(&rewards));
+
+        // Not referred: the empty Referral PDA is accepted, rewards must be omitted
+        let user = Pubkey::new_unique();
+        let (key, _) = find_referral_pda(&user, &crate::ID);
+        let empty = leak_account(key, anchor_lang::system_program::ID, 0, Vec::new());
+        assert!(load_referral_accounts(empty, None, &user).unwrap().is_none());
+        assert!(load_referral_accounts(empty, Some(rewards_info), &user).is_err());
+        // Some other address cannot stand in for the user's Referral PDA
+        let other = leak_account(Pubkey::new_unique(), anchor_lang::system_program::ID, 0, Vec::new());
+        assert!(load_referral_accounts(other, None, &user).is_err());
+
+        // Referred: the referrer's rewards are required
+        let alice = Pubkey::new_unique();
+        let alice_referral = referral_info(alice, referrer);
+        assert!(load_referral_accounts(alice_referral, None, &alice).is_err());
+        let wrong_rewards = leak_account(
+            find_referrer_rewards_pda(&Pubkey::new_unique(), &crate::ID).0,
+            crate::ID,
+            1_000_000,
+            serialized(&rewards),
+        );
+        assert!(load_referral_accounts(alice_referral, Some(wrong_rewards), &alice).is_err());
+
+        // Two users with the same referrer accrue into the same account
+        let bob = Pubkey::new_unique();
+        let alice_accounts = load_referral_accounts(alice_referral, Some(rewards_info), &alice).unwrap();
+        let bob_accounts = load_referral_accounts(referral_info(bob, referrer), Some(rewards_info), &bob).unwrap();
+        let global = global(Pubkey::new_unique());
+        let market = Pubkey::new_unique();
+        let (_, _, _, referral_fee) = distribute_fee(&global, None, 10_000, true).unwrap();
+        assert_eq!(accrue_referral_fee(&global, None, alice_accounts.as_ref(), market, 10_000, 0).unwrap(), referral_fee);
+        assert_eq!(accrue_referral_fee(&global, None, bob_accounts.as_ref(), market, 10_000, 0).unwrap(), referral_fee);
+        assert_eq!(accrue_referral_fee(&global, None, None, market, 10_000, 0).unwrap(), 0);
+
+        let data = rewards_info.try_borrow_data().unwrap();
+        assert_eq!(ReferrerRewards::try_deserialize(&mut &data[..]).unwrap().accrued, 2 * referral_fee);
+    }
+}
@@ -2,7 +2,7 @@ use anchor_lang::prelude::*;
 use crate::constants::{GLOBAL_SEED, MARKET_SEED};
 use crate::errors::TerminatorError;
 use crate::events::MarketResumed;
-use crate::states::{global::Global, market::Market};
+use crate::states::{global::Global, market::Market, role, RoleAssignment};
 
 /// Resume a paused market (admin only)
 /// 
@@ -14,9 +14,10 @@ use crate::states::{global::Global, market::Market};
 /// - Market operates normally
 #[derive(Accounts)]
 pub struct ResumeMarket<'info> {
-    /// Global authority (program admin)
+    /// Pauser (or authority)
     #[account(
-        constraint = authority.key() == global.authority @ TerminatorError::Unauthorized
+        constraint = global.has_role(&authority.key(), pauser_role.as_deref(), role::PAUSER)
+            @ TerminatorError::Unauthorized
     )]
     pub authority: Signer<'info>,
 
@@ -27,6 +28,13 @@ pub struct ResumeMarket<'info> {
     )]
     pub global: Account<'info, Global>,
 
+    /// Signer's pauser role assignment (omitted when the signer is the authority)
+    #[account(
+        seeds = [RoleAssignment::SEED_PREFIX, &[role::PAUSER], authority.key().as_ref()],
+        bump = pauser_role.bump,
+    )]
+    pub pauser_role: Option<Account<'info, RoleAssignment>>,
+
     /// Market to resume
     #[account(
         mut,
@@ -0,0 +1,129 @@
+//! Role Management Instructions
+//!
+//! Admin-only instructions to grant and revoke roles (see `states::role`).
+//! Each grant creates a RoleAssignment PDA for (role, holder); revoking closes
+//! it and returns the rent to the admin.
+
+use anchor_lang::prelude::*;
+use crate::constants::GLOBAL_SEED;
+use crate::errors::TerminatorError;
+use crate::events::{RoleGranted, RoleRevoked};
+use crate::states::{role, Global, RoleAssignment};
+
+// ============================================
+// Grant Role
+// ============================================
+
+#[derive(Accounts)]
+#[instruction(params: GrantRoleParams)]
+pub struct GrantRole<'info> {
+    /// Admin (authority)
+    #[account(
+        mut,
+        constraint = admin.key() == global.authority @ TerminatorError::NotAdmin
+    )]
+    pub admin: Signer<'info>,
+
+    #[account(
+        seeds = [GLOBAL_SEED.as_bytes()],
+        bump = global.bump,
+    )]
+    pub global: Account<'info, Global>,
+
+    #[account(
+        init,
+        payer = admin,
+        space = RoleAssignment::INIT_SPACE,
+        seeds = [RoleAssignment::SEED_PREFIX, &[params.role], params.holder.as_ref()],
+        bump,
+    )]
+    pub role_assignment: Account<'info, RoleAssignment>,
+
+    pub system_program: Program<'info, System>,
+}
+
+/// Parameters for grant_role instruction
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug)]
+pub struct GrantRoleParams {
+    /// Role tag (see `role`)
+    pub role: u8,
+    /// Key receiving the role
+    pub holder: Pubkey,
+}
+
+pub fn handler_grant_role(ctx: Context<GrantRole>, params: GrantRoleParams) -> Result<()> {
+    let clock = Clock::get()?;
+
+    require!(role::is_valid(params.role), TerminatorError::InvalidRole);
+    // The authority already holds every role
+    require!(
+        params.holder != Pubkey::default() && !ctx.accounts.global.is_admin(&params.holder),
+        TerminatorError::InvalidInput
+    );
+
+    let assignment = &mut ctx.accounts.role_assignment;
+    assignment.role = params.role;
+    assignment.holder = params.holder;
+    assignment.granted_by = ctx.accounts.admin.key();
+    assignment.granted_at = clock.unix_timestamp;
+    assignment.bump = ctx.bumps.role_assignment;
+
+    emit!(RoleGranted {
+        role: params.role,
+        holder: params.holder,
+        granted_by: ctx.accounts.admin.key(),
+        timestamp: clock.unix_timestamp,
+    });
+
+    msg!("Role {} granted to {}", params.role, params.holder);
+
+    Ok(())
+}
+
+// ============================================
+// Revoke Role
+// ============================================
+
+#[derive(Accounts)]
+pub struct RevokeRole<'info> {
+    /// Admin (authority), receives the rent
+    #[account(
+        mut,
+        constraint = admin.key() == global.authority @ TerminatorError::NotAdmin
+    )]
+    pub admin: Signer<'info>,
+
+    #[account(
+        seeds = [GLOBAL_SEED.as_bytes()],
+        bump = global.bump,
+    )]
+    pub global: Account<'info, Global>,
+
+    #[account(
+        mut,
+        close = admin,
+        seeds = [
+            RoleAssignment::SEED_PREFIX,
+            &[role_assignment.role],
+            role_assignment.holder.as_ref(),
+        ],
+        bump = role_assignment.bump,
+    )]
+    pub role_assignment: Account<'info, RoleAssignment>,
+}
+
+pub fn handler_revoke_role(ctx: Context<RevokeRole>) -> Result<()> {
+    let clock = Clock::get()?;
+    let assignment = &ctx.accounts.role_assignment;
+
+    emit!(RoleRevoked {
+        role: assignment.role,
+        holder: assignment.holder,
+        revoked_by: ctx.accounts.admin.key(),
+        timestamp: clock.unix_timestamp,
+    });
+
+    msg!("Role {} revoked from {}", assignment.role, assignment.holder);
+
+    Ok(())
+}
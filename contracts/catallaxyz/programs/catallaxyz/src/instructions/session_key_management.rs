@@ -0,0 +1,125 @@
+//! Session Key Instructions
+//!
+//! A wallet authorizes a short-lived hot key once on-chain; the key can then sign
+//! orders for the wallet within the session's market scope, notional cap and expiry.
+//! Revoking closes the session account and kills the key immediately.
+
+use anchor_lang::prelude::*;
+use crate::errors::TerminatorError;
+use crate::events::{SessionKeyCreated, SessionKeyRevoked};
+use crate::states::{SessionKey, MAX_SESSION_MARKETS};
+
+// ============================================
+// Create Session Key
+// ============================================
+
+#[derive(Accounts)]
+#[instruction(params: CreateSessionKeyParams)]
+pub struct CreateSessionKey<'info> {
+    /// Wallet authorizing the session
+    #[account(mut)]
+    pub owner: Signer<'info>,
+
+    #[account(
+        init,
+        payer = owner,
+        space = SessionKey::INIT_SPACE,
+        seeds = [SessionKey::SEED_PREFIX, owner.key().as_ref(), params.key.as_ref()],
+        bump,
+    )]
+    pub session_key: Account<'info, SessionKey>,
+
+    pub system_program: Program<'info, System>,
+}
+
+/// Parameters for create_session_key instruction
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug)]
+pub struct CreateSessionKeyParams {
+    /// Session signing key
+    pub key: Pubkey,
+    /// Markets the key may trade (1..=MAX_SESSION_MARKETS)
+    pub markets: Vec<Pubkey>,
+    /// Maximum cumulative USDC notional
+    pub max_notional: u64,
+    /// Expiry timestamp
+    pub expires_at: i64,
+}
+
+pub fn handler_create_session_key(
+    ctx: Context<CreateSessionKey>,
+    params: CreateSessionKeyParams,
+) -> Result<()> {
+    let clock = Clock::get()?;
+    let owner = ctx.accounts.owner.key();
+
+    require!(
+        params.key != Pubkey::default() && params.key != owner,
+        TerminatorError::InvalidInput
+    );
+    require!(
+        !params.markets.is_empty() && params.markets.len() <= MAX_SESSION_MARKETS,
+        TerminatorError::InvalidInput
+    );
+    require!(params.max_notional > 0, TerminatorError::InvalidAmount);
+    require!(params.expires_at > clock.unix_timestamp, TerminatorError::InvalidInput);
+
+    let session = &mut ctx.accounts.session_key;
+    session.owner = owner;
+    session.key = params.key;
+    session.market_count = params.markets.len() as u8;
+    session.markets = [Pubkey::default(); MAX_SESSION_MARKETS];
+    session.markets[..params.markets.len()].copy_from_slice(&params.markets);
+    session.max_notional = params.max_notional;
+    session.used_notional = 0;
+    session.expires_at = params.expires_at;
+    session.bump = ctx.bumps.session_key;
+
+    emit!(SessionKeyCreated {
+        owner,
+        key: params.key,
+        markets: params.markets,
+        max_notional: params.max_notional,
+        expires_at: params.expires_at,
+        timestamp: clock.unix_timestamp,
+    });
+
+    msg!("Session key created: {}", params.key);
+
+    Ok(())
+}
+
+// ============================================
+// Revoke Session Key
+// ============================================
+
+#[derive(Accounts)]
+pub struct RevokeSessionKey<'info> {
+    /// Wallet that authorized the session
+    #[account(mut)]
+    pub owner: Signer<'info>,
+
+    #[account(
+        mut,
+        close = owner,
+        seeds = [SessionKey::SEED_PREFIX, owner.key().as_ref(), session_key.key.as_ref()],
+        bump = session_key.bump,
+        constraint = session_key.owner == owner.key() @ TerminatorError::Unauthorized,
+    )]
+    pub session_key: Account<'info, SessionKey>,
+}
+
+pub fn handler_revoke_session_key(ctx: Context<RevokeSessionKey>) -> Result<()> {
+    let clock = Clock::get()?;
+    let session = &ctx.accounts.session_key;
+
+    emit!(SessionKeyRevoked {
+        owner: session.owner,
+        key: session.key,
+        used_notional: session.used_notional,
+        timestamp: clock.unix_timestamp,
+    });
+
+    msg!("Session key revoked: {}", session.key);
+
+    Ok(())
+}
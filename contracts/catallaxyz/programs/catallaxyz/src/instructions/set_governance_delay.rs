@@ -0,0 +1,124 @@
+//! Set Governance Delay Instruction
+//!
+//! Admin-only. Sets the timelock between proposing a fee or market parameter
+//! change (update_fee_rates / update_market_params) and executing it. The new
+//! delay applies to future proposals; pending proposals keep their eta.
+//!
+//! Increases apply immediately. A decrease is itself timelocked at the
+//! current delay and applied by the permissionless `execute_governance_delay`,
+//! so the timelock cannot be bypassed by lowering it first. Setting the delay
+//! again (to any value at least the current one) drops a queued decrease.
+
+use anchor_lang::prelude::*;
+use crate::constants::GLOBAL_SEED;
+use crate::errors::TerminatorError;
+use crate::events::{GovernanceDelayProposed, GovernanceDelayUpdated};
+use crate::states::{Global, MAX_GOVERNANCE_DELAY};
+
+#[derive(Accounts)]
+pub struct SetGovernanceDelay<'info> {
+    /// Admin (authority)
+    #[account(
+        constraint = admin.key() == global.authority @ TerminatorError::NotAdmin
+    )]
+    pub admin: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [GLOBAL_SEED.as_bytes()],
+        bump = global.bump,
+    )]
+    pub global: Account<'info, Global>,
+}
+
+/// Parameters for set_governance_delay instruction
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug)]
+pub struct SetGovernanceDelayParams {
+    /// Timelock for future proposals (seconds, 0..=MAX_GOVERNANCE_DELAY)
+    pub delay: i64,
+}
+
+pub fn handler(ctx: Context<SetGovernanceDelay>, params: SetGovernanceDelayParams) -> Result<()> {
+    require!(
+        (0..=MAX_GOVERNANCE_DELAY).contains(&params.delay),
+        TerminatorError::InvalidInput
+    );
+
+    let clock = Clock::get()?;
+    let global = &mut ctx.accounts.global;
+
+    // Any new setting replaces a queued decrease
+    global.pending_governance_delay = None;
+    global.pending_governance_delay_eta = 0;
+
+    if params.delay >= global.governance_delay {
+        global.governance_delay = params.delay;
+
+        emit!(GovernanceDelayUpdated {
+            delay: params.delay,
+            updated_by: ctx.accounts.admin.key(),
+            timestamp: clock.unix_timestamp,
+        });
+
+        msg!("Governance delay set to {}s", params.delay);
+    } else {
+        let eta = clock.unix_timestamp
+            .checked_add(global.governance_delay)
+            .ok_or(TerminatorError::ArithmeticOverflow)?;
+        global.pending_governance_delay = Some(params.delay);
+        global.pending_governance_delay_eta = eta;
+
+        emit!(GovernanceDelayProposed {
+            delay: params.delay,
+            eta,
+            proposed_by: ctx.accounts.admin.key(),
+            timestamp: clock.unix_timestamp,
+        });
+
+        msg!("Governance delay decrease to {}s queued until {}", params.delay, eta);
+    }
+
+    Ok(())
+}
+
+// ============================================
+// Execute Governance Delay (permissionless)
+// ============================================
+
+#[derive(Accounts)]
+pub struct ExecuteGovernanceDelay<'info> {
+    /// Anyone can execute a queued decrease once its timelock has passed
+    pub executor: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [GLOBAL_SEED.as_bytes()],
+        bump = global.bump,
+        constraint = global.pending_governance_delay.is_some() @ TerminatorError::NoPendingProposal,
+    )]
+    pub global: Account<'info, Global>,
+}
+
+pub fn handler_execute_governance_delay(ctx: Context<ExecuteGovernanceDelay>) -> Result<()> {
+    let global = &mut ctx.accounts.global;
+    let clock = Clock::get()?;
+
+    require!(
+        clock.unix_timestamp >= global.pending_governance_delay_eta,
+        TerminatorError::GovernanceTimelockActive
+    );
+
+    let delay = global.pending_governance_delay.take().ok_or(TerminatorError::NoPendingProposal)?;
+    global.governance_delay = delay;
+    global.pending_governance_delay_eta = 0;
+
+    emit!(GovernanceDelayUpdated {
+        delay,
+        updated_by: ctx.accounts.executor.key(),
+        timestamp: clock.unix_timestamp,
+    });
+
+    msg!("Governance delay set to {}s", delay);
+
+    Ok(())
+}
@@ -0,0 +1,51 @@
+//! Set Legacy Order Hash Instruction
+//! 
+//! Admin-only migration switch. While enabled, fill paths also accept order
+//! signatures over the legacy v1 hash (no program ID / cluster binding).
+//! Should be disabled once clients sign with the structured order domain.
+
+use anchor_lang::prelude::*;
+use crate::constants::GLOBAL_SEED;
+use crate::errors::TerminatorError;
+use crate::events::LegacyOrderHashUpdated;
+use crate::states::Global;
+
+#[derive(Accounts)]
+pub struct SetLegacyOrderHash<'info> {
+    /// Admin (authority)
+    #[account(
+        constraint = admin.key() == global.authority @ TerminatorError::NotAdmin
+    )]
+    pub admin: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [GLOBAL_SEED.as_bytes()],
+        bump = global.bump,
+    )]
+    pub global: Account<'info, Global>,
+}
+
+/// Parameters for set_legacy_order_hash instruction
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug)]
+pub struct SetLegacyOrderHashParams {
+    /// Accept signatures over the legacy order hash
+    pub accept_legacy_order_hash: bool,
+}
+
+pub fn handler(ctx: Context<SetLegacyOrderHash>, params: SetLegacyOrderHashParams) -> Result<()> {
+    let clock = Clock::get()?;
+    let global = &mut ctx.accounts.global;
+    
+    global.accept_legacy_order_hash = params.accept_legacy_order_hash;
+    
+    emit!(LegacyOrderHashUpdated {
+        accept_legacy_order_hash: params.accept_legacy_order_hash,
+        updated_by: ctx.accounts.admin.key(),
+        timestamp: clock.unix_timestamp,
+    });
+    
+    msg!("Legacy order hash accepted: {}", params.accept_legacy_order_hash);
+    
+    Ok(())
+}
@@ -3,16 +3,18 @@ use anchor_spl::token_interface::{self as token_interface, TokenAccount, TokenIn
 use crate::constants::{GLOBAL_SEED, OUTCOME_YES, OUTCOME_NO, CREATOR_TREASURY_SEED};
 use crate::errors::TerminatorError;
 use crate::events::MarketSettled;
-use crate::states::{global::Global, market::Market};
+use crate::states::{global::Global, market::Market, role, RoleAssignment};
 
 /// Settle market based on last trade outcome
 /// Winning positions can be redeemed 1:1 for USDC
 /// Losing positions become worthless
 #[derive(Accounts)]
 pub struct SettleMarket<'info> {
+    /// Market moderator (or authority)
     #[account(
         mut,
-        constraint = authority.key() == global.authority @ TerminatorError::Unauthorized
+        constraint = global.has_role(&authority.key(), moderator_role.as_deref(), role::MARKET_MODERATOR)
+            @ TerminatorError::Unauthorized
     )]
     pub authority: Signer<'info>,
 
@@ -22,6 +24,13 @@ pub struct SettleMarket<'info> {
     )]
     pub global: Box<Account<'info, Global>>,
 
+    /// Signer's market moderator role assignment (omitted when the signer is the authority)
+    #[account(
+        seeds = [RoleAssignment::SEED_PREFIX, &[role::MARKET_MODERATOR], authority.key().as_ref()],
+        bump = moderator_role.bump,
+    )]
+    pub moderator_role: Option<Account<'info, RoleAssignment>>,
+
     #[account(
         mut,
         constraint = market.is_active() @ TerminatorError::MarketAlreadySettled,
@@ -113,7 +122,7 @@ pub fn handler(ctx: Context<SettleMarket>) -> Result<()> {
 
     // AUDIT FIX v1.2.2: Use method instead of direct assignment
     // Mark market as settled (status change from Active to Settled)
-    market.set_settled();
+    market.set_settled(clock.unix_timestamp);
     market.total_redeemable_usdc = vault_balance;
     market.total_redeemed_usdc = 0;
 
@@ -150,16 +150,8 @@ pub fn handler(ctx: Context<SettleWithRandomness>, params: SettleWithRandomnessP
 
     // Best-effort sanity: if we have recorded last prices, require params to match closely
     if let (Some(yes), Some(no)) = (market.last_trade_yes_price, market.last_trade_no_price) {
-        let yes_diff = if yes > params.last_trade_yes_price {
-            yes - params.last_trade_yes_price
-        } else {
-            params.last_trade_yes_price - yes
-        };
-        let no_diff = if no > params.last_trade_no_price {
-            no - params.last_trade_no_price
-        } else {
-            params.last_trade_no_price - no
-        };
+        let yes_diff = yes.abs_diff(params.last_trade_yes_price);
+        let no_diff = no.abs_diff(params.last_trade_no_price);
         // Allow up to 0.01% drift (100 / 1e6) to tolerate rounding
         require!(yes_diff <= 100 && no_diff <= 100, TerminatorError::InvalidInput);
     } else {
@@ -281,6 +273,7 @@ pub fn handler(ctx: Context<SettleWithRandomness>, params: SettleWithRandomnessP
             params.last_trade_yes_price,
             params.last_trade_no_price,
             params.last_trade_slot,
+            clock.unix_timestamp,
         )?;
 
         // Reload vault account to get fresh balance before setting redeemable amount
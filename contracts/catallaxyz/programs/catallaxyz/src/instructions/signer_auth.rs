@@ -0,0 +1,108 @@
+//! Order Signer Authorization
+//!
+//! Every fill path verifies the Ed25519 signature against `order.signer`. This
+//! module checks that the signer may actually act for `order.maker`:
+//! - `signer == maker`: always authorized
+//! - otherwise the authorization account must be one of:
+//!   - the maker's DelegateRegistry listing the signer as an active, unexpired
+//!     delegate with enough remaining spend cap
+//!   - a SessionKey for (maker, signer) scoped to the order's market, with
+//!     enough remaining notional and not expired
+//!
+//! Spend is recorded in memory and only written back by `commit`, so callers can
+//! authorize before executing a fill and persist once the fill has succeeded.
+
+use anchor_lang::prelude::*;
+use anchor_lang::Discriminator;
+use crate::errors::TerminatorError;
+use crate::states::{DelegateRegistry, Order, SessionKey};
+
+/// Authorization account with spend recorded in memory
+enum PendingSpend {
+    Delegate(DelegateRegistry),
+    Session(SessionKey),
+}
+
+/// Pending authorization result (spend to persist after the fill)
+pub struct SignerAuthorization<'a, 'info> {
+    pending: Option<(&'a AccountInfo<'info>, PendingSpend)>,
+}
+
+impl SignerAuthorization<'_, '_> {
+    /// Persist the recorded spend (no-op when the maker signed directly)
+    pub fn commit(&self) -> Result<()> {
+        if let Some((info, pending)) = &self.pending {
+            let mut data = info.try_borrow_mut_data()?;
+            match pending {
+                PendingSpend::Delegate(registry) => registry.try_serialize(&mut &mut data[..])?,
+                PendingSpend::Session(session) => session.try_serialize(&mut &mut data[..])?,
+            }
+        }
+        Ok(())
+    }
+}
+
+/// USDC notional of a fill (the USDC leg: paid by a BUY, received by a SELL)
+pub fn order_notional(order: &Order, making: u64, taking: u64) -> u64 {
+    if order.is_buy() { making } else { taking }
+}
+
+/// Map an optional remaining-accounts slot to an account.
+/// The program ID stands in for "no account", like Anchor optional accounts.
+pub fn optional_account<'a, 'info>(info: &'a AccountInfo<'info>) -> Option<&'a AccountInfo<'info>> {
+    if info.key() == crate::ID { None } else { Some(info) }
+}
+
+/// Check that `order.signer` may sign for `order.maker` and record the spend
+///
+/// `authorization` is the maker's DelegateRegistry or the signer's SessionKey;
+/// it is only required when the order was not signed by the maker.
+pub fn authorize_order_signer<'a, 'info>(
+    order: &Order,
+    authorization: Option<&'a AccountInfo<'info>>,
+    now: i64,
+    notional: u64,
+) -> Result<SignerAuthorization<'a, 'info>> {
+    if order.signer == order.maker {
+        return Ok(SignerAuthorization { pending: None });
+    }
+
+    let info = authorization.ok_or(TerminatorError::SignerNotAuthorized)?;
+    require!(info.owner == &crate::ID, TerminatorError::SignerNotAuthorized);
+
+    let data = info.try_borrow_data()?;
+    let pending = if data.starts_with(DelegateRegistry::DISCRIMINATOR) {
+        let mut registry = DelegateRegistry::try_deserialize(&mut &data[..])?;
+        require!(registry.maker == order.maker, TerminatorError::SignerNotAuthorized);
+        require_pda(info, &[DelegateRegistry::SEED_PREFIX, order.maker.as_ref(), &[registry.bump]])?;
+
+        registry.spend(&order.signer, now, notional)?;
+        PendingSpend::Delegate(registry)
+    } else if data.starts_with(SessionKey::DISCRIMINATOR) {
+        let mut session = SessionKey::try_deserialize(&mut &data[..])?;
+        require!(
+            session.owner == order.maker && session.key == order.signer,
+            TerminatorError::SignerNotAuthorized
+        );
+        require_pda(
+            info,
+            &[SessionKey::SEED_PREFIX, order.maker.as_ref(), order.signer.as_ref(), &[session.bump]],
+        )?;
+
+        session.spend(&order.market, now, notional)?;
+        PendingSpend::Session(session)
+    } else {
+        return err!(TerminatorError::SignerNotAuthorized);
+    };
+    drop(data);
+
+    Ok(SignerAuthorization { pending: Some((info, pending)) })
+}
+
+/// Check that an authorization account is the canonical PDA for its seeds
+fn require_pda(info: &AccountInfo, seeds: &[&[u8]]) -> Result<()> {
+    let expected = Pubkey::create_program_address(seeds, &crate::ID)
+        .map_err(|_| TerminatorError::SignerNotAuthorized)?;
+    require!(info.key() == expected, TerminatorError::SignerNotAuthorized);
+    Ok(())
+}
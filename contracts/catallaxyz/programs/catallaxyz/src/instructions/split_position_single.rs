@@ -37,8 +37,8 @@ pub struct SplitPositionSingle<'info> {
             market.market_id.as_ref(),
         ],
         bump = market.bump,
-        // Use can_trade() to also check pause status (not just active status)
-        constraint = market.can_trade() @ TerminatorError::MarketNotActive,
+        // Use can_deposit() to also check pause and delist status (not just active status)
+        constraint = market.can_deposit() @ TerminatorError::MarketNotActive,
         constraint = market.global == global.key() @ TerminatorError::InvalidAccountInput,
     )]
     pub market: Box<Account<'info, Market>>,
@@ -3,13 +3,13 @@ use anchor_spl::token_interface::{self as token_interface, TokenAccount, TokenIn
 use crate::constants::{GLOBAL_SEED, CREATOR_TREASURY_SEED, PRICE_SCALE};
 use crate::errors::TerminatorError;
 use crate::events::MarketTerminated;
-use crate::states::{global::Global, Market};
+use crate::states::{global::Global, role, Market, RoleAssignment};
 
 /// Terminate a market if it has been inactive for >= 7 days.
 ///
 /// Notes:
 /// - Solana programs can't run automatically; this instruction must be called by
-///   the global authority or designated keeper to finalize an inactive market.
+///   the global authority or a keeper (KEEPER role) to finalize an inactive market.
 /// - Final prices are taken from the market's last observed trade/order price (best-effort).
 /// - No execution reward is paid; this is an automated keeper task.
 #[derive(Accounts)]
@@ -24,10 +24,18 @@ pub struct TerminateIfInactive<'info> {
 
     /// Keeper or authority (either can call this instruction)
     #[account(
-        constraint = global.is_keeper(&caller.key()) @ TerminatorError::Unauthorized
+        constraint = global.has_role(&caller.key(), keeper_role.as_deref(), role::KEEPER)
+            @ TerminatorError::Unauthorized
     )]
     pub caller: Signer<'info>,
 
+    /// Caller's keeper role assignment (omitted when the caller is the authority)
+    #[account(
+        seeds = [RoleAssignment::SEED_PREFIX, &[role::KEEPER], caller.key().as_ref()],
+        bump = keeper_role.bump,
+    )]
+    pub keeper_role: Option<Account<'info, RoleAssignment>>,
+
     #[account(
         mut,
         constraint = market.is_active() @ TerminatorError::MarketNotActive,
@@ -1,12 +1,14 @@
-/**
+/*!
  * Treasury Utility Macros and Functions
  * AUDIT FIX: Centralized treasury initialization patterns to reduce code duplication
  */
 
 use anchor_lang::prelude::*;
-use anchor_spl::token_interface::TokenAccount;
+use anchor_spl::token_interface::{self as token_interface, TokenAccount, TransferChecked};
 
+use crate::constants::{MARKET_SEED, USDC_DECIMALS};
 use crate::errors::TerminatorError;
+use crate::states::{Global, Market};
 
 /// Verify USDC mint matches global configuration
 /// 
@@ -59,6 +61,50 @@ pub fn log_treasury_init(treasury_type: &str, treasury_key: &Pubkey) {
     msg!("{} treasury initialized: {}", treasury_type, treasury_key);
 }
 
+/// Move USDC trading fees from a market vault into the platform treasury
+///
+/// Fill paths deduct USDC fees from the payee's internal balance, which leaves
+/// the USDC in the market vault; it becomes platform revenue (and can fund
+/// referral claims) only once moved here.
+pub fn collect_trading_fee<'info>(
+    global: &mut Global,
+    market: &Account<'info, Market>,
+    market_usdc_vault: &AccountInfo<'info>,
+    platform_treasury: &AccountInfo<'info>,
+    usdc_mint: &AccountInfo<'info>,
+    token_program: &AccountInfo<'info>,
+    amount: u64,
+) -> Result<()> {
+    if amount == 0 {
+        return Ok(());
+    }
+
+    let market_seeds = &[
+        MARKET_SEED.as_bytes(),
+        market.creator.as_ref(),
+        market.market_id.as_ref(),
+        &[market.bump],
+    ];
+    let signer_seeds = &[&market_seeds[..]];
+    let transfer_ctx = CpiContext::new_with_signer(
+        token_program.clone(),
+        TransferChecked {
+            from: market_usdc_vault.clone(),
+            mint: usdc_mint.clone(),
+            to: platform_treasury.clone(),
+            authority: market.to_account_info(),
+        },
+        signer_seeds,
+    );
+    token_interface::transfer_checked(transfer_ctx, amount, USDC_DECIMALS)?;
+
+    global.total_trading_fees_collected = global.total_trading_fees_collected
+        .checked_add(amount)
+        .ok_or(TerminatorError::ArithmeticOverflow)?;
+
+    Ok(())
+}
+
 /// Calculate treasury PDA seeds
 /// 
 /// Returns the base seeds for different treasury types
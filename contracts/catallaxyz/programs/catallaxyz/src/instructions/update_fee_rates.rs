@@ -1,52 +1,74 @@
 use anchor_lang::prelude::*;
 use crate::constants::GLOBAL_SEED;
 use crate::errors::TerminatorError;
-use crate::events::GlobalFeeRatesUpdated;
-use crate::states::global::Global;
+use crate::events::{FeeRatesProposalCancelled, FeeRatesProposed, GlobalFeeRatesUpdated};
+use crate::states::{global::{FeeRates, Global}, role, FeeCurve, FeeTierTable, RoleAssignment};
 
-/// Update global fee rates (admin only)
-/// 
+// ============================================
+// Update Fee Rates (propose)
+// ============================================
+
+/// Queue new global fee rates (fee admin)
+///
 /// Allows admin to adjust the platform-wide fee configuration:
 /// - center_taker_fee_rate: Fee at 50% probability (maximum)
 /// - extreme_taker_fee_rate: Fee at 0%/100% probability (minimum)
 /// - platform_fee_rate: Platform's share of fees
 /// - maker_rebate_rate: Maker's rebate share
 /// - creator_incentive_rate: Creator's incentive share
-/// 
-/// All markets read from the Global account, so changes take effect immediately.
-/// 
+/// - curve: Fee curve shape (linear, quadratic or piecewise breakpoints)
+/// - fee_tiers: Taker fee discounts by rolling 30-day taker volume
+///
+/// The rates are queued in `Global::pending_fee_rates` and applied by the
+/// permissionless `execute_fee_rates` once `Global::governance_delay` has
+/// passed, so traders see a fee change (FeeRatesProposed) before it applies.
+/// A new proposal replaces the pending one and restarts the timelock.
+///
 /// Constraints:
 /// - Fee rates must be between 0 and 10% (0-100,000 scaled by 10^6)
 /// - center_rate must be >= extreme_rate
 /// - platform + maker + creator rates must equal 100% (1,000,000)
+/// - piecewise curves need 2-8 breakpoints with strictly increasing prices
+///   within 0-1 and rates of at most 10%; other shapes take no breakpoints
+/// - tier thresholds must strictly increase from above zero; tier discounts
+///   must not decrease and are at most 100%
 #[derive(AnchorSerialize, AnchorDeserialize, Clone)]
 pub struct UpdateFeeRatesParams {
     /// New center fee rate (at 50% probability, scaled by 10^6)
     /// Example: 32000 = 3.2%
     pub center_taker_fee_rate: u32,
-    
+
     /// New extreme fee rate (at 0%/100% probability, scaled by 10^6)
     /// Example: 2000 = 0.2%
     pub extreme_taker_fee_rate: u32,
-    
+
     /// Platform fee share (scaled by 10^6)
     /// Example: 750000 = 75%
     pub platform_fee_rate: u32,
-    
+
     /// Maker rebate rate (scaled by 10^6)
     /// Example: 200000 = 20%
     pub maker_rebate_rate: u32,
-    
+
     /// Creator incentive rate (scaled by 10^6)
     /// Example: 50000 = 5%
     pub creator_incentive_rate: u32,
+
+    /// Fee curve shape (see `curve_type`)
+    /// Example: FeeCurve::default() = linear between center and extreme
+    pub curve: FeeCurve,
+
+    /// Volume tier table (see `UserStats`)
+    /// Example: FeeTierTable::default() = no tiers
+    pub fee_tiers: FeeTierTable,
 }
 
 #[derive(Accounts)]
 pub struct UpdateFeeRates<'info> {
-    /// Global authority (program admin)
+    /// Fee admin (or authority)
     #[account(
-        constraint = authority.key() == global.authority @ TerminatorError::Unauthorized
+        constraint = global.has_role(&authority.key(), fee_admin_role.as_deref(), role::FEE_ADMIN)
+            @ TerminatorError::Unauthorized
     )]
     pub authority: Signer<'info>,
 
@@ -58,6 +80,13 @@ pub struct UpdateFeeRates<'info> {
     )]
     pub global: Account<'info, Global>,
 
+    /// Signer's fee admin role assignment (omitted when the signer is the authority)
+    #[account(
+        seeds = [RoleAssignment::SEED_PREFIX, &[role::FEE_ADMIN], authority.key().as_ref()],
+        bump = fee_admin_role.bump,
+    )]
+    pub fee_admin_role: Option<Account<'info, RoleAssignment>>,
+
     pub system_program: Program<'info, System>,
 }
 
@@ -65,61 +94,141 @@ pub fn handler(ctx: Context<UpdateFeeRates>, params: UpdateFeeRatesParams) -> Re
     let global = &mut ctx.accounts.global;
     let clock = Clock::get()?;
 
-    // Validate taker fee rates (maximum 10%)
-    const MAX_TAKER_FEE_RATE: u32 = 100_000;
-    
-    require!(
-        params.center_taker_fee_rate <= MAX_TAKER_FEE_RATE,
-        TerminatorError::InvalidFeeRate
-    );
-    
-    require!(
-        params.extreme_taker_fee_rate <= MAX_TAKER_FEE_RATE,
-        TerminatorError::InvalidFeeRate
-    );
-    
-    // Center rate must be >= extreme rate (fee curve logic)
-    require!(
-        params.center_taker_fee_rate >= params.extreme_taker_fee_rate,
-        TerminatorError::InvalidFeeConfiguration
-    );
-    
-    // Validate fee distribution (must sum to 100%)
-    // AUDIT FIX v1.1.0: Use checked_add instead of saturating_add for clarity
-    const RATE_SCALE: u32 = 1_000_000;
-    let total_distribution = params.platform_fee_rate
-        .checked_add(params.maker_rebate_rate)
-        .and_then(|sum| sum.checked_add(params.creator_incentive_rate))
+    let rates = FeeRates {
+        center_taker_fee_rate: params.center_taker_fee_rate,
+        extreme_taker_fee_rate: params.extreme_taker_fee_rate,
+        platform_fee_rate: params.platform_fee_rate,
+        maker_rebate_rate: params.maker_rebate_rate,
+        creator_incentive_rate: params.creator_incentive_rate,
+        curve: params.curve,
+    };
+    rates.validate()?;
+    params.fee_tiers.validate()?;
+
+    let eta = clock.unix_timestamp
+        .checked_add(global.governance_delay)
         .ok_or(TerminatorError::ArithmeticOverflow)?;
-    
+    global.pending_fee_rates = Some(rates);
+    global.pending_fee_tiers = params.fee_tiers;
+    global.pending_fee_rates_eta = eta;
+
+    emit!(FeeRatesProposed {
+        proposed_by: ctx.accounts.authority.key(),
+        center_taker_fee_rate: rates.center_taker_fee_rate,
+        extreme_taker_fee_rate: rates.extreme_taker_fee_rate,
+        platform_fee_rate: rates.platform_fee_rate,
+        maker_rebate_rate: rates.maker_rebate_rate,
+        creator_incentive_rate: rates.creator_incentive_rate,
+        curve: rates.curve,
+        fee_tiers: params.fee_tiers,
+        eta,
+        timestamp: clock.unix_timestamp,
+    });
+
+    msg!("Fee rates proposed (eta {})", eta);
+    msg!("Center taker rate: {}%", rates.center_taker_fee_rate as f64 / 10_000.0);
+    msg!("Extreme taker rate: {}%", rates.extreme_taker_fee_rate as f64 / 10_000.0);
+    msg!("Platform share: {}%", rates.platform_fee_rate as f64 / 10_000.0);
+    msg!("Maker rebate: {}%", rates.maker_rebate_rate as f64 / 10_000.0);
+    msg!("Creator incentive: {}%", rates.creator_incentive_rate as f64 / 10_000.0);
+    msg!("Curve type: {} ({} breakpoints)", rates.curve.curve_type, rates.curve.point_count);
+    msg!("Fee tiers: {}", params.fee_tiers.tier_count);
+
+    Ok(())
+}
+
+// ============================================
+// Execute Fee Rates (permissionless)
+// ============================================
+
+#[derive(Accounts)]
+pub struct ExecuteFeeRates<'info> {
+    /// Anyone can execute a proposal once its timelock has passed
+    pub executor: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [GLOBAL_SEED.as_bytes()],
+        bump = global.bump,
+        constraint = global.pending_fee_rates.is_some() @ TerminatorError::NoPendingProposal,
+    )]
+    pub global: Account<'info, Global>,
+}
+
+pub fn handler_execute_fee_rates(ctx: Context<ExecuteFeeRates>) -> Result<()> {
+    let global = &mut ctx.accounts.global;
+    let clock = Clock::get()?;
+
     require!(
-        total_distribution == RATE_SCALE,
-        TerminatorError::InvalidFeeConfiguration
+        clock.unix_timestamp >= global.pending_fee_rates_eta,
+        TerminatorError::GovernanceTimelockActive
     );
 
-    // Update global fee rates
-    global.center_taker_fee_rate = params.center_taker_fee_rate;
-    global.extreme_taker_fee_rate = params.extreme_taker_fee_rate;
-    global.platform_fee_rate = params.platform_fee_rate;
-    global.maker_rebate_rate = params.maker_rebate_rate;
-    global.creator_incentive_rate = params.creator_incentive_rate;
+    let rates = global.pending_fee_rates.take().ok_or(TerminatorError::NoPendingProposal)?;
+    global.set_fee_rates(&rates);
+    global.fee_tiers = std::mem::take(&mut global.pending_fee_tiers);
+    global.pending_fee_rates_eta = 0;
 
     emit!(GlobalFeeRatesUpdated {
-        updated_by: ctx.accounts.authority.key(),
-        center_taker_fee_rate: params.center_taker_fee_rate,
-        extreme_taker_fee_rate: params.extreme_taker_fee_rate,
-        platform_fee_rate: params.platform_fee_rate,
-        maker_rebate_rate: params.maker_rebate_rate,
-        creator_incentive_rate: params.creator_incentive_rate,
+        updated_by: ctx.accounts.executor.key(),
+        center_taker_fee_rate: rates.center_taker_fee_rate,
+        extreme_taker_fee_rate: rates.extreme_taker_fee_rate,
+        platform_fee_rate: rates.platform_fee_rate,
+        maker_rebate_rate: rates.maker_rebate_rate,
+        creator_incentive_rate: rates.creator_incentive_rate,
+        curve: rates.curve,
+        fee_tiers: global.fee_tiers,
         updated_at: clock.unix_timestamp,
     });
 
     msg!("Global fee rates updated");
-    msg!("Center taker rate: {}%", params.center_taker_fee_rate as f64 / 10_000.0);
-    msg!("Extreme taker rate: {}%", params.extreme_taker_fee_rate as f64 / 10_000.0);
-    msg!("Platform share: {}%", params.platform_fee_rate as f64 / 10_000.0);
-    msg!("Maker rebate: {}%", params.maker_rebate_rate as f64 / 10_000.0);
-    msg!("Creator incentive: {}%", params.creator_incentive_rate as f64 / 10_000.0);
+
+    Ok(())
+}
+
+// ============================================
+// Cancel Fee Rates
+// ============================================
+
+#[derive(Accounts)]
+pub struct CancelFeeRates<'info> {
+    /// Fee admin (or authority)
+    #[account(
+        constraint = global.has_role(&authority.key(), fee_admin_role.as_deref(), role::FEE_ADMIN)
+            @ TerminatorError::Unauthorized
+    )]
+    pub authority: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [GLOBAL_SEED.as_bytes()],
+        bump = global.bump,
+        constraint = global.pending_fee_rates.is_some() @ TerminatorError::NoPendingProposal,
+    )]
+    pub global: Account<'info, Global>,
+
+    /// Signer's fee admin role assignment (omitted when the signer is the authority)
+    #[account(
+        seeds = [RoleAssignment::SEED_PREFIX, &[role::FEE_ADMIN], authority.key().as_ref()],
+        bump = fee_admin_role.bump,
+    )]
+    pub fee_admin_role: Option<Account<'info, RoleAssignment>>,
+}
+
+pub fn handler_cancel_fee_rates(ctx: Context<CancelFeeRates>) -> Result<()> {
+    let global = &mut ctx.accounts.global;
+    let clock = Clock::get()?;
+
+    global.pending_fee_rates = None;
+    global.pending_fee_tiers = FeeTierTable::default();
+    global.pending_fee_rates_eta = 0;
+
+    emit!(FeeRatesProposalCancelled {
+        cancelled_by: ctx.accounts.authority.key(),
+        timestamp: clock.unix_timestamp,
+    });
+
+    msg!("Pending fee rates cancelled");
 
     Ok(())
 }
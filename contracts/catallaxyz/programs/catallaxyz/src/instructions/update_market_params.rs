@@ -1,14 +1,22 @@
 use anchor_lang::prelude::*;
 use crate::constants::GLOBAL_SEED;
 use crate::errors::TerminatorError;
-use crate::events::MarketParamsUpdated;
-use crate::states::{global::Global, market::Market};
+use crate::events::{MarketParamsProposalCancelled, MarketParamsProposed, MarketParamsUpdated};
+use crate::states::{global::Global, market::Market, role, RoleAssignment};
 
-/// Update market parameters (admin only)
+// ============================================
+// Update Market Params (propose)
+// ============================================
+
+/// Queue new market parameters (market moderator)
 ///
 /// Allows admin to adjust per-market settings:
 /// - termination_probability: probability per trade (scaled by 10^6, 1000 = 0.1%)
 ///
+/// The change is queued on the market and applied by the permissionless
+/// `execute_market_params` once `Global::governance_delay` has passed.
+/// A new proposal replaces the pending one and restarts the timelock.
+///
 /// Note: Fee rates (platform/maker/creator) are now managed globally via update_fee_rates.
 /// See Global.calculate_taker_fee_rate() for fee calculation.
 #[derive(AnchorSerialize, AnchorDeserialize, Clone)]
@@ -20,9 +28,10 @@ pub struct UpdateMarketParamsInput {
 
 #[derive(Accounts)]
 pub struct UpdateMarketParamsAccounts<'info> {
-    /// Global authority (program admin)
+    /// Market moderator (or authority)
     #[account(
-        constraint = authority.key() == global.authority @ TerminatorError::Unauthorized
+        constraint = global.has_role(&authority.key(), moderator_role.as_deref(), role::MARKET_MODERATOR)
+            @ TerminatorError::Unauthorized
     )]
     pub authority: Signer<'info>,
 
@@ -33,6 +42,13 @@ pub struct UpdateMarketParamsAccounts<'info> {
     )]
     pub global: Account<'info, Global>,
 
+    /// Signer's market moderator role assignment (omitted when the signer is the authority)
+    #[account(
+        seeds = [RoleAssignment::SEED_PREFIX, &[role::MARKET_MODERATOR], authority.key().as_ref()],
+        bump = moderator_role.bump,
+    )]
+    pub moderator_role: Option<Account<'info, RoleAssignment>>,
+
     /// Market to update
     #[account(
         mut,
@@ -47,25 +63,125 @@ pub fn handler(ctx: Context<UpdateMarketParamsAccounts>, params: UpdateMarketPar
     let market = &mut ctx.accounts.market;
     let clock = Clock::get()?;
 
-    if let Some(probability) = params.termination_probability {
-        // 0% - 100% (scaled by 10^6)
-        require!(probability <= 1_000_000, TerminatorError::InvalidInput);
-        market.termination_probability = probability;
-    }
+    let probability = params.termination_probability.ok_or(TerminatorError::InvalidInput)?;
+    // 0% - 100% (scaled by 10^6)
+    require!(probability <= 1_000_000, TerminatorError::InvalidInput);
+
+    let eta = clock.unix_timestamp
+        .checked_add(ctx.accounts.global.governance_delay)
+        .ok_or(TerminatorError::ArithmeticOverflow)?;
+    market.pending_termination_probability = Some(probability);
+    market.pending_params_eta = eta;
+
+    emit!(MarketParamsProposed {
+        market: market.key(),
+        proposed_by: ctx.accounts.authority.key(),
+        termination_probability: probability,
+        eta,
+        timestamp: clock.unix_timestamp,
+    });
+
+    msg!("Market parameters proposed: {} (eta {})", market.key(), eta);
+    msg!(
+        "  Termination probability: {} (scaled by 10^6, {} %)",
+        probability,
+        probability as f64 / 10_000.0
+    );
+
+    Ok(())
+}
+
+// ============================================
+// Execute Market Params (permissionless)
+// ============================================
+
+#[derive(Accounts)]
+pub struct ExecuteMarketParams<'info> {
+    /// Anyone can execute a proposal once its timelock has passed
+    pub executor: Signer<'info>,
+
+    #[account(
+        mut,
+        constraint = market.pending_termination_probability.is_some() @ TerminatorError::NoPendingProposal,
+    )]
+    pub market: Account<'info, Market>,
+}
+
+pub fn handler_execute_market_params(ctx: Context<ExecuteMarketParams>) -> Result<()> {
+    let market = &mut ctx.accounts.market;
+    let clock = Clock::get()?;
+
+    require!(
+        clock.unix_timestamp >= market.pending_params_eta,
+        TerminatorError::GovernanceTimelockActive
+    );
+
+    let probability = market.pending_termination_probability
+        .take()
+        .ok_or(TerminatorError::NoPendingProposal)?;
+    market.termination_probability = probability;
+    market.pending_params_eta = 0;
 
     emit!(MarketParamsUpdated {
         market: market.key(),
-        updated_by: ctx.accounts.authority.key(),
+        updated_by: ctx.accounts.executor.key(),
         termination_probability: market.termination_probability,
         updated_at: clock.unix_timestamp,
     });
 
     msg!("Market parameters updated: {}", market.key());
-    msg!(
-        "  Termination probability: {} (scaled by 10^6, {} %)",
-        market.termination_probability,
-        market.termination_probability as f64 / 10_000.0
-    );
+
+    Ok(())
+}
+
+// ============================================
+// Cancel Market Params
+// ============================================
+
+#[derive(Accounts)]
+pub struct CancelMarketParams<'info> {
+    /// Market moderator (or authority)
+    #[account(
+        constraint = global.has_role(&authority.key(), moderator_role.as_deref(), role::MARKET_MODERATOR)
+            @ TerminatorError::Unauthorized
+    )]
+    pub authority: Signer<'info>,
+
+    #[account(
+        seeds = [GLOBAL_SEED.as_bytes()],
+        bump = global.bump
+    )]
+    pub global: Account<'info, Global>,
+
+    /// Signer's market moderator role assignment (omitted when the signer is the authority)
+    #[account(
+        seeds = [RoleAssignment::SEED_PREFIX, &[role::MARKET_MODERATOR], authority.key().as_ref()],
+        bump = moderator_role.bump,
+    )]
+    pub moderator_role: Option<Account<'info, RoleAssignment>>,
+
+    #[account(
+        mut,
+        constraint = market.global == global.key() @ TerminatorError::InvalidAccountInput,
+        constraint = market.pending_termination_probability.is_some() @ TerminatorError::NoPendingProposal,
+    )]
+    pub market: Account<'info, Market>,
+}
+
+pub fn handler_cancel_market_params(ctx: Context<CancelMarketParams>) -> Result<()> {
+    let market = &mut ctx.accounts.market;
+    let clock = Clock::get()?;
+
+    market.pending_termination_probability = None;
+    market.pending_params_eta = 0;
+
+    emit!(MarketParamsProposalCancelled {
+        market: market.key(),
+        cancelled_by: ctx.accounts.authority.key(),
+        timestamp: clock.unix_timestamp,
+    });
+
+    msg!("Pending market parameters cancelled: {}", market.key());
 
     Ok(())
 }
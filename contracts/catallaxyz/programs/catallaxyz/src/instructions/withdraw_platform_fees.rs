@@ -3,7 +3,7 @@ use anchor_spl::token_interface::{self as token_interface, TokenAccount, TokenIn
 use crate::constants::{GLOBAL_SEED, PLATFORM_TREASURY_SEED};
 use crate::errors::TerminatorError;
 use crate::events::PlatformFeesWithdrawn;
-use crate::states::global::Global;
+use crate::states::{global::Global, role, RoleAssignment};
 
 /// Withdraw platform fees (admin only)
 /// 
@@ -13,6 +13,7 @@ use crate::states::global::Global;
 /// Safety:
 /// - Only program authority can call this
 /// - Transfers USDC from platform treasury to recipient
+/// - Never withdraws unclaimed referral rewards (`Global::referral_rewards_outstanding`)
 /// - Updates global fee tracking stats
 #[derive(AnchorSerialize, AnchorDeserialize, Clone)]
 pub struct WithdrawPlatformFeesParams {
@@ -22,10 +23,11 @@ pub struct WithdrawPlatformFeesParams {
 
 #[derive(Accounts)]
 pub struct WithdrawPlatformFees<'info> {
-    /// Global authority (program admin)
+    /// Treasurer (or authority)
     #[account(
         mut,
-        constraint = authority.key() == global.authority @ TerminatorError::Unauthorized
+        constraint = global.has_role(&authority.key(), treasurer_role.as_deref(), role::TREASURER)
+            @ TerminatorError::Unauthorized
     )]
     pub authority: Signer<'info>,
 
@@ -37,6 +39,13 @@ pub struct WithdrawPlatformFees<'info> {
     )]
     pub global: Account<'info, Global>,
 
+    /// Signer's treasurer role assignment (omitted when the signer is the authority)
+    #[account(
+        seeds = [RoleAssignment::SEED_PREFIX, &[role::TREASURER], authority.key().as_ref()],
+        bump = treasurer_role.bump,
+    )]
+    pub treasurer_role: Option<Account<'info, RoleAssignment>>,
+
     /// Platform treasury (holds accumulated fees)
     #[account(
         mut,
@@ -68,6 +77,12 @@ pub fn handler(ctx: Context<WithdrawPlatformFees>, params: WithdrawPlatformFeesP
         TerminatorError::InsufficientVaultBalance
     );
 
+    // Unclaimed referral rewards stay in the treasury for their referrers
+    require!(
+        global.withdrawable_platform_fees(ctx.accounts.platform_treasury.amount) >= params.amount,
+        TerminatorError::ReferralRewardsReserved
+    );
+
     // Verify recipient account is for correct mint
     require!(
         ctx.accounts.recipient_usdc_account.mint == global.usdc_mint,
@@ -8,6 +8,9 @@ pub mod states;
 pub mod switchboard_lite;
 pub mod utils;
 
+#[cfg(test)]
+pub(crate) mod test_utils;
+
 // Import all instruction types (Context structs and params)
 // The ambiguous glob re-exports warning is acceptable since each handler
 // is namespaced by its module and we call them explicitly
@@ -58,6 +61,11 @@ pub mod catallaxyz {
         instructions::redeem_single_outcome::handler(ctx, params)
     }
 
+    /// Redeem both outcomes at final prices plus the idle trading balance
+    pub fn redeem_all(ctx: Context<RedeemAll>) -> Result<()> {
+        instructions::redeem_all::handler(ctx)
+    }
+
     /// Request Switchboard randomness for market settlement check
     pub fn request_randomness(ctx: Context<RequestRandomness>) -> Result<()> {
         instructions::request_randomness::handler(ctx)
@@ -80,12 +88,6 @@ pub mod catallaxyz {
         instructions::terminate_if_inactive::handler(ctx)
     }
 
-    /// Set or update the keeper wallet address.
-    /// Authority only.
-    pub fn set_keeper(ctx: Context<SetKeeper>, params: SetKeeperParams) -> Result<()> {
-        instructions::set_keeper::handler(ctx, params)
-    }
-
     /// Split USDC into YES and NO positions for a SINGLE question
     /// Split 1 USDC into 1 YES + 1 NO position
     pub fn split_position_single(
@@ -136,8 +138,14 @@ pub mod catallaxyz {
         instructions::resume_market::handler(ctx)
     }
 
-    /// Update market fee rates (admin only)
-    /// Adjusts the dynamic fee curve parameters
+    /// Delist a market with a reason code (market moderator)
+    /// Blocks deposits and splits; optionally terminates at the last traded price
+    pub fn delist_market(ctx: Context<DelistMarket>, params: DelistMarketParams) -> Result<()> {
+        instructions::delist_market::handler(ctx, params)
+    }
+
+    /// Propose new global fee rates (fee admin)
+    /// Adjusts the dynamic fee curve parameters after the governance delay
     pub fn update_fee_rates(
         ctx: Context<UpdateFeeRates>,
         params: UpdateFeeRatesParams,
@@ -145,7 +153,17 @@ pub mod catallaxyz {
         instructions::update_fee_rates::handler(ctx, params)
     }
 
-    /// Update market termination probability and maker rebate rate (admin only)
+    /// Apply pending fee rates once their timelock has passed (permissionless)
+    pub fn execute_fee_rates(ctx: Context<ExecuteFeeRates>) -> Result<()> {
+        instructions::update_fee_rates::handler_execute_fee_rates(ctx)
+    }
+
+    /// Cancel pending fee rates (fee admin)
+    pub fn cancel_fee_rates(ctx: Context<CancelFeeRates>) -> Result<()> {
+        instructions::update_fee_rates::handler_cancel_fee_rates(ctx)
+    }
+
+    /// Propose a new market termination probability (market moderator)
     pub fn update_market_params(
         ctx: Context<UpdateMarketParamsAccounts>,
         params: UpdateMarketParamsInput,
@@ -153,6 +171,95 @@ pub mod catallaxyz {
         instructions::update_market_params::handler(ctx, params)
     }
 
+    /// Apply pending market parameters once their timelock has passed (permissionless)
+    pub fn execute_market_params(ctx: Context<ExecuteMarketParams>) -> Result<()> {
+        instructions::update_market_params::handler_execute_market_params(ctx)
+    }
+
+    /// Cancel pending market parameters (market moderator)
+    pub fn cancel_market_params(ctx: Context<CancelMarketParams>) -> Result<()> {
+        instructions::update_market_params::handler_cancel_market_params(ctx)
+    }
+
+    /// Propose a market fee override (fee admin, or creator within global bounds)
+    pub fn propose_market_fee_override(
+        ctx: Context<ProposeMarketFeeOverride>,
+        params: ProposeMarketFeeOverrideParams,
+    ) -> Result<()> {
+        instructions::market_fee_override::handler_propose_market_fee_override(ctx, params)
+    }
+
+    /// Apply a pending market fee override once its timelock has passed (permissionless)
+    pub fn execute_market_fee_override(ctx: Context<ExecuteMarketFeeOverride>) -> Result<()> {
+        instructions::market_fee_override::handler_execute_market_fee_override(ctx)
+    }
+
+    /// Cancel a pending market fee override (fee admin or creator)
+    pub fn cancel_market_fee_override(ctx: Context<CancelMarketFeeOverride>) -> Result<()> {
+        instructions::market_fee_override::handler_cancel_market_fee_override(ctx)
+    }
+
+    /// Register the caller's referrer (once per user)
+    pub fn register_referrer(ctx: Context<RegisterReferrer>, params: RegisterReferrerParams) -> Result<()> {
+        instructions::referral::handler_register_referrer(ctx, params)
+    }
+
+    /// Claim accrued referral fees from the platform treasury
+    pub fn claim_referral_fees(ctx: Context<ClaimReferralFees>) -> Result<()> {
+        instructions::referral::handler_claim_referral_fees(ctx)
+    }
+
+    /// Set the referrer's share of the platform fee (fee admin)
+    pub fn set_referral_share_rate(
+        ctx: Context<SetReferralShareRate>,
+        params: SetReferralShareRateParams,
+    ) -> Result<()> {
+        instructions::referral::handler_set_referral_share_rate(ctx, params)
+    }
+
+    /// Set the market creation fee, refund volume and alternative fee token (fee admin)
+    pub fn update_creation_fee(
+        ctx: Context<UpdateCreationFee>,
+        params: UpdateCreationFeeParams,
+    ) -> Result<()> {
+        instructions::creation_fee::handler_update_creation_fee(ctx, params)
+    }
+
+    /// Refund a USDC creation fee once the market reaches the refund volume
+    pub fn claim_creation_fee_refund(ctx: Context<ClaimCreationFeeRefund>) -> Result<()> {
+        instructions::creation_fee::handler_claim_creation_fee_refund(ctx)
+    }
+
+    /// Edit market metadata before the first trade or position (creator)
+    pub fn update_market_metadata(
+        ctx: Context<UpdateMarketMetadata>,
+        params: UpdateMarketMetadataParams,
+    ) -> Result<()> {
+        instructions::market_metadata::handler_update_market_metadata(ctx, params)
+    }
+
+    /// Append a clarification to the market's clarification log (creator)
+    pub fn append_market_clarification(
+        ctx: Context<AppendMarketClarification>,
+        params: AppendMarketClarificationParams,
+    ) -> Result<()> {
+        instructions::market_metadata::handler_append_market_clarification(ctx, params)
+    }
+
+    /// Set the timelock for fee and market parameter proposals (admin only)
+    /// Increases apply immediately; decreases are queued at the current delay
+    pub fn set_governance_delay(
+        ctx: Context<SetGovernanceDelay>,
+        params: SetGovernanceDelayParams,
+    ) -> Result<()> {
+        instructions::set_governance_delay::handler(ctx, params)
+    }
+
+    /// Apply a queued governance delay decrease once its timelock has passed (permissionless)
+    pub fn execute_governance_delay(ctx: Context<ExecuteGovernanceDelay>) -> Result<()> {
+        instructions::set_governance_delay::handler_execute_governance_delay(ctx)
+    }
+
     /// Initialize reward treasury (admin only)
     pub fn init_reward_treasury(ctx: Context<InitRewardTreasury>) -> Result<()> {
         instructions::init_reward_treasury::handler(ctx)
@@ -201,6 +308,24 @@ pub mod catallaxyz {
         instructions::fill_order::handler(ctx, params)
     }
 
+    /// Fill multiple independent signed orders in one market
+    /// Operator acts as counterparty; all-or-nothing or best-effort
+    pub fn fill_orders<'info>(
+        ctx: Context<'_, '_, 'info, 'info, FillOrders<'info>>,
+        params: FillOrdersParams,
+    ) -> Result<()> {
+        instructions::fill_orders::handler(ctx, params)
+    }
+
+    /// Fill signed orders across different markets atomically
+    /// Every leg must fill completely or the whole instruction fails
+    pub fn fill_multi_leg<'info>(
+        ctx: Context<'_, '_, 'info, 'info, FillMultiLeg<'info>>,
+        params: FillMultiLegParams,
+    ) -> Result<()> {
+        instructions::fill_multi_leg::handler(ctx, params)
+    }
+
     /// Match taker order against multiple maker orders atomically
     /// Supports COMPLEMENTARY, MINT, and MERGE match types
     pub fn match_orders<'info>(
@@ -223,20 +348,90 @@ pub mod catallaxyz {
         instructions::increment_nonce::handler(ctx)
     }
 
-    /// Add an operator (admin only)
-    pub fn add_operator(
-        ctx: Context<AddOperator>,
-        params: AddOperatorParams,
+    /// Cancel many orders at once by salt (per-user cancellation bitmap)
+    pub fn cancel_orders<'info>(
+        ctx: Context<'_, '_, 'info, 'info, CancelOrders<'info>>,
+        params: CancelOrdersParams,
+    ) -> Result<()> {
+        instructions::cancel_orders::handler(ctx, params)
+    }
+
+    /// Close the status of an expired or cancelled (global or market nonce,
+    /// salt bitmap) order and refund its rent to the payer
+    pub fn close_order_status(
+        ctx: Context<CloseOrderStatus>,
+        params: CloseOrderStatusParams,
+    ) -> Result<()> {
+        instructions::close_order_status::handler(ctx, params)
+    }
+
+    /// Close the caller's empty UserBalance and UserPosition for a settled or
+    /// terminated market
+    pub fn close_user_accounts(ctx: Context<CloseUserAccounts>) -> Result<()> {
+        instructions::close_user_accounts::handler_close_user_accounts(ctx)
+    }
+
+    /// Keeper: pay out dust and close a user's accounts in a redeemable market
+    pub fn sweep_user_accounts(ctx: Context<SweepUserAccounts>) -> Result<()> {
+        instructions::close_user_accounts::handler_sweep_user_accounts(ctx)
+    }
+
+    /// Close a fully redeemed market with an empty vault, or a long-terminated
+    /// market whose leftover vault USDC (including idle user balances) is swept
+    /// to the platform treasury; the market id can never be reused
+    pub fn close_market(ctx: Context<CloseMarket>) -> Result<()> {
+        instructions::close_market::handler(ctx)
+    }
+
+    /// Cancel orders in one market: raise the market nonce,
+    /// cancel salt ranges and/or specific order hashes
+    pub fn cancel_market_orders(
+        ctx: Context<CancelMarketOrders>,
+        params: CancelMarketOrdersParams,
+    ) -> Result<()> {
+        instructions::cancel_market_orders::handler(ctx, params)
+    }
+
+    /// Grant a role to a key (admin only)
+    pub fn grant_role(
+        ctx: Context<GrantRole>,
+        params: GrantRoleParams,
+    ) -> Result<()> {
+        instructions::role_management::handler_grant_role(ctx, params)
+    }
+
+    /// Revoke a role and reclaim its rent (admin only)
+    pub fn revoke_role(ctx: Context<RevokeRole>) -> Result<()> {
+        instructions::role_management::handler_revoke_role(ctx)
+    }
+
+    /// Approve a delegate signer for the maker's orders (maker only)
+    pub fn add_delegate(
+        ctx: Context<AddDelegate>,
+        params: AddDelegateParams,
+    ) -> Result<()> {
+        instructions::delegate_management::handler_add_delegate(ctx, params)
+    }
+
+    /// Revoke a delegate signer (maker only)
+    pub fn revoke_delegate(
+        ctx: Context<RevokeDelegate>,
+        params: RevokeDelegateParams,
     ) -> Result<()> {
-        instructions::operator_management::handler_add_operator(ctx, params)
+        instructions::delegate_management::handler_revoke_delegate(ctx, params)
     }
 
-    /// Remove an operator (admin only)
-    pub fn remove_operator(
-        ctx: Context<RemoveOperator>,
-        params: RemoveOperatorParams,
+    /// Authorize a scoped, short-lived session key (wallet only)
+    pub fn create_session_key(
+        ctx: Context<CreateSessionKey>,
+        params: CreateSessionKeyParams,
     ) -> Result<()> {
-        instructions::operator_management::handler_remove_operator(ctx, params)
+        instructions::session_key_management::handler_create_session_key(ctx, params)
+    }
+
+    /// Revoke a session key immediately by closing it (wallet only)
+    pub fn revoke_session_key(ctx: Context<RevokeSessionKey>) -> Result<()> {
+        instructions::session_key_management::handler_revoke_session_key(ctx)
     }
 
     /// Pause global trading (admin only)
@@ -249,4 +444,52 @@ pub mod catallaxyz {
         instructions::global_pause::handler_unpause_trading(ctx)
     }
 
+    /// Accept or reject legacy (unbound) order hashes during migration (admin only)
+    pub fn set_legacy_order_hash(
+        ctx: Context<SetLegacyOrderHash>,
+        params: SetLegacyOrderHashParams,
+    ) -> Result<()> {
+        instructions::set_legacy_order_hash::handler(ctx, params)
+    }
+
+    /// Propose a new global authority (admin only); the proposal must be accepted
+    pub fn propose_authority(
+        ctx: Context<ProposeAuthority>,
+        params: ProposeAuthorityParams,
+    ) -> Result<()> {
+        instructions::authority_transfer::handler_propose_authority(ctx, params)
+    }
+
+    /// Accept a pending authority proposal (signed by the proposed authority)
+    pub fn accept_authority(ctx: Context<AcceptAuthority>) -> Result<()> {
+        instructions::authority_transfer::handler_accept_authority(ctx)
+    }
+
+    /// Set the timelock applied to authority proposals (admin only)
+    /// Increases apply immediately; decreases are queued at the current delay
+    pub fn set_authority_transfer_delay(
+        ctx: Context<SetAuthorityTransferDelay>,
+        params: SetAuthorityTransferDelayParams,
+    ) -> Result<()> {
+        instructions::authority_transfer::handler_set_authority_transfer_delay(ctx, params)
+    }
+
+    /// Apply a queued authority transfer delay decrease once its timelock has
+    /// passed (permissionless)
+    pub fn execute_authority_transfer_delay(ctx: Context<ExecuteAuthorityTransferDelay>) -> Result<()> {
+        instructions::authority_transfer::handler_execute_authority_transfer_delay(ctx)
+    }
+
+    /// Rewrite a Global account created with an earlier layout in the current
+    /// one (admin only)
+    pub fn migrate_global(ctx: Context<MigrateGlobal>, params: MigrateGlobalParams) -> Result<()> {
+        instructions::migrate::handler_migrate_global(ctx, params)
+    }
+
+    /// Rewrite a market created with an earlier layout in the current one
+    /// (permissionless; the caller pays the extra rent)
+    pub fn migrate_market(ctx: Context<MigrateMarket>) -> Result<()> {
+        instructions::migrate::handler_migrate_market(ctx)
+    }
+
 }
\ No newline at end of file
@@ -0,0 +1,109 @@
+use anchor_lang::prelude::*;
+
+/// Bytes of cancellation bits per page
+pub const CANCEL_BITMAP_BYTES: usize = 1024;
+
+/// Salts covered by one bitmap page
+pub const SALTS_PER_PAGE: u64 = (CANCEL_BITMAP_BYTES * 8) as u64;
+
+/// Per-user cancellation bitmap page keyed by order salt
+///
+/// Page `p` covers salts `[p * SALTS_PER_PAGE, (p + 1) * SALTS_PER_PAGE)`; a set
+/// bit cancels every order of the user with that salt, in any market.
+/// Mass cancellation costs one account write per page instead of one
+/// OrderStatus account per order. Makers that quote heavily should allocate
+/// salts sequentially so their live orders share few pages.
+#[account]
+pub struct CancelBitmap {
+    /// User's public key
+    pub user: Pubkey,
+
+    /// Page index (salt / SALTS_PER_PAGE)
+    pub page: u64,
+
+    /// Cancellation bits (bit i of byte j = salt offset j * 8 + i)
+    pub bits: [u8; CANCEL_BITMAP_BYTES],
+
+    /// PDA bump seed
+    pub bump: u8,
+}
+
+impl CancelBitmap {
+    /// Seed prefix for CancelBitmap PDA
+    pub const SEED_PREFIX: &'static [u8] = b"cancel_bitmap";
+
+    /// Space calculation for account initialization
+    /// discriminator(8) + user(32) + page(8) + bits(1024) + bump(1)
+    pub const INIT_SPACE: usize = 8 + 32 + 8 + CANCEL_BITMAP_BYTES + 1;
+
+    /// Page index for a salt
+    pub fn page_of(salt: u64) -> u64 {
+        salt / SALTS_PER_PAGE
+    }
+
+    /// Byte index and bit mask for a salt within its page
+    fn bit_position(salt: u64) -> (usize, u8) {
+        let offset = (salt % SALTS_PER_PAGE) as usize;
+        (offset / 8, 1u8 << (offset % 8))
+    }
+
+    /// Check if a salt is cancelled (false for salts outside this page)
+    pub fn is_cancelled(&self, salt: u64) -> bool {
+        if Self::page_of(salt) != self.page {
+            return false;
+        }
+        let (byte, mask) = Self::bit_position(salt);
+        self.bits[byte] & mask != 0
+    }
+
+    /// Mark a salt as cancelled (must belong to this page)
+    pub fn cancel(&mut self, salt: u64) -> Result<()> {
+        require!(
+            Self::page_of(salt) == self.page,
+            crate::errors::TerminatorError::InvalidInput
+        );
+        let (byte, mask) = Self::bit_position(salt);
+        self.bits[byte] |= mask;
+        Ok(())
+    }
+}
+
+/// Find CancelBitmap PDA address for the page containing `salt`
+pub fn find_cancel_bitmap_pda(user: &Pubkey, salt: u64, program_id: &Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(
+        &[
+            CancelBitmap::SEED_PREFIX,
+            user.as_ref(),
+            &CancelBitmap::page_of(salt).to_le_bytes(),
+        ],
+        program_id,
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_cancel_bitmap() {
+        let mut bitmap = CancelBitmap {
+            user: Pubkey::new_unique(),
+            page: 2,
+            bits: [0u8; CANCEL_BITMAP_BYTES],
+            bump: 255,
+        };
+        let first = 2 * SALTS_PER_PAGE;
+        let last = 3 * SALTS_PER_PAGE - 1;
+
+        assert!(!bitmap.is_cancelled(first));
+        bitmap.cancel(first).unwrap();
+        bitmap.cancel(last).unwrap();
+        assert!(bitmap.is_cancelled(first));
+        assert!(bitmap.is_cancelled(last));
+        assert!(!bitmap.is_cancelled(first + 1));
+
+        // Salts from other pages are never reported and cannot be set
+        assert!(!bitmap.is_cancelled(first - SALTS_PER_PAGE));
+        assert!(bitmap.cancel(last + 1).is_err());
+    }
+}
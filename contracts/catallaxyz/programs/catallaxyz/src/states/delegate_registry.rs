@@ -0,0 +1,177 @@
+use anchor_lang::prelude::*;
+use crate::errors::TerminatorError;
+
+/// Maximum number of delegates per maker
+pub const MAX_DELEGATES: usize = 8;
+
+/// A single approved delegate signer
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct DelegateEntry {
+    /// Key allowed to sign orders on behalf of the maker
+    pub signer: Pubkey,
+    /// Maximum USDC notional this delegate may trade (0 = unlimited)
+    pub spend_cap: u64,
+    /// USDC notional already traded by this delegate
+    pub spent: u64,
+    /// Unix timestamp after which the delegation is invalid (0 = never expires)
+    pub expires_at: i64,
+}
+
+impl DelegateEntry {
+    /// signer(32) + spend_cap(8) + spent(8) + expires_at(8)
+    pub const SIZE: usize = 32 + 8 + 8 + 8;
+
+    /// Check if the delegation has expired
+    pub fn is_expired(&self, now: i64) -> bool {
+        self.expires_at != 0 && now > self.expires_at
+    }
+}
+
+/// Delegation registry for a maker
+///
+/// Lists keys that may sign orders spending the maker's balances
+/// (`Order::signer != Order::maker`). Managed by the maker via
+/// add_delegate / revoke_delegate; enforced by every fill path.
+#[account]
+pub struct DelegateRegistry {
+    /// Maker who owns the registry
+    pub maker: Pubkey,
+
+    /// Number of active delegates
+    pub delegate_count: u8,
+
+    /// Approved delegates (first `delegate_count` entries are valid)
+    pub delegates: [DelegateEntry; MAX_DELEGATES],
+
+    /// PDA bump seed
+    pub bump: u8,
+}
+
+impl DelegateRegistry {
+    /// Seed prefix for DelegateRegistry PDA
+    pub const SEED_PREFIX: &'static [u8] = b"delegate_registry";
+
+    /// Space calculation for account initialization
+    /// discriminator(8) + maker(32) + delegate_count(1) + delegates(56 * 8) + bump(1)
+    pub const INIT_SPACE: usize = 8 + 32 + 1 + DelegateEntry::SIZE * MAX_DELEGATES + 1;
+
+    /// Find the active entry for a signer
+    pub fn find(&self, signer: &Pubkey) -> Option<usize> {
+        self.delegates[..self.delegate_count as usize]
+            .iter()
+            .position(|entry| entry.signer == *signer)
+    }
+
+    /// Add a delegate, or update cap/expiry if it already exists (spent is kept)
+    pub fn upsert(&mut self, signer: Pubkey, spend_cap: u64, expires_at: i64) -> Result<()> {
+        require!(
+            signer != Pubkey::default() && signer != self.maker,
+            TerminatorError::InvalidInput
+        );
+
+        if let Some(index) = self.find(&signer) {
+            let entry = &mut self.delegates[index];
+            entry.spend_cap = spend_cap;
+            entry.expires_at = expires_at;
+            return Ok(());
+        }
+
+        require!(
+            (self.delegate_count as usize) < MAX_DELEGATES,
+            TerminatorError::TooManyDelegates
+        );
+        self.delegates[self.delegate_count as usize] = DelegateEntry {
+            signer,
+            spend_cap,
+            spent: 0,
+            expires_at,
+        };
+        self.delegate_count += 1;
+        Ok(())
+    }
+
+    /// Remove a delegate (swap-remove)
+    pub fn remove(&mut self, signer: &Pubkey) -> Result<()> {
+        let index = self.find(signer).ok_or(TerminatorError::DelegateNotFound)?;
+        let last = self.delegate_count as usize - 1;
+        self.delegates[index] = self.delegates[last];
+        self.delegates[last] = DelegateEntry::default();
+        self.delegate_count -= 1;
+        Ok(())
+    }
+
+    /// Authorize `signer` to spend `notional` USDC and record the spend
+    pub fn spend(&mut self, signer: &Pubkey, now: i64, notional: u64) -> Result<()> {
+        let index = self.find(signer).ok_or(TerminatorError::SignerNotAuthorized)?;
+        let entry = &mut self.delegates[index];
+
+        require!(!entry.is_expired(now), TerminatorError::DelegationExpired);
+
+        let spent = entry.spent
+            .checked_add(notional)
+            .ok_or(TerminatorError::ArithmeticOverflow)?;
+        require!(
+            entry.spend_cap == 0 || spent <= entry.spend_cap,
+            TerminatorError::DelegateSpendCapExceeded
+        );
+        entry.spent = spent;
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn registry() -> DelegateRegistry {
+        DelegateRegistry {
+            maker: Pubkey::new_unique(),
+            delegate_count: 0,
+            delegates: [DelegateEntry::default(); MAX_DELEGATES],
+            bump: 255,
+        }
+    }
+
+    #[test]
+    fn test_delegate_spend_cap_and_expiry() {
+        let mut registry = registry();
+        let delegate = Pubkey::new_unique();
+        registry.upsert(delegate, 1_000, 100).unwrap();
+
+        // Spend within cap
+        assert!(registry.spend(&delegate, 50, 600).is_ok());
+        assert!(registry.spend(&delegate, 50, 400).is_ok());
+        // Cap exceeded
+        assert!(registry.spend(&delegate, 50, 1).is_err());
+        // Expired
+        registry.upsert(delegate, 0, 100).unwrap();
+        assert!(registry.spend(&delegate, 101, 1).is_err());
+        // Unknown signer
+        assert!(registry.spend(&Pubkey::new_unique(), 50, 1).is_err());
+    }
+
+    #[test]
+    fn test_delegate_upsert_and_remove() {
+        let mut registry = registry();
+        let maker = registry.maker;
+        assert!(registry.upsert(maker, 0, 0).is_err());
+
+        let delegates: Vec<Pubkey> = (0..MAX_DELEGATES).map(|_| Pubkey::new_unique()).collect();
+        for delegate in &delegates {
+            registry.upsert(*delegate, 0, 0).unwrap();
+        }
+        assert!(registry.upsert(Pubkey::new_unique(), 0, 0).is_err());
+
+        // Updating an existing delegate keeps its spend
+        registry.spend(&delegates[0], 0, 10).unwrap();
+        registry.upsert(delegates[0], 500, 0).unwrap();
+        assert_eq!(registry.delegates[0].spent, 10);
+        assert_eq!(registry.delegates[0].spend_cap, 500);
+
+        registry.remove(&delegates[0]).unwrap();
+        assert_eq!(registry.delegate_count as usize, MAX_DELEGATES - 1);
+        assert!(registry.find(&delegates[0]).is_none());
+        assert!(registry.find(&delegates[MAX_DELEGATES - 1]).is_some());
+        assert!(registry.remove(&delegates[0]).is_err());
+    }
+}
@@ -0,0 +1,196 @@
+use anchor_lang::prelude::*;
+use crate::constants::PRICE_SCALE;
+use crate::errors::TerminatorError;
+
+/// Maximum number of breakpoints in a piecewise-linear fee curve
+pub const MAX_FEE_CURVE_POINTS: usize = 8;
+
+/// Fee curve shape constants
+pub mod curve_type {
+    /// Triangular: center at 0.5, falling linearly to extreme at 0 and 1
+    pub const LINEAR: u8 = 0;
+    /// Polymarket-style p(1-p): center at 0.5, extreme at 0 and 1
+    pub const QUADRATIC: u8 = 1;
+    /// Linear interpolation between breakpoints (center/extreme unused)
+    pub const PIECEWISE: u8 = 2;
+
+    /// Check if a curve type is known
+    pub fn is_valid(curve_type: u8) -> bool {
+        curve_type <= PIECEWISE
+    }
+}
+
+/// Breakpoint of a piecewise-linear fee curve
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct FeeCurvePoint {
+    /// Price (scaled by 10^6, 0-1_000_000)
+    pub price: u32,
+    /// Taker fee rate at this price (scaled by 10^6)
+    pub rate: u32,
+}
+
+/// Shape of the taker fee curve over price
+///
+/// LINEAR and QUADRATIC are defined by the center/extreme rates of the
+/// enclosing `FeeRates`; PIECEWISE uses the first `point_count` breakpoints
+/// (prices strictly increasing) and is flat outside them.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct FeeCurve {
+    /// Curve shape (see `curve_type`)
+    pub curve_type: u8,
+    /// Number of valid breakpoints (PIECEWISE only)
+    pub point_count: u8,
+    /// Breakpoints (first `point_count` entries are valid)
+    pub points: [FeeCurvePoint; MAX_FEE_CURVE_POINTS],
+}
+
+impl FeeCurve {
+    /// curve_type(1) + point_count(1) + points((4 + 4) * 8)
+    pub const SIZE: usize = 1 + 1 + 8 * MAX_FEE_CURVE_POINTS;
+
+    /// Valid breakpoints
+    pub fn points(&self) -> &[FeeCurvePoint] {
+        &self.points[..(self.point_count as usize).min(MAX_FEE_CURVE_POINTS)]
+    }
+
+    /// Check the curve is well-formed and no rate exceeds `max_rate`
+    pub fn validate(&self, max_rate: u32) -> Result<()> {
+        require!(curve_type::is_valid(self.curve_type), TerminatorError::InvalidFeeCurve);
+        if self.curve_type != curve_type::PIECEWISE {
+            require!(self.point_count == 0, TerminatorError::InvalidFeeCurve);
+            return Ok(());
+        }
+
+        require!(
+            (2..=MAX_FEE_CURVE_POINTS).contains(&(self.point_count as usize)),
+            TerminatorError::InvalidFeeCurve
+        );
+        let points = self.points();
+        require!(
+            points.windows(2).all(|pair| pair[0].price < pair[1].price)
+                && points.iter().all(|point| point.price as u64 <= PRICE_SCALE),
+            TerminatorError::InvalidFeeCurve
+        );
+        require!(
+            points.iter().all(|point| point.rate <= max_rate),
+            TerminatorError::InvalidFeeRate
+        );
+        Ok(())
+    }
+
+    /// Taker fee rate at `price` (scaled by 10^6)
+    pub fn rate(&self, center: u32, extreme: u32, price: u64) -> u32 {
+        let price = price.min(PRICE_SCALE);
+        match self.curve_type {
+            curve_type::QUADRATIC => {
+                // extreme + (center - extreme) * 4p(1-p)
+                let range = center.saturating_sub(extreme) as u128;
+                let shape = 4 * price as u128 * (PRICE_SCALE - price) as u128;
+                extreme + (range * shape / (PRICE_SCALE as u128 * PRICE_SCALE as u128)) as u32
+            }
+            curve_type::PIECEWISE => self.interpolate(price),
+            _ => {
+                // center - (center - extreme) * |price - 0.5| / 0.5
+                const CENTER_PRICE: u64 = PRICE_SCALE / 2;
+                let distance_from_center = price.abs_diff(CENTER_PRICE);
+                let range = center.saturating_sub(extreme) as u64;
+                center.saturating_sub((range * distance_from_center / CENTER_PRICE) as u32)
+            }
+        }
+    }
+
+    /// Linear interpolation between the breakpoints around `price`
+    fn interpolate(&self, price: u64) -> u32 {
+        let points = self.points();
+        let (Some(first), Some(last)) = (points.first(), points.last()) else {
+            return 0;
+        };
+        if price <= first.price as u64 {
+            return first.rate;
+        }
+        if price >= last.price as u64 {
+            return last.rate;
+        }
+
+        let upper = points.iter().position(|point| point.price as u64 >= price).unwrap_or(points.len() - 1);
+        let (low, high) = (points[upper - 1], points[upper]);
+        let span = (high.price - low.price) as i128;
+        let offset = price as i128 - low.price as i128;
+        let delta = high.rate as i128 - low.rate as i128;
+        (low.rate as i128 + delta * offset / span) as u32
+    }
+}
+
+/// Build a piecewise-linear curve from breakpoints
+pub fn piecewise_fee_curve(breakpoints: &[(u32, u32)]) -> FeeCurve {
+    let mut curve = FeeCurve {
+        curve_type: curve_type::PIECEWISE,
+        point_count: breakpoints.len().min(MAX_FEE_CURVE_POINTS) as u8,
+        ..FeeCurve::default()
+    };
+    for (slot, (price, rate)) in curve.points.iter_mut().zip(breakpoints) {
+        *slot = FeeCurvePoint { price: *price, rate: *rate };
+    }
+    curve
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const CENTER: u32 = 32_000;
+    const EXTREME: u32 = 2_000;
+
+    #[test]
+    fn test_linear_curve_rates() {
+        let curve = FeeCurve::default();
+        assert_eq!(curve.rate(CENTER, EXTREME, 500_000), 32_000);
+        assert_eq!(curve.rate(CENTER, EXTREME, 400_000), 26_000);
+        assert_eq!(curve.rate(CENTER, EXTREME, 600_000), 26_000);
+        assert_eq!(curve.rate(CENTER, EXTREME, 100_000), 8_000);
+        assert_eq!(curve.rate(CENTER, EXTREME, 10_000), 2_600);
+        assert_eq!(curve.rate(CENTER, EXTREME, 0), 2_000);
+        assert_eq!(curve.rate(CENTER, EXTREME, 1_000_000), 2_000);
+    }
+
+    #[test]
+    fn test_quadratic_curve_rates() {
+        let curve = FeeCurve { curve_type: curve_type::QUADRATIC, ..FeeCurve::default() };
+        assert_eq!(curve.rate(CENTER, EXTREME, 500_000), 32_000);
+        // 2000 + 30000 * 4 * 0.4 * 0.6
+        assert_eq!(curve.rate(CENTER, EXTREME, 400_000), 30_800);
+        assert_eq!(curve.rate(CENTER, EXTREME, 600_000), 30_800);
+        // 2000 + 30000 * 4 * 0.1 * 0.9
+        assert_eq!(curve.rate(CENTER, EXTREME, 100_000), 12_800);
+        // 2000 + 30000 * 4 * 0.01 * 0.99
+        assert_eq!(curve.rate(CENTER, EXTREME, 10_000), 3_188);
+        assert_eq!(curve.rate(CENTER, EXTREME, 0), 2_000);
+        assert_eq!(curve.rate(CENTER, EXTREME, 1_000_000), 2_000);
+    }
+
+    #[test]
+    fn test_piecewise_curve_rates() {
+        let curve = piecewise_fee_curve(&[(100_000, 5_000), (500_000, 20_000), (900_000, 10_000)]);
+        assert!(curve.validate(100_000).is_ok());
+        // Flat outside the breakpoints
+        assert_eq!(curve.rate(CENTER, EXTREME, 0), 5_000);
+        assert_eq!(curve.rate(CENTER, EXTREME, 1_000_000), 10_000);
+        // Exact breakpoints and interpolation
+        assert_eq!(curve.rate(CENTER, EXTREME, 100_000), 5_000);
+        assert_eq!(curve.rate(CENTER, EXTREME, 300_000), 12_500);
+        assert_eq!(curve.rate(CENTER, EXTREME, 500_000), 20_000);
+        assert_eq!(curve.rate(CENTER, EXTREME, 700_000), 15_000);
+    }
+
+    #[test]
+    fn test_piecewise_curve_validation() {
+        // Too few points, unsorted prices, price above 1, rate above max
+        assert!(piecewise_fee_curve(&[(0, 0)]).validate(100_000).is_err());
+        assert!(piecewise_fee_curve(&[(500_000, 0), (500_000, 0)]).validate(100_000).is_err());
+        assert!(piecewise_fee_curve(&[(0, 0), (1_000_001, 0)]).validate(100_000).is_err());
+        assert!(piecewise_fee_curve(&[(0, 0), (1_000_000, 100_001)]).validate(100_000).is_err());
+        // Unknown curve type, breakpoints on a non-piecewise curve
+        assert!(FeeCurve { curve_type: 3, ..FeeCurve::default() }.validate(100_000).is_err());
+        assert!(FeeCurve { point_count: 2, ..FeeCurve::default() }.validate(100_000).is_err());
+    }
+}
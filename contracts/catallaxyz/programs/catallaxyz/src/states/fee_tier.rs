@@ -0,0 +1,114 @@
+use anchor_lang::prelude::*;
+use crate::errors::TerminatorError;
+
+/// Maximum number of volume tiers in the fee tier table
+pub const MAX_FEE_TIERS: usize = 4;
+
+/// Maximum tier discount (100%, scaled by 10^6)
+pub const MAX_TIER_DISCOUNT_RATE: u32 = 1_000_000;
+
+/// Taker fee discount earned by a rolling 30-day volume threshold
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct FeeTier {
+    /// Minimum rolling 30-day taker notional (USDC, 6 decimals)
+    pub min_volume: u64,
+    /// Discount on the taker fee (scaled by 10^6, e.g. 100000 = 10% off)
+    pub discount_rate: u32,
+}
+
+/// Volume tier table (see `UserStats`)
+///
+/// Tier 0 is the base tier (no discount); tier `n` is reached at
+/// `tiers[n - 1].min_volume`. Thresholds and discounts increase with the tier.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct FeeTierTable {
+    /// Number of valid tiers (0 = tiers disabled)
+    pub tier_count: u8,
+    /// Tiers (first `tier_count` entries are valid)
+    pub tiers: [FeeTier; MAX_FEE_TIERS],
+}
+
+impl FeeTierTable {
+    /// tier_count(1) + tiers((8 + 4) * 4)
+    pub const SIZE: usize = 1 + 12 * MAX_FEE_TIERS;
+
+    /// Valid tiers
+    pub fn tiers(&self) -> &[FeeTier] {
+        &self.tiers[..(self.tier_count as usize).min(MAX_FEE_TIERS)]
+    }
+
+    /// Check thresholds strictly increase from above zero and discounts
+    /// never decrease and stay at most 100%
+    pub fn validate(&self) -> Result<()> {
+        require!(
+            self.tier_count as usize <= MAX_FEE_TIERS,
+            TerminatorError::InvalidFeeTiers
+        );
+        let tiers = self.tiers();
+        require!(
+            tiers.first().is_none_or(|tier| tier.min_volume > 0)
+                && tiers.windows(2).all(|pair| {
+                    pair[0].min_volume < pair[1].min_volume
+                        && pair[0].discount_rate <= pair[1].discount_rate
+                })
+                && tiers.iter().all(|tier| tier.discount_rate <= MAX_TIER_DISCOUNT_RATE),
+            TerminatorError::InvalidFeeTiers
+        );
+        Ok(())
+    }
+
+    /// Tier reached by a rolling volume (0 = base tier)
+    pub fn tier_for(&self, volume: u64) -> u8 {
+        self.tiers().iter().filter(|tier| volume >= tier.min_volume).count() as u8
+    }
+
+    /// Fee discount for a tier (scaled by 10^6)
+    pub fn discount_rate(&self, tier: u8) -> u32 {
+        match tier {
+            0 => 0,
+            tier => self.tiers().get(tier as usize - 1).map_or(0, |tier| tier.discount_rate),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn table(tiers: &[(u64, u32)]) -> FeeTierTable {
+        let mut table = FeeTierTable { tier_count: tiers.len() as u8, ..FeeTierTable::default() };
+        for (slot, (min_volume, discount_rate)) in table.tiers.iter_mut().zip(tiers) {
+            *slot = FeeTier { min_volume: *min_volume, discount_rate: *discount_rate };
+        }
+        table
+    }
+
+    #[test]
+    fn test_fee_tier_lookup() {
+        // 100k USDC -> 10% off, 1M USDC -> 25% off
+        let tiers = table(&[(100_000_000_000, 100_000), (1_000_000_000_000, 250_000)]);
+        assert!(tiers.validate().is_ok());
+
+        assert_eq!(tiers.tier_for(99_999_999_999), 0);
+        assert_eq!(tiers.tier_for(100_000_000_000), 1);
+        assert_eq!(tiers.tier_for(5_000_000_000_000), 2);
+        assert_eq!(tiers.discount_rate(0), 0);
+        assert_eq!(tiers.discount_rate(1), 100_000);
+        assert_eq!(tiers.discount_rate(2), 250_000);
+
+        // Disabled table
+        assert_eq!(FeeTierTable::default().tier_for(u64::MAX), 0);
+    }
+
+    #[test]
+    fn test_fee_tier_validate() {
+        assert!(FeeTierTable::default().validate().is_ok());
+        // Zero threshold, unsorted thresholds, shrinking discount, discount above 100%
+        assert!(table(&[(0, 100_000)]).validate().is_err());
+        assert!(table(&[(200, 100_000), (100, 200_000)]).validate().is_err());
+        assert!(table(&[(100, 200_000), (200, 100_000)]).validate().is_err());
+        assert!(table(&[(100, 1_000_001)]).validate().is_err());
+        // Too many tiers
+        assert!(FeeTierTable { tier_count: 5, ..FeeTierTable::default() }.validate().is_err());
+    }
+}
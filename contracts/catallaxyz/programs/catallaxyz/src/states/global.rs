@@ -1,19 +1,109 @@
 use anchor_lang::prelude::*;
-
-/// Maximum number of operators allowed
-pub const MAX_OPERATORS: usize = 10;
+use crate::states::{FeeCurve, FeeTierTable, RoleAssignment};
 
 /// Maximum fee rate in basis points (10% = 1000 bps)
 pub const MAX_FEE_RATE_BPS: u16 = 1000;
 
+/// Size of the unused operator list kept in the `Global` layout
+pub const LEGACY_MAX_OPERATORS: usize = 10;
+
+/// Maximum authority transfer timelock (30 days)
+pub const MAX_AUTHORITY_TRANSFER_DELAY: i64 = 30 * 24 * 60 * 60;
+
+/// Maximum timelock for fee and market parameter changes (30 days)
+pub const MAX_GOVERNANCE_DELAY: i64 = 30 * 24 * 60 * 60;
+
+/// Default timelock for fee and market parameter changes (1 day)
+pub const DEFAULT_GOVERNANCE_DELAY: i64 = 24 * 60 * 60;
+
+/// Platform fee configuration (all rates scaled by 10^6)
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, PartialEq, Eq)]
+pub struct FeeRates {
+    pub center_taker_fee_rate: u32,
+    pub extreme_taker_fee_rate: u32,
+    pub platform_fee_rate: u32,
+    pub maker_rebate_rate: u32,
+    pub creator_incentive_rate: u32,
+    pub curve: FeeCurve,
+}
+
+impl FeeRates {
+    /// center(4) + extreme(4) + platform(4) + maker(4) + creator(4) + curve(66)
+    pub const SIZE: usize = 4 * 5 + FeeCurve::SIZE;
+
+    /// Maximum taker fee rate (10%)
+    pub const MAX_TAKER_FEE_RATE: u32 = 100_000;
+
+    /// Check the configuration is usable:
+    /// - taker rates are at most 10% and center >= extreme (fee curve logic)
+    /// - the curve is well-formed (see `FeeCurve::validate`)
+    /// - platform + maker + creator shares sum to 100%
+    pub fn validate(&self) -> Result<()> {
+        require!(
+            self.center_taker_fee_rate <= Self::MAX_TAKER_FEE_RATE
+                && self.extreme_taker_fee_rate <= Self::MAX_TAKER_FEE_RATE,
+            crate::errors::TerminatorError::InvalidFeeRate
+        );
+        require!(
+            self.center_taker_fee_rate >= self.extreme_taker_fee_rate,
+            crate::errors::TerminatorError::InvalidFeeConfiguration
+        );
+        self.curve.validate(Self::MAX_TAKER_FEE_RATE)?;
+
+        const RATE_SCALE: u32 = 1_000_000;
+        let total_distribution = self.platform_fee_rate
+            .checked_add(self.maker_rebate_rate)
+            .and_then(|sum| sum.checked_add(self.creator_incentive_rate))
+            .ok_or(crate::errors::TerminatorError::ArithmeticOverflow)?;
+        require!(
+            total_distribution == RATE_SCALE,
+            crate::errors::TerminatorError::InvalidFeeConfiguration
+        );
+        Ok(())
+    }
+
+    /// Check a creator-proposed market override stays within the global
+    /// configuration: taker rates no higher than the global curve and a
+    /// platform share no lower than the global one
+    ///
+    /// Curves of different shapes are compared pointwise at every 1% price
+    /// step and at the override's own breakpoints.
+    pub fn is_within(&self, global_rates: &FeeRates) -> bool {
+        const PRICE_STEP: u64 = crate::constants::PRICE_SCALE / 100;
+        let breakpoints = self.curve.points().iter().map(|point| point.price as u64);
+        let within_at = |price: u64| self.taker_fee_rate(price) <= global_rates.taker_fee_rate(price);
+
+        (0..=100).map(|step| step * PRICE_STEP).chain(breakpoints).all(within_at)
+            && self.platform_fee_rate >= global_rates.platform_fee_rate
+    }
+
+    /// Calculate taker fee rate based on price using the configured curve
+    /// 
+    /// Linear (default): fee = center - (center - extreme) * |price - 0.5| / 0.5
+    /// Quadratic: fee = extreme + (center - extreme) * 4 * price * (1 - price)
+    /// Piecewise: interpolated between the curve's breakpoints
+    /// 
+    /// Examples (linear, with default rates):
+    /// - price 0.50: 3.2% (center)
+    /// - price 0.40/0.60: 2.6%
+    /// - price 0.30/0.70: 2.0%
+    /// - price 0.20/0.80: 1.4%
+    /// - price 0.10/0.90: 0.8%
+    /// - price 0.00/1.00: 0.2% (extreme)
+    pub fn taker_fee_rate(&self, price: u64) -> u32 {
+        self.curve.rate(self.center_taker_fee_rate, self.extreme_taker_fee_rate, price)
+    }
+}
+
 #[account]
 pub struct Global {
+    /// Super-admin: implicitly holds every role and grants/revokes them
+    /// (see `RoleAssignment`)
     pub authority: Pubkey,
     pub usdc_mint: Pubkey,
-    /// Keeper wallet for automated tasks (terminating inactive markets)
-    /// Can be set to a different wallet than authority for separation of concerns
-    /// If set to Pubkey::default(), only authority can perform keeper tasks
-    pub keeper: Pubkey,
+    /// Unused: superseded by the keeper role (see `RoleAssignment`); kept so
+    /// the account layout stays compatible with deployed accounts
+    pub legacy_keeper: Pubkey,
     pub bump: u8,
     pub platform_treasury_bump: u8, // Platform treasury bump (for trading & creation fees)
     pub total_trading_fees_collected: u64, // Total trading fees collected in USDC
@@ -54,92 +144,199 @@ pub struct Global {
     /// When true, no trading operations (fill_order, match_orders) are allowed
     pub trading_paused: bool,
     
-    /// Number of active operators
-    pub operator_count: u8,
+    /// Unused: superseded by the operator role (see `RoleAssignment`); kept so
+    /// the account layout stays compatible with deployed accounts
+    pub legacy_operator_count: u8,
+    
+    /// Unused: operators are granted role PDAs instead
+    pub legacy_operators: [Pubkey; LEGACY_MAX_OPERATORS],
+    
+    // ============================================
+    // Fields added after the initial deployment
+    // (appended only; see `migrate_global`)
+    // ============================================
+    
+    /// Account layout version (see `Global::LAYOUT_VERSION`)
+    pub layout_version: u8,
+    
+    // ============================================
+    // Fee Curve, Tiers and Referrals
+    // ============================================
+    
+    /// Fee curve shape (linear, quadratic or piecewise; see `FeeCurve`)
+    /// Default: linear between center and extreme
+    pub fee_curve: FeeCurve,
+
+    /// Taker fee discounts by rolling 30-day taker volume (see `UserStats`)
+    /// Default: no tiers
+    pub fee_tiers: FeeTierTable,
+
+    /// Referrer's share of the platform fee (scaled by 10^6, e.g., 100000 = 10%)
+    /// Paid out of the platform's cut for users with a registered referrer
+    pub referral_share_rate: u32,
+    
+    // ============================================
+    // Order Signing Domain
+    // ============================================
+    
+    /// Cluster tag bound into order hashes (see `order_types::cluster`)
+    pub cluster: u8,
+    
+    /// Migration flag: also accept signatures over the legacy v1 order hash
+    /// (no program ID / cluster binding). OrderStatus is always keyed by the
+    /// current domain hash.
+    pub accept_legacy_order_hash: bool,
+    
+    // ============================================
+    // Authority Transfer (two-step)
+    // ============================================
+    
+    /// Proposed new authority (Pubkey::default() if none pending)
+    /// Must sign accept_authority, so it can be a multisig vault PDA
+    pub pending_authority: Pubkey,
+    
+    /// Earliest time the pending authority can accept
+    pub pending_authority_eta: i64,
+    
+    /// Timelock applied to new proposals (seconds, 0 = none)
+    pub authority_transfer_delay: i64,
+    
+    // ============================================
+    // Governance (timelocked fee/parameter changes)
+    // ============================================
+    
+    /// Timelock applied to fee and market parameter proposals (seconds)
+    pub governance_delay: i64,
+    
+    /// Queued fee rates (applied by execute_fee_rates)
+    pub pending_fee_rates: Option<FeeRates>,
+    
+    /// Queued fee tiers (applied together with pending_fee_rates)
+    pub pending_fee_tiers: FeeTierTable,
+    
+    /// Earliest time the queued fee rates can be executed
+    pub pending_fee_rates_eta: i64,
+    
+    /// Queued governance delay decrease (applied by execute_governance_delay)
+    pub pending_governance_delay: Option<i64>,
+    
+    /// Earliest time the queued governance delay can be executed
+    pub pending_governance_delay_eta: i64,
     
-    /// List of operator addresses (authorized to execute trades)
-    /// Operators can call fill_order and match_orders
-    /// Max 10 operators
-    pub operators: [Pubkey; 10],
+    // ============================================
+    // Market Creation Fee
+    // ============================================
+    
+    /// Market creation fee (USDC lamports)
+    /// Default: MARKET_CREATION_FEE (10 USDC)
+    pub creation_fee: u64,
+    
+    /// Market volume at which a USDC creation fee is refunded to the creator
+    /// (USDC lamports, 0 = no refunds); snapshotted into each new market
+    pub creation_fee_refund_volume: u64,
+    
+    /// Alternative token accepted for the creation fee (Pubkey::default() if none)
+    pub alt_fee_mint: Pubkey,
+    
+    /// Token account receiving alternative-token creation fees
+    pub alt_fee_recipient: Pubkey,
+    
+    /// Alternative-token base units charged per USDC lamport of creation fee
+    /// (scaled by 10^6; 0 = alternative token disabled)
+    pub alt_fee_rate: u64,
+    
+    /// Queued authority transfer delay decrease
+    /// (applied by execute_authority_transfer_delay)
+    pub pending_authority_transfer_delay: Option<i64>,
+    
+    /// Earliest time the queued authority transfer delay can be executed
+    pub pending_authority_transfer_delay_eta: i64,
+    
+    /// Referral rewards accrued but not yet claimed; the platform treasury
+    /// keeps at least this much USDC
+    pub referral_rewards_outstanding: u64,
 }
 
 impl Global {
     // Space calculation:
-    // discriminator(8) + authority(32) + usdc_mint(32) + keeper(32)
+    // discriminator(8) + authority(32) + usdc_mint(32) + legacy_keeper(32)
     // + bump(1) + platform_treasury_bump(1)
     // + total_trading_fees_collected(8) + total_creation_fees_collected(8)
     // + center_taker_fee_rate(4) + extreme_taker_fee_rate(4)
     // + platform_fee_rate(4) + maker_rebate_rate(4) + creator_incentive_rate(4)
-    // + trading_paused(1) + operator_count(1) + operators(32 * 10)
-    pub const INIT_SPACE: usize = 8 + 32 + 32 + 32 + 1 + 1 + 8 + 8 + 4 + 4 + 4 + 4 + 4 + 1 + 1 + 32 * MAX_OPERATORS;
+    // + trading_paused(1) + legacy_operator_count(1) + legacy_operators(32 * 10)
+    // + layout_version(1)
+    // + fee_curve(66) + fee_tiers(49) + referral_share_rate(4)
+    // + cluster(1) + accept_legacy_order_hash(1)
+    // + pending_authority(32) + pending_authority_eta(8) + authority_transfer_delay(8)
+    // + governance_delay(8) + pending_fee_rates(1 + 86) + pending_fee_tiers(49)
+    // + pending_fee_rates_eta(8)
+    // + pending_governance_delay(1 + 8) + pending_governance_delay_eta(8)
+    // + creation_fee(8) + creation_fee_refund_volume(8)
+    // + alt_fee_mint(32) + alt_fee_recipient(32) + alt_fee_rate(8)
+    // + pending_authority_transfer_delay(1 + 8) + pending_authority_transfer_delay_eta(8)
+    // + referral_rewards_outstanding(8)
+    pub const INIT_SPACE: usize = Self::LEGACY_INIT_SPACE
+        + 1
+        + FeeCurve::SIZE + FeeTierTable::SIZE + 4
+        + 1 + 1
+        + 32 + 8 + 8
+        + 8 + 1 + FeeRates::SIZE + FeeTierTable::SIZE + 8
+        + 1 + 8 + 8
+        + 8 + 8
+        + 32 + 32 + 8
+        + 1 + 8 + 8
+        + 8;
+
+    /// Space of the initially deployed layout (every field up to
+    /// `legacy_operators`)
+    pub const LEGACY_INIT_SPACE: usize = 8 + 32 + 32 + 32 + 1 + 1 + 8 + 8 + 4 + 4 + 4 + 4 + 4
+        + 1 + 1 + 32 * LEGACY_MAX_OPERATORS;
 
-    /// Check if the given pubkey is authorized as keeper (authority or designated keeper)
-    pub fn is_keeper(&self, pubkey: &Pubkey) -> bool {
-        *pubkey == self.authority || (*pubkey == self.keeper && self.keeper != Pubkey::default())
+    /// Current account layout version
+    /// - 0: initial deployment (no `layout_version` field)
+    /// - 1: fields appended after `legacy_operators`
+    pub const LAYOUT_VERSION: u8 = 1;
+
+    /// Signing domain for orders executed by this deployment
+    pub fn order_domain(&self) -> crate::states::OrderDomain {
+        crate::states::OrderDomain::new(self.cluster)
     }
-    
+
     /// Check if the given pubkey is an admin (authority)
     pub fn is_admin(&self, pubkey: &Pubkey) -> bool {
         *pubkey == self.authority
     }
     
-    /// Check if the given pubkey is an operator
-    pub fn is_operator(&self, pubkey: &Pubkey) -> bool {
-        // Authority is always an operator
-        if *pubkey == self.authority {
-            return true;
-        }
-        
-        // Check operator list
-        for i in 0..self.operator_count as usize {
-            if self.operators[i] == *pubkey {
-                return true;
-            }
-        }
-        false
+    /// Check if the given pubkey holds a role
+    ///
+    /// The authority holds every role; anyone else needs a RoleAssignment
+    /// for exactly this (role, holder) pair.
+    pub fn has_role(&self, pubkey: &Pubkey, assignment: Option<&RoleAssignment>, role: u8) -> bool {
+        self.is_admin(pubkey)
+            || assignment.is_some_and(|assignment| assignment.role == role && assignment.holder == *pubkey)
     }
     
-    /// Add an operator
-    pub fn add_operator(&mut self, operator: Pubkey) -> Result<()> {
-        require!(
-            (self.operator_count as usize) < MAX_OPERATORS,
-            crate::errors::TerminatorError::MaxOperatorsReached
-        );
-        
-        // Check if already an operator
-        require!(
-            !self.is_operator(&operator),
-            crate::errors::TerminatorError::AlreadyOperator
-        );
-        
-        self.operators[self.operator_count as usize] = operator;
-        self.operator_count += 1;
-        Ok(())
+    /// Current fee configuration
+    pub fn fee_rates(&self) -> FeeRates {
+        FeeRates {
+            center_taker_fee_rate: self.center_taker_fee_rate,
+            extreme_taker_fee_rate: self.extreme_taker_fee_rate,
+            platform_fee_rate: self.platform_fee_rate,
+            maker_rebate_rate: self.maker_rebate_rate,
+            creator_incentive_rate: self.creator_incentive_rate,
+            curve: self.fee_curve,
+        }
     }
     
-    /// Remove an operator
-    pub fn remove_operator(&mut self, operator: Pubkey) -> Result<()> {
-        // Find the operator
-        let mut found_idx: Option<usize> = None;
-        for i in 0..self.operator_count as usize {
-            if self.operators[i] == operator {
-                found_idx = Some(i);
-                break;
-            }
-        }
-        
-        let idx = found_idx.ok_or(crate::errors::TerminatorError::OperatorNotFound)?;
-        
-        // Shift remaining operators
-        for i in idx..(self.operator_count as usize - 1) {
-            self.operators[i] = self.operators[i + 1];
-        }
-        
-        // Clear last slot and decrement count
-        self.operators[(self.operator_count - 1) as usize] = Pubkey::default();
-        self.operator_count -= 1;
-        
-        Ok(())
+    /// Apply a fee configuration
+    pub fn set_fee_rates(&mut self, rates: &FeeRates) {
+        self.center_taker_fee_rate = rates.center_taker_fee_rate;
+        self.extreme_taker_fee_rate = rates.extreme_taker_fee_rate;
+        self.platform_fee_rate = rates.platform_fee_rate;
+        self.maker_rebate_rate = rates.maker_rebate_rate;
+        self.creator_incentive_rate = rates.creator_incentive_rate;
+        self.fee_curve = rates.curve;
     }
     
     /// Check if trading is allowed (not paused)
@@ -157,35 +354,53 @@ impl Global {
         self.trading_paused = false;
     }
 
-    /// Calculate taker fee rate based on price using smooth curve
+    /// Fee configuration for a market: its override if set, else the global rates
+    pub fn effective_fee_rates(&self, fee_override: Option<&FeeRates>) -> FeeRates {
+        fee_override.copied().unwrap_or_else(|| self.fee_rates())
+    }
+
+    /// Check if creation fees can be paid in the alternative token
+    pub fn alt_fee_enabled(&self) -> bool {
+        self.alt_fee_rate > 0 && self.alt_fee_mint != Pubkey::default()
+    }
+
+    /// Creation fee in alternative-token base units
+    pub fn alt_creation_fee(&self) -> Result<u64> {
+        let amount = (self.creation_fee as u128)
+            .checked_mul(self.alt_fee_rate as u128)
+            .ok_or(crate::errors::TerminatorError::ArithmeticOverflow)?
+            / crate::constants::PRICE_SCALE as u128;
+        u64::try_from(amount).map_err(|_| crate::errors::TerminatorError::ArithmeticOverflow.into())
+    }
+
+    /// Reserve newly accrued referral rewards in the platform treasury
+    pub fn reserve_referral_rewards(&mut self, amount: u64) -> Result<()> {
+        self.referral_rewards_outstanding = self.referral_rewards_outstanding
+            .checked_add(amount)
+            .ok_or(crate::errors::TerminatorError::ArithmeticOverflow)?;
+        Ok(())
+    }
+
+    /// Release claimed referral rewards from the treasury reserve
     /// 
-    /// Formula: fee = center - (center - extreme) * |price - 0.5| / 0.5
+    /// Saturates because rewards accrued before the reserve was tracked are
+    /// not part of it.
+    pub fn release_referral_rewards(&mut self, amount: u64) {
+        self.referral_rewards_outstanding = self.referral_rewards_outstanding.saturating_sub(amount);
+    }
+
+    /// Platform treasury USDC that can be withdrawn without touching
+    /// unclaimed referral rewards
+    pub fn withdrawable_platform_fees(&self, treasury_amount: u64) -> u64 {
+        treasury_amount.saturating_sub(self.referral_rewards_outstanding)
+    }
+
+    /// Calculate taker fee rate for a market at `price`
     /// 
-    /// Examples (with default rates):
-    /// - price 0.50: 3.2% (center)
-    /// - price 0.40/0.60: 2.6%
-    /// - price 0.30/0.70: 2.0%
-    /// - price 0.20/0.80: 1.4%
-    /// - price 0.10/0.90: 0.8%
-    /// - price 0.01/0.99: 0.2% (extreme)
-    pub fn calculate_taker_fee_rate(&self, price: u64) -> u32 {
-        const CENTER_PRICE: u64 = crate::constants::PRICE_SCALE / 2; // 0.5 scaled
-        
-        // Distance from center (0-500000)
-        let distance_from_center = if price > CENTER_PRICE {
-            price - CENTER_PRICE
-        } else {
-            CENTER_PRICE - price
-        };
-        
-        // Rate range
-        let rate_range = self.center_taker_fee_rate.saturating_sub(self.extreme_taker_fee_rate);
-        
-        // Calculate fee reduction: (rate_range * distance) / 500000
-        let fee_reduction = (rate_range as u64 * distance_from_center) / CENTER_PRICE;
-        
-        // Final fee rate
-        self.center_taker_fee_rate.saturating_sub(fee_reduction as u32)
+    /// Uses the market's fee override when present (see `Market::fee_override`),
+    /// otherwise the global curve.
+    pub fn calculate_taker_fee_rate(&self, fee_override: Option<&FeeRates>, price: u64) -> u32 {
+        self.effective_fee_rates(fee_override).taker_fee_rate(price)
     }
 }
 
@@ -196,4 +411,117 @@ pub mod default_fees {
     pub const PLATFORM_FEE_RATE: u32 = 750_000; // 75%
     pub const MAKER_REBATE_RATE: u32 = 200_000; // 20%
     pub const CREATOR_INCENTIVE_RATE: u32 = 50_000; // 5%
+    pub const REFERRAL_SHARE_RATE: u32 = 100_000; // 10% of the platform fee
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::states::role;
+    use crate::test_utils::global;
+
+    #[test]
+    fn test_has_role() {
+        let authority = Pubkey::new_unique();
+        let keeper = Pubkey::new_unique();
+        let global = global(authority);
+        let assignment = RoleAssignment {
+            role: role::KEEPER,
+            holder: keeper,
+            granted_by: authority,
+            granted_at: 0,
+            bump: 255,
+        };
+
+        // Authority holds every role without an assignment
+        assert!(global.has_role(&authority, None, role::OPERATOR));
+        assert!(global.has_role(&keeper, Some(&assignment), role::KEEPER));
+        assert!(!global.has_role(&keeper, None, role::KEEPER));
+        // Assignment is for one role and one holder only
+        assert!(!global.has_role(&keeper, Some(&assignment), role::PAUSER));
+        assert!(!global.has_role(&Pubkey::new_unique(), Some(&assignment), role::KEEPER));
+    }
+
+    #[test]
+    fn test_fee_rates_validate() {
+        let rates = global(Pubkey::new_unique()).fee_rates();
+        assert!(rates.validate().is_ok());
+
+        // Taker rate above 10%
+        assert!(FeeRates { center_taker_fee_rate: 100_001, ..rates }.validate().is_err());
+        // Center below extreme
+        assert!(FeeRates { center_taker_fee_rate: 1_000, ..rates }.validate().is_err());
+        // Shares must sum to 100%
+        assert!(FeeRates { platform_fee_rate: 749_999, ..rates }.validate().is_err());
+    }
+    #[test]
+    fn test_market_fee_override() {
+        let global = global(Pubkey::new_unique());
+        let rates = global.fee_rates();
+        let promo = FeeRates { center_taker_fee_rate: 0, extreme_taker_fee_rate: 0, ..rates };
+
+        assert_eq!(global.calculate_taker_fee_rate(None, 500_000), 32_000);
+        assert_eq!(global.calculate_taker_fee_rate(Some(&promo), 500_000), 0);
+
+        // Creators may lower fees but not raise them or cut the platform share
+        assert!(promo.is_within(&rates));
+        assert!(!FeeRates { center_taker_fee_rate: 40_000, ..rates }.is_within(&rates));
+        assert!(!FeeRates { platform_fee_rate: 700_000, creator_incentive_rate: 100_000, ..rates }.is_within(&rates));
+
+        // Quadratic lies above the linear curve between 0 and 1
+        let quadratic = FeeCurve { curve_type: crate::states::curve_type::QUADRATIC, ..FeeCurve::default() };
+        assert!(!FeeRates { curve: quadratic, ..rates }.is_within(&rates));
+        // Piecewise curve below the linear one everywhere
+        let low = crate::states::piecewise_fee_curve(&[(0, 1_000), (500_000, 20_000), (1_000_000, 1_000)]);
+        assert!(FeeRates { curve: low, ..rates }.is_within(&rates));
+        // ...but not one that peaks above it
+        let spike = crate::states::piecewise_fee_curve(&[(0, 0), (500_000, 32_001), (1_000_000, 0)]);
+        assert!(!FeeRates { curve: spike, ..rates }.is_within(&rates));
+    }
+
+    #[test]
+    fn test_curve_taker_fee_rates() {
+        let mut global = global(Pubkey::new_unique());
+        assert_eq!(global.calculate_taker_fee_rate(None, 300_000), 20_000);
+
+        global.fee_curve = FeeCurve { curve_type: crate::states::curve_type::QUADRATIC, ..FeeCurve::default() };
+        assert!(global.fee_rates().validate().is_ok());
+        // 2000 + 30000 * 4 * 0.3 * 0.7
+        assert_eq!(global.calculate_taker_fee_rate(None, 300_000), 27_200);
+        assert_eq!(global.calculate_taker_fee_rate(None, 950_000), 7_700);
+    }
+
+    #[test]
+    fn test_alt_creation_fee() {
+        let mut global = global(Pubkey::new_unique());
+        assert!(!global.alt_fee_enabled());
+
+        // 10 USDC at 2.5 alt-token base units per USDC lamport
+        global.alt_fee_mint = Pubkey::new_unique();
+        global.alt_fee_rate = 2_500_000;
+        assert!(global.alt_fee_enabled());
+        assert_eq!(global.alt_creation_fee().unwrap(), 25_000_000);
+
+        global.alt_fee_rate = u64::MAX;
+        global.creation_fee = u64::MAX;
+        assert!(global.alt_creation_fee().is_err());
+    }
+
+    #[test]
+    fn test_referral_rewards_reserved() {
+        let mut global = global(Pubkey::new_unique());
+        assert_eq!(global.withdrawable_platform_fees(1_000), 1_000);
+
+        global.reserve_referral_rewards(300).unwrap();
+        global.reserve_referral_rewards(100).unwrap();
+        assert_eq!(global.withdrawable_platform_fees(1_000), 600);
+        assert_eq!(global.withdrawable_platform_fees(200), 0);
+
+        // Claims of rewards accrued before tracking began cannot underflow
+        global.release_referral_rewards(300);
+        global.release_referral_rewards(500);
+        assert_eq!(global.referral_rewards_outstanding, 0);
+        assert!(global.reserve_referral_rewards(u64::MAX).is_ok());
+        assert!(global.reserve_referral_rewards(1).is_err());
+    }
 }
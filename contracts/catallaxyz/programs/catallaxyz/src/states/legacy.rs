@@ -0,0 +1,296 @@
+//! Account layouts of the initial deployment (layout version 0)
+//!
+//! `migrate_global` and `migrate_market` read accounts created in these
+//! layouts and rewrite them in the current one. The field order mirrors the
+//! deployed program and must never change.
+
+use anchor_lang::prelude::*;
+use crate::constants::MARKET_CREATION_FEE;
+use crate::states::{
+    default_fees, FeeCurve, FeeTierTable, Global, Market, DEFAULT_GOVERNANCE_DELAY,
+    LEGACY_MAX_OPERATORS,
+};
+
+/// `Global` as created by the initial deployment
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug)]
+pub struct GlobalV0 {
+    pub authority: Pubkey,
+    pub usdc_mint: Pubkey,
+    pub keeper: Pubkey,
+    pub bump: u8,
+    pub platform_treasury_bump: u8,
+    pub total_trading_fees_collected: u64,
+    pub total_creation_fees_collected: u64,
+    pub center_taker_fee_rate: u32,
+    pub extreme_taker_fee_rate: u32,
+    pub platform_fee_rate: u32,
+    pub maker_rebate_rate: u32,
+    pub creator_incentive_rate: u32,
+    pub trading_paused: bool,
+    pub operator_count: u8,
+    pub operators: [Pubkey; LEGACY_MAX_OPERATORS],
+}
+
+impl GlobalV0 {
+    /// Current layout with the appended fields at their `initialize` defaults
+    ///
+    /// The keeper and operators are kept in the legacy fields for reference;
+    /// they hold no role until granted one with grant_role.
+    pub fn into_current(self, cluster: u8, accept_legacy_order_hash: bool) -> Global {
+        Global {
+            authority: self.authority,
+            usdc_mint: self.usdc_mint,
+            legacy_keeper: self.keeper,
+            bump: self.bump,
+            platform_treasury_bump: self.platform_treasury_bump,
+            total_trading_fees_collected: self.total_trading_fees_collected,
+            total_creation_fees_collected: self.total_creation_fees_collected,
+            center_taker_fee_rate: self.center_taker_fee_rate,
+            extreme_taker_fee_rate: self.extreme_taker_fee_rate,
+            platform_fee_rate: self.platform_fee_rate,
+            maker_rebate_rate: self.maker_rebate_rate,
+            creator_incentive_rate: self.creator_incentive_rate,
+            trading_paused: self.trading_paused,
+            legacy_operator_count: self.operator_count,
+            legacy_operators: self.operators,
+            layout_version: Global::LAYOUT_VERSION,
+            fee_curve: FeeCurve::default(),
+            fee_tiers: FeeTierTable::default(),
+            referral_share_rate: default_fees::REFERRAL_SHARE_RATE,
+            cluster,
+            accept_legacy_order_hash,
+            pending_authority: Pubkey::default(),
+            pending_authority_eta: 0,
+            authority_transfer_delay: 0,
+            governance_delay: DEFAULT_GOVERNANCE_DELAY,
+            pending_fee_rates: None,
+            pending_fee_tiers: FeeTierTable::default(),
+            pending_fee_rates_eta: 0,
+            pending_governance_delay: None,
+            pending_governance_delay_eta: 0,
+            creation_fee: MARKET_CREATION_FEE,
+            creation_fee_refund_volume: 0,
+            alt_fee_mint: Pubkey::default(),
+            alt_fee_recipient: Pubkey::default(),
+            alt_fee_rate: 0,
+            pending_authority_transfer_delay: None,
+            pending_authority_transfer_delay_eta: 0,
+            referral_rewards_outstanding: 0,
+        }
+    }
+}
+
+/// `Market` as created by the initial deployment
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug)]
+pub struct MarketV0 {
+    pub creator: Pubkey,
+    pub global: Pubkey,
+    pub market_id: [u8; 32],
+    pub question: String,
+    pub description: String,
+    pub yes_description: String,
+    pub no_description: String,
+    pub created_at: i64,
+    pub last_activity_ts: i64,
+    pub status: u8,
+    pub switchboard_queue: Pubkey,
+    pub randomness_account: Pubkey,
+    pub total_position_collateral: u64,
+    pub total_yes_supply: u64,
+    pub total_no_supply: u64,
+    pub total_redeemable_usdc: u64,
+    pub total_redeemed_usdc: u64,
+    pub last_trade_outcome: Option<u8>,
+    pub reference_agent: Option<Pubkey>,
+    pub total_trades: u64,
+    pub last_trade_slot: Option<u64>,
+    pub last_trade_yes_price: Option<u64>,
+    pub last_trade_no_price: Option<u64>,
+    pub random_termination_enabled: bool,
+    pub termination_probability: u32,
+    pub is_randomly_terminated: bool,
+    pub final_yes_price: Option<u64>,
+    pub final_no_price: Option<u64>,
+    pub can_redeem: bool,
+    pub termination_trade_slot: Option<u64>,
+    pub trade_nonce: u64,
+    pub creator_incentive_accrued: u64,
+    pub is_paused: bool,
+    pub paused_at: Option<i64>,
+    pub bump: u8,
+}
+
+impl MarketV0 {
+    /// Current layout with the appended fields at their `create_market` defaults
+    ///
+    /// A market that already stopped trading starts its close grace period at
+    /// `now`. The creation fee was paid without a refund offer, so it is not
+    /// refundable.
+    pub fn into_current(self, now: i64) -> Market {
+        let stopped = self.status == crate::states::market_status::SETTLED
+            || self.status == crate::states::market_status::TERMINATED;
+        Market {
+            creator: self.creator,
+            global: self.global,
+            market_id: self.market_id,
+            question: self.question,
+            description: self.description,
+            yes_description: self.yes_description,
+            no_description: self.no_description,
+            created_at: self.created_at,
+            last_activity_ts: self.last_activity_ts,
+            status: self.status,
+            switchboard_queue: self.switchboard_queue,
+            randomness_account: self.randomness_account,
+            total_position_collateral: self.total_position_collateral,
+            total_yes_supply: self.total_yes_supply,
+            total_no_supply: self.total_no_supply,
+            total_redeemable_usdc: self.total_redeemable_usdc,
+            total_redeemed_usdc: self.total_redeemed_usdc,
+            last_trade_outcome: self.last_trade_outcome,
+            reference_agent: self.reference_agent,
+            total_trades: self.total_trades,
+            last_trade_slot: self.last_trade_slot,
+            last_trade_yes_price: self.last_trade_yes_price,
+            last_trade_no_price: self.last_trade_no_price,
+            random_termination_enabled: self.random_termination_enabled,
+            termination_probability: self.termination_probability,
+            is_randomly_terminated: self.is_randomly_terminated,
+            final_yes_price: self.final_yes_price,
+            final_no_price: self.final_no_price,
+            can_redeem: self.can_redeem,
+            termination_trade_slot: self.termination_trade_slot,
+            trade_nonce: self.trade_nonce,
+            creator_incentive_accrued: self.creator_incentive_accrued,
+            is_paused: self.is_paused,
+            paused_at: self.paused_at,
+            bump: self.bump,
+            layout_version: Market::LAYOUT_VERSION,
+            terminated_at: if stopped { Some(now) } else { None },
+            pending_termination_probability: None,
+            pending_params_eta: 0,
+            fee_override: None,
+            pending_fee_override: None,
+            pending_fee_override_eta: 0,
+            fee_override_proposer: Pubkey::default(),
+            pending_fee_override_proposer: Pubkey::default(),
+            creation_fee_paid: 0,
+            creation_fee_refund_volume: 0,
+            creation_fee_refunded: false,
+            total_volume: 0,
+            refund_eligible_volume: 0,
+            is_delisted: false,
+            delist_reason: 0,
+            delisted_at: None,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::states::market_status;
+    use crate::test_utils::serialized;
+
+    fn legacy_bytes<T: AnchorSerialize>(discriminator: &[u8], value: &T) -> Vec<u8> {
+        let mut data = discriminator.to_vec();
+        value.serialize(&mut data).unwrap();
+        data
+    }
+
+    fn market_v0() -> MarketV0 {
+        MarketV0 {
+            creator: Pubkey::new_unique(),
+            global: Pubkey::new_unique(),
+            market_id: [7; 32],
+            question: "Will it rain?".to_string(),
+            description: "Resolves YES if it rains".to_string(),
+            yes_description: "Rain".to_string(),
+            no_description: "No rain".to_string(),
+            created_at: 1,
+            last_activity_ts: 2,
+            status: market_status::TERMINATED,
+            switchboard_queue: Pubkey::new_unique(),
+            randomness_account: Pubkey::new_unique(),
+            total_position_collateral: 100,
+            total_yes_supply: 100,
+            total_no_supply: 100,
+            total_redeemable_usdc: 100,
+            total_redeemed_usdc: 0,
+            last_trade_outcome: Some(1),
+            reference_agent: None,
+            total_trades: 3,
+            last_trade_slot: Some(9),
+            last_trade_yes_price: Some(600_000),
+            last_trade_no_price: Some(400_000),
+            random_termination_enabled: true,
+            termination_probability: 1_000,
+            is_randomly_terminated: true,
+            final_yes_price: Some(600_000),
+            final_no_price: Some(400_000),
+            can_redeem: true,
+            termination_trade_slot: Some(9),
+            trade_nonce: 4,
+            creator_incentive_accrued: 5,
+            is_paused: false,
+            paused_at: None,
+            bump: 254,
+        }
+    }
+
+    #[test]
+    fn test_layouts_extend_initial_deployment() {
+        // Sizes of the initially deployed layouts
+        assert_eq!(Global::LEGACY_INIT_SPACE, 464);
+        assert_eq!(Market::LEGACY_INIT_SPACE, 1472);
+
+        // Current layouts only append fields
+        let legacy = GlobalV0 {
+            authority: Pubkey::new_unique(),
+            usdc_mint: Pubkey::new_unique(),
+            keeper: Pubkey::new_unique(),
+            bump: 255,
+            platform_treasury_bump: 254,
+            total_trading_fees_collected: 1,
+            total_creation_fees_collected: 2,
+            center_taker_fee_rate: default_fees::CENTER_TAKER_FEE_RATE,
+            extreme_taker_fee_rate: default_fees::EXTREME_TAKER_FEE_RATE,
+            platform_fee_rate: default_fees::PLATFORM_FEE_RATE,
+            maker_rebate_rate: default_fees::MAKER_REBATE_RATE,
+            creator_incentive_rate: default_fees::CREATOR_INCENTIVE_RATE,
+            trading_paused: true,
+            operator_count: 1,
+            operators: [Pubkey::new_unique(); LEGACY_MAX_OPERATORS],
+        };
+        let legacy_global = legacy_bytes(Global::DISCRIMINATOR, &legacy);
+        assert_eq!(legacy_global.len(), Global::LEGACY_INIT_SPACE);
+        let global = legacy.into_current(crate::states::cluster::MAINNET_BETA, false);
+        assert!(serialized(&global).starts_with(&legacy_global));
+        assert!(serialized(&global).len() <= Global::INIT_SPACE);
+
+        let legacy = market_v0();
+        let legacy_market = legacy_bytes(Market::DISCRIMINATOR, &legacy);
+        let market = legacy.into_current(10);
+        assert!(serialized(&market).starts_with(&legacy_market));
+        assert_eq!(market.terminated_at, Some(10));
+        assert!(!market.is_creation_fee_refundable());
+    }
+
+    #[test]
+    fn test_legacy_market_ignores_stale_tail() {
+        // A paused then resumed market leaves the old paused_at bytes behind
+        let resumed = market_v0();
+        let mut paused = resumed.clone();
+        paused.is_paused = true;
+        paused.paused_at = Some(i64::MAX);
+        let mut data = vec![0u8; 8 + Market::LEGACY_INIT_SPACE];
+        let stale = legacy_bytes(Market::DISCRIMINATOR, &paused);
+        data[..stale.len()].copy_from_slice(&stale);
+        let resumed = legacy_bytes(Market::DISCRIMINATOR, &resumed);
+        data[..resumed.len()].copy_from_slice(&resumed);
+
+        let decoded = MarketV0::deserialize(&mut &data[8..]).unwrap();
+        assert_eq!(decoded.paused_at, None);
+        assert_eq!(decoded.bump, 254);
+    }
+}
@@ -1,4 +1,5 @@
 use anchor_lang::prelude::*;
+use crate::states::FeeRates;
 
 #[account]
 pub struct Market {
@@ -83,7 +84,8 @@ pub struct Market {
     
     // ============================================
     // Creator Incentive Tracking
-    // Fee rates are read from Global account (see Global.calculate_taker_fee_rate())
+    // Fee rates are read from Global account unless `fee_override` is set
+    // (see Global.calculate_taker_fee_rate())
     // ============================================
     
     /// Accrued creator incentive amount (USDC lamports)
@@ -99,6 +101,65 @@ pub struct Market {
     pub paused_at: Option<i64>,
     
     pub bump: u8,
+    
+    // ============================================
+    // Fields added after the initial deployment
+    // (appended only; see `migrate_market`)
+    // ============================================
+    /// Account layout version (see `Market::LAYOUT_VERSION`)
+    pub layout_version: u8,
+    /// When the market stopped trading (settled or terminated); starts the
+    /// grace period before close_market may sweep the vault
+    pub terminated_at: Option<i64>,
+    
+    // ============================================
+    // Governance (timelocked parameter changes)
+    // ============================================
+    /// Queued termination probability (applied by execute_market_params)
+    pub pending_termination_probability: Option<u32>,
+    /// Earliest time the queued parameters can be executed
+    pub pending_params_eta: i64,
+    
+    // ============================================
+    // Fee Override
+    // ============================================
+    /// Market-specific fee curve and split (None = use Global rates)
+    pub fee_override: Option<FeeRates>,
+    /// Queued fee override (applied by execute_market_fee_override;
+    /// None clears the override)
+    pub pending_fee_override: Option<FeeRates>,
+    /// Earliest time the queued override can be executed (0 = none queued)
+    pub pending_fee_override_eta: i64,
+    /// Who proposed the current override (Pubkey::default() if never set)
+    pub fee_override_proposer: Pubkey,
+    /// Who proposed the queued override
+    pub pending_fee_override_proposer: Pubkey,
+    
+    // ============================================
+    // Creation Fee Refund
+    // ============================================
+    /// USDC creation fee paid by the creator (0 if paid in the alternative token)
+    pub creation_fee_paid: u64,
+    /// Refund-eligible volume at which the creation fee is refundable (0 = no refund)
+    pub creation_fee_refund_volume: u64,
+    /// Whether the creation fee has been refunded
+    pub creation_fee_refunded: bool,
+    /// Cumulative USDC notional traded through the fill paths
+    pub total_volume: u64,
+    /// Part of `total_volume` that counts toward the creation fee refund
+    /// (see `is_refund_eligible_fill`)
+    pub refund_eligible_volume: u64,
+    
+    // ============================================
+    // Moderation
+    // ============================================
+    /// Whether a moderator delisted the market (blocks deposits and splits;
+    /// indexers hide delisted markets)
+    pub is_delisted: bool,
+    /// Delist reason code (see `delist_reason`)
+    pub delist_reason: u8,
+    /// Timestamp when the market was delisted
+    pub delisted_at: Option<i64>,
 }
 
 /// Market status constants
@@ -108,6 +169,24 @@ pub mod market_status {
     pub const TERMINATED: u8 = 4;
 }
 
+/// Delist reason codes
+pub mod delist_reason {
+    /// Spam or nonsensical question
+    pub const SPAM: u8 = 1;
+    /// Illegal or prohibited content
+    pub const PROHIBITED_CONTENT: u8 = 2;
+    /// Question cannot be resolved unambiguously
+    pub const AMBIGUOUS: u8 = 3;
+    /// Duplicate of an existing market
+    pub const DUPLICATE: u8 = 4;
+    /// Any other reason (details off-chain)
+    pub const OTHER: u8 = 5;
+
+    pub fn is_valid(reason: u8) -> bool {
+        (SPAM..=OTHER).contains(&reason)
+    }
+}
+
 impl Market {
     // Space calculation - Binary market only (optimized, no tokenized positions)
     // discriminator(8) + creator(32) + global(32) + market_id(32)
@@ -123,7 +202,25 @@ impl Market {
     // + final_yes_price(1+8) + final_no_price(1+8) + can_redeem(1) + termination_trade_slot(1+8)
     // + trade_nonce(8) + creator_incentive_accrued(8)
     // + is_paused(1) + paused_at(1+8) + bump(1)
-    pub const INIT_SPACE: usize = 8 + 32 + 32 + 32
+    // + layout_version(1) + terminated_at(1+8)
+    // + pending_termination_probability(1+4) + pending_params_eta(8)
+    // + fee_override(1+86) + pending_fee_override(1+86) + pending_fee_override_eta(8)
+    // + fee_override_proposer(32) + pending_fee_override_proposer(32)
+    // + creation_fee_paid(8) + creation_fee_refund_volume(8) + creation_fee_refunded(1)
+    // + total_volume(8) + refund_eligible_volume(8)
+    // + is_delisted(1) + delist_reason(1) + delisted_at(1+8)
+    pub const INIT_SPACE: usize = Self::LEGACY_INIT_SPACE
+        + 1 + 1 + 8  // layout_version, terminated_at
+        + 1 + 4 + 8  // pending_termination_probability, pending_params_eta
+        + 1 + FeeRates::SIZE + 1 + FeeRates::SIZE + 8  // fee override
+        + 32 + 32  // fee override proposers
+        + 8 + 8 + 1 + 8 + 8  // creation fee refund, total_volume, refund_eligible_volume
+        + 1 + 1 + 1 + 8;  // is_delisted, delist_reason, delisted_at
+    // ≈ 1166 bytes (removed 64 bytes from outcome_token_mints)
+    // Rent cost: ~0.0065 SOL
+
+    /// Space of the initially deployed layout (every field up to `bump`)
+    pub const LEGACY_INIT_SPACE: usize = 8 + 32 + 32 + 32
         + 4 + crate::constants::MAX_QUESTION_LEN
         + 4 + crate::constants::MAX_DESCRIPTION_LEN
         + 4 + crate::constants::MAX_OUTCOME_DESCRIPTION_LEN
@@ -135,8 +232,11 @@ impl Market {
         + 1 + 4 + 1 + 1 + 8 + 1 + 8 + 1 + 1 + 8  // termination fields
         + 8 + 8  // trade_nonce, creator_incentive_accrued
         + 1 + 1 + 8 + 1;  // is_paused, paused_at, bump
-    // ≈ 926 bytes (removed 64 bytes from outcome_token_mints)
-    // Rent cost: ~0.0065 SOL
+
+    /// Current account layout version
+    /// - 0: initial deployment (no `layout_version` field)
+    /// - 1: fields appended after `bump`
+    pub const LAYOUT_VERSION: u8 = 1;
 
     pub fn is_active(&self) -> bool {
         self.status == market_status::ACTIVE
@@ -156,10 +256,17 @@ impl Market {
     pub fn can_trade(&self) -> bool {
         self.is_active() && !self.is_paused && !self.is_randomly_terminated
     }
+
+    /// Check if new collateral can enter the market (deposits and splits)
+    /// Delisted markets still trade so users can exit their positions.
+    pub fn can_deposit(&self) -> bool {
+        self.can_trade() && !self.is_delisted
+    }
     
     /// Mark market as settled
-    pub fn set_settled(&mut self) {
+    pub fn set_settled(&mut self, now_ts: i64) {
         self.status = market_status::SETTLED;
+        self.terminated_at = Some(now_ts);
     }
     
     /// Mark market as terminated (inactivity)
@@ -183,6 +290,52 @@ impl Market {
         self.last_trade_slot = Some(now_slot);
     }
 
+    /// Check if the creator can still edit the market metadata
+    /// Metadata is frozen by the first trade or the first position (split).
+    pub fn is_metadata_editable(&self) -> bool {
+        self.is_active()
+            && self.total_trades == 0
+            && self.total_yes_supply == 0
+            && self.total_no_supply == 0
+            && self.total_position_collateral == 0
+    }
+
+    /// Mark market as delisted (moderator only)
+    pub fn delist(&mut self, reason: u8, now_ts: i64) {
+        self.is_delisted = true;
+        self.delist_reason = reason;
+        self.delisted_at = Some(now_ts);
+    }
+
+    /// Add traded USDC notional to the market volume; `refund_notional` is
+    /// the part of it from refund-eligible fills
+    pub fn record_volume(&mut self, notional: u64, refund_notional: u64) -> Result<()> {
+        self.total_volume = self.total_volume
+            .checked_add(notional)
+            .ok_or(crate::errors::TerminatorError::ArithmeticOverflow)?;
+        self.refund_eligible_volume = self.refund_eligible_volume
+            .checked_add(refund_notional)
+            .ok_or(crate::errors::TerminatorError::ArithmeticOverflow)?;
+        Ok(())
+    }
+
+    /// Check if a fill between `maker` and `taker` counts toward the creation
+    /// fee refund
+    /// 
+    /// Fills involving the creator, or between a user and themselves, are
+    /// wash trades the creator could use to farm the refund.
+    pub fn is_refund_eligible_fill(&self, maker: &Pubkey, taker: &Pubkey) -> bool {
+        maker != taker && *maker != self.creator && *taker != self.creator
+    }
+
+    /// Check if the creator can claim the creation fee refund
+    pub fn is_creation_fee_refundable(&self) -> bool {
+        self.creation_fee_paid > 0
+            && self.creation_fee_refund_volume > 0
+            && !self.creation_fee_refunded
+            && self.refund_eligible_volume >= self.creation_fee_refund_volume
+    }
+
     /// Update market activity timestamps and last slot.
     pub fn record_activity(&mut self, now_ts: i64, now_slot: u64) {
         self.last_activity_ts = now_ts;
@@ -208,6 +361,25 @@ impl Market {
         Ok(())
     }
 
+    /// Record last observed price for a token ID (1 = YES, 2 = NO).
+    /// Other token IDs are ignored.
+    pub fn record_token_last_price(&mut self, token_id: u8, price: u64) -> Result<()> {
+        match token_id {
+            crate::states::token_id::YES => self.record_binary_last_price(0, price),
+            crate::states::token_id::NO => self.record_binary_last_price(1, price),
+            _ => Ok(()),
+        }
+    }
+
+    /// Last observed price for a token ID (1 = YES, 2 = NO), if any.
+    pub fn last_price_for_token(&self, token_id: u8) -> Option<u64> {
+        match token_id {
+            crate::states::token_id::YES => self.last_trade_yes_price,
+            crate::states::token_id::NO => self.last_trade_no_price,
+            _ => None,
+        }
+    }
+
     /// Terminate market if inactivity timeout has elapsed.
     ///
     /// Returns `Ok(true)` if termination was executed, `Ok(false)` otherwise.
@@ -230,13 +402,19 @@ impl Market {
         self.last_trade_yes_price = Some(yes_price);
         self.last_trade_no_price = Some(no_price);
 
-        self.terminate_market(yes_price, no_price, now_slot)?;
+        self.terminate_market(yes_price, no_price, now_slot, now_ts)?;
         Ok(true)
     }
     
     /// Set market termination state
     /// Returns error if market is not active
-    pub fn terminate_market(&mut self, yes_price: u64, no_price: u64, trade_slot: u64) -> Result<()> {
+    pub fn terminate_market(
+        &mut self,
+        yes_price: u64,
+        no_price: u64,
+        trade_slot: u64,
+        now_ts: i64,
+    ) -> Result<()> {
         use crate::errors::TerminatorError;
         
         // Verify market is active before terminating
@@ -250,10 +428,64 @@ impl Market {
         self.final_no_price = Some(no_price);
         self.can_redeem = true;
         self.termination_trade_slot = Some(trade_slot);
+        self.terminated_at = Some(now_ts);
         self.status = market_status::TERMINATED;
         Ok(())
     }
     
+    // ============================================
+    // Redemption
+    // ============================================
+
+    /// USDC value of outcome positions at the final prices
+    pub fn redemption_value(&self, yes_amount: u64, no_amount: u64) -> Result<u64> {
+        use crate::errors::TerminatorError;
+
+        let mut value = 0u128;
+        for (amount, final_price) in [(yes_amount, self.final_yes_price), (no_amount, self.final_no_price)] {
+            if amount == 0 {
+                continue;
+            }
+            let price = final_price.ok_or(TerminatorError::MarketNotTerminated)?;
+            value = (amount as u128)
+                .checked_mul(price as u128)
+                .and_then(|x| x.checked_div(crate::constants::PRICE_SCALE as u128))
+                .and_then(|x| value.checked_add(x))
+                .ok_or(TerminatorError::ArithmeticOverflow)?;
+        }
+        u64::try_from(value).map_err(|_| error!(TerminatorError::ArithmeticOverflow))
+    }
+
+    /// Redeem outcome positions at the final prices
+    /// Burns the supply, enforces the redeemable limit and releases collateral.
+    /// Returns the USDC amount the caller must transfer out of the vault.
+    pub fn redeem_positions(&mut self, yes_amount: u64, no_amount: u64) -> Result<u64> {
+        use crate::errors::TerminatorError;
+        require!(self.can_redeem, TerminatorError::RedemptionNotAllowed);
+
+        let usdc_amount = self.redemption_value(yes_amount, no_amount)?;
+
+        self.total_yes_supply = self.total_yes_supply
+            .checked_sub(yes_amount)
+            .ok_or(TerminatorError::ArithmeticOverflow)?;
+        self.total_no_supply = self.total_no_supply
+            .checked_sub(no_amount)
+            .ok_or(TerminatorError::ArithmeticOverflow)?;
+
+        let remaining = self.total_redeemable_usdc
+            .checked_sub(self.total_redeemed_usdc)
+            .ok_or(TerminatorError::ArithmeticOverflow)?;
+        require!(usdc_amount <= remaining, TerminatorError::InsufficientVaultBalance);
+        self.total_redeemed_usdc = self.total_redeemed_usdc
+            .checked_add(usdc_amount)
+            .ok_or(TerminatorError::ArithmeticOverflow)?;
+        self.total_position_collateral = self.total_position_collateral
+            .checked_sub(usdc_amount)
+            .ok_or(TerminatorError::ArithmeticOverflow)?;
+
+        Ok(usdc_amount)
+    }
+
     // ============================================
     // Per-trade Unique Randomness
     // ============================================
@@ -333,3 +565,30 @@ impl Market {
         Ok(())
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_creation_fee_refund_ignores_wash_volume() {
+        let mut market = Market::try_deserialize_unchecked(&mut &vec![0u8; Market::INIT_SPACE][..]).unwrap();
+        market.creator = Pubkey::new_unique();
+        market.creation_fee_paid = 10_000_000;
+        market.creation_fee_refund_volume = 1_000_000;
+
+        let creator = market.creator;
+        let (alice, bob) = (Pubkey::new_unique(), Pubkey::new_unique());
+        assert!(!market.is_refund_eligible_fill(&creator, &alice));
+        assert!(!market.is_refund_eligible_fill(&alice, &creator));
+        assert!(!market.is_refund_eligible_fill(&alice, &alice));
+        assert!(market.is_refund_eligible_fill(&alice, &bob));
+
+        // Volume alone does not make the fee refundable
+        market.record_volume(5_000_000, 0).unwrap();
+        assert!(!market.is_creation_fee_refundable());
+        market.record_volume(1_000_000, 1_000_000).unwrap();
+        assert_eq!(market.total_volume, 6_000_000);
+        assert!(market.is_creation_fee_refundable());
+    }
+}
@@ -0,0 +1,98 @@
+use anchor_lang::prelude::*;
+use crate::constants::{MAX_CLARIFICATIONS, MAX_CLARIFICATION_LEN};
+use crate::errors::TerminatorError;
+
+/// One clarification appended by the market creator
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug, Default, PartialEq, Eq)]
+pub struct Clarification {
+    /// Clarification text (UTF-8, at most MAX_CLARIFICATION_LEN bytes)
+    pub text: String,
+
+    /// Unix timestamp when the clarification was appended
+    pub added_at: i64,
+}
+
+impl Clarification {
+    /// Serialized size: text(4 + MAX_CLARIFICATION_LEN) + added_at(8)
+    pub const SIZE: usize = 4 + MAX_CLARIFICATION_LEN + 8;
+}
+
+/// Append-only clarification log for a market
+///
+/// Once a market has traded (or positions exist) its metadata is frozen;
+/// the creator can only append clarifications here, up to MAX_CLARIFICATIONS.
+/// Created with the first clarification.
+#[account]
+pub struct MarketClarifications {
+    /// Market this log belongs to
+    pub market: Pubkey,
+
+    /// Clarifications in the order they were appended
+    pub clarifications: Vec<Clarification>,
+
+    /// PDA bump seed
+    pub bump: u8,
+}
+
+impl MarketClarifications {
+    /// Seed prefix for MarketClarifications PDA
+    pub const SEED_PREFIX: &'static [u8] = b"market_clarifications";
+
+    /// Space calculation for account initialization
+    /// discriminator(8) + market(32)
+    /// + clarifications(4 + MAX_CLARIFICATIONS * Clarification::SIZE) + bump(1)
+    pub const INIT_SPACE: usize = 8 + 32 + 4 + MAX_CLARIFICATIONS * Clarification::SIZE + 1;
+
+    /// Append a clarification, returning its index in the log
+    pub fn append(&mut self, text: String, now: i64) -> Result<u32> {
+        require!(
+            !text.is_empty() && text.len() <= MAX_CLARIFICATION_LEN,
+            TerminatorError::InvalidInput
+        );
+        require!(
+            self.clarifications.len() < MAX_CLARIFICATIONS,
+            TerminatorError::ClarificationLogFull
+        );
+        self.clarifications.push(Clarification { text, added_at: now });
+        Ok((self.clarifications.len() - 1) as u32)
+    }
+}
+
+/// Helper to derive MarketClarifications PDA
+pub fn find_market_clarifications_pda(market: &Pubkey, program_id: &Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[MarketClarifications::SEED_PREFIX, market.as_ref()], program_id)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_append_bounded() {
+        let mut log = MarketClarifications {
+            market: Pubkey::new_unique(),
+            clarifications: Vec::new(),
+            bump: 255,
+        };
+
+        assert!(log.append(String::new(), 1).is_err());
+        assert!(log.append("x".repeat(MAX_CLARIFICATION_LEN + 1), 1).is_err());
+
+        for i in 0..MAX_CLARIFICATIONS {
+            assert_eq!(log.append(format!("clarification {}", i), i as i64).unwrap(), i as u32);
+        }
+        assert!(log.append("one too many".to_string(), 100).is_err());
+        assert_eq!(log.clarifications.len(), MAX_CLARIFICATIONS);
+
+        // A full log still fits the allocated space
+        let max = MarketClarifications {
+            market: log.market,
+            clarifications: vec![
+                Clarification { text: "x".repeat(MAX_CLARIFICATION_LEN), added_at: 0 };
+                MAX_CLARIFICATIONS
+            ],
+            bump: 255,
+        };
+        assert_eq!(8 + max.try_to_vec().unwrap().len(), MarketClarifications::INIT_SPACE);
+    }
+}
@@ -0,0 +1,238 @@
+use anchor_lang::prelude::*;
+use crate::errors::TerminatorError;
+use crate::states::Order;
+
+/// Maximum number of cancelled salt ranges per (user, market)
+pub const MAX_CANCELLED_SALT_RANGES: usize = 8;
+
+/// Maximum number of individually cancelled order hashes per (user, market)
+pub const MAX_CANCELLED_ORDER_HASHES: usize = 16;
+
+/// Inclusive salt range
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct SaltRange {
+    pub start: u64,
+    pub end: u64,
+}
+
+impl SaltRange {
+    /// start(8) + end(8)
+    pub const SIZE: usize = 8 + 8;
+
+    /// Check if a salt is inside the range
+    pub fn contains(&self, salt: u64) -> bool {
+        self.start <= salt && salt <= self.end
+    }
+}
+
+/// Per-market order cancellation state for a user
+///
+/// Complements the global `UserNonce`, letting market makers cancel one
+/// market's quotes without touching the others:
+/// - Orders with nonce < current_nonce are cancelled in this market only
+/// - Orders whose salt falls in a cancelled range are cancelled
+/// - Orders whose hash is listed are cancelled
+///
+/// The lists are bounded and kept when the nonce is raised, since a listed
+/// order may carry a nonce above the new one. Once a list is full, further
+/// orders are cancelled with the nonce or the `CancelBitmap`.
+///
+/// The PDA is passed to every fill path for each order's maker; it may be
+/// uninitialized, in which case only the global `UserNonce` applies.
+#[account]
+pub struct MarketNonce {
+    /// User's public key
+    pub user: Pubkey,
+
+    /// Market PDA
+    pub market: Pubkey,
+
+    /// Current valid nonce for this market
+    pub current_nonce: u64,
+
+    /// Number of cancelled salt ranges
+    pub salt_range_count: u8,
+
+    /// Cancelled salt ranges (first `salt_range_count` entries are valid)
+    pub salt_ranges: [SaltRange; MAX_CANCELLED_SALT_RANGES],
+
+    /// Number of cancelled order hashes
+    pub order_hash_count: u8,
+
+    /// Cancelled order hashes (first `order_hash_count` entries are valid)
+    pub order_hashes: [[u8; 32]; MAX_CANCELLED_ORDER_HASHES],
+
+    /// PDA bump seed
+    pub bump: u8,
+}
+
+impl MarketNonce {
+    /// Seed prefix for MarketNonce PDA
+    pub const SEED_PREFIX: &'static [u8] = b"market_nonce";
+
+    /// Space calculation for account initialization
+    /// discriminator(8) + user(32) + market(32) + current_nonce(8)
+    /// + salt_range_count(1) + salt_ranges(16 * 8)
+    /// + order_hash_count(1) + order_hashes(32 * 16) + bump(1)
+    pub const INIT_SPACE: usize = 8 + 32 + 32 + 8
+        + 1 + SaltRange::SIZE * MAX_CANCELLED_SALT_RANGES
+        + 1 + 32 * MAX_CANCELLED_ORDER_HASHES + 1;
+
+    /// Check if an order is still valid in this market scope
+    pub fn is_order_valid(&self, order: &Order, order_hash: &[u8; 32]) -> bool {
+        order.nonce >= self.current_nonce
+            && !self.salt_ranges[..self.salt_range_count as usize]
+                .iter()
+                .any(|range| range.contains(order.salt))
+            && !self.order_hashes[..self.order_hash_count as usize].contains(order_hash)
+    }
+
+    /// Raise the market nonce (must be > current)
+    ///
+    /// The salt range and order hash lists are kept: their orders may have been
+    /// signed with a nonce at or above `new_nonce`.
+    pub fn set_nonce(&mut self, new_nonce: u64) -> Result<()> {
+        require!(new_nonce > self.current_nonce, TerminatorError::InvalidNonce);
+        self.current_nonce = new_nonce;
+        Ok(())
+    }
+
+    /// Cancel every order whose salt is within `range`
+    pub fn cancel_salt_range(&mut self, range: SaltRange) -> Result<()> {
+        require!(range.start <= range.end, TerminatorError::InvalidInput);
+        require!(
+            (self.salt_range_count as usize) < MAX_CANCELLED_SALT_RANGES,
+            TerminatorError::CancellationListFull
+        );
+        self.salt_ranges[self.salt_range_count as usize] = range;
+        self.salt_range_count += 1;
+        Ok(())
+    }
+
+    /// Cancel a single order by hash (no-op if already listed)
+    pub fn cancel_order_hash(&mut self, order_hash: [u8; 32]) -> Result<()> {
+        if self.order_hashes[..self.order_hash_count as usize].contains(&order_hash) {
+            return Ok(());
+        }
+        require!(
+            (self.order_hash_count as usize) < MAX_CANCELLED_ORDER_HASHES,
+            TerminatorError::CancellationListFull
+        );
+        self.order_hashes[self.order_hash_count as usize] = order_hash;
+        self.order_hash_count += 1;
+        Ok(())
+    }
+}
+
+/// Find MarketNonce PDA address
+pub fn find_market_nonce_pda(user: &Pubkey, market: &Pubkey, program_id: &Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(
+        &[MarketNonce::SEED_PREFIX, user.as_ref(), market.as_ref()],
+        program_id,
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::states::{side, token_id, trigger_direction};
+
+    #[test]
+    fn test_market_nonce_scope() {
+        let mut market_nonce = MarketNonce {
+            user: Pubkey::new_unique(),
+            market: Pubkey::new_unique(),
+            current_nonce: 0,
+            salt_range_count: 0,
+            salt_ranges: [SaltRange::default(); MAX_CANCELLED_SALT_RANGES],
+            order_hash_count: 0,
+            order_hashes: [[0u8; 32]; MAX_CANCELLED_ORDER_HASHES],
+            bump: 255,
+        };
+        let order = Order {
+            salt: 50,
+            maker: market_nonce.user,
+            signer: market_nonce.user,
+            taker: Pubkey::default(),
+            market: market_nonce.market,
+            token_id: token_id::YES,
+            maker_amount: 1,
+            taker_amount: 1,
+            expiration: 0,
+            nonce: 1,
+            fee_rate_bps: 0,
+            side: side::BUY,
+            trigger_price: 0,
+            trigger_direction: trigger_direction::NONE,
+        };
+        let hash = [7u8; 32];
+        assert!(market_nonce.is_order_valid(&order, &hash));
+
+        // Hash list
+        market_nonce.cancel_order_hash(hash).unwrap();
+        market_nonce.cancel_order_hash(hash).unwrap();
+        assert_eq!(market_nonce.order_hash_count, 1);
+        assert!(!market_nonce.is_order_valid(&order, &hash));
+        assert!(market_nonce.is_order_valid(&order, &[8u8; 32]));
+
+        // Salt range
+        market_nonce.cancel_salt_range(SaltRange { start: 10, end: 50 }).unwrap();
+        assert!(!market_nonce.is_order_valid(&order, &[8u8; 32]));
+        assert!(market_nonce.is_order_valid(&Order { salt: 51, ..order.clone() }, &[8u8; 32]));
+        assert!(market_nonce.cancel_salt_range(SaltRange { start: 2, end: 1 }).is_err());
+
+        // Nonce
+        market_nonce.set_nonce(2).unwrap();
+        assert!(!market_nonce.is_order_valid(&Order { salt: 51, ..order.clone() }, &[8u8; 32]));
+        assert!(market_nonce.set_nonce(2).is_err());
+
+        // Lists are bounded
+        for i in 1..MAX_CANCELLED_ORDER_HASHES {
+            market_nonce.cancel_order_hash([i as u8 + 100; 32]).unwrap();
+        }
+        assert!(market_nonce.cancel_order_hash([99u8; 32]).is_err());
+        for i in 1..MAX_CANCELLED_SALT_RANGES as u64 {
+            market_nonce.cancel_salt_range(SaltRange { start: i * 1_000, end: i * 1_000 }).unwrap();
+        }
+        assert!(market_nonce.cancel_salt_range(SaltRange { start: 60, end: 60 }).is_err());
+    }
+
+    #[test]
+    fn test_raising_nonce_keeps_cancelled_orders() {
+        let mut market_nonce = MarketNonce {
+            user: Pubkey::new_unique(),
+            market: Pubkey::new_unique(),
+            current_nonce: 0,
+            salt_range_count: 0,
+            salt_ranges: [SaltRange::default(); MAX_CANCELLED_SALT_RANGES],
+            order_hash_count: 0,
+            order_hashes: [[0u8; 32]; MAX_CANCELLED_ORDER_HASHES],
+            bump: 255,
+        };
+        let order = Order {
+            salt: 50,
+            maker: market_nonce.user,
+            signer: market_nonce.user,
+            taker: Pubkey::default(),
+            market: market_nonce.market,
+            token_id: token_id::YES,
+            maker_amount: 1,
+            taker_amount: 1,
+            expiration: 0,
+            nonce: 10,
+            fee_rate_bps: 0,
+            side: side::BUY,
+            trigger_price: 0,
+            trigger_direction: trigger_direction::NONE,
+        };
+        let hash = [7u8; 32];
+        market_nonce.cancel_order_hash(hash).unwrap();
+        market_nonce.cancel_salt_range(SaltRange { start: 100, end: 200 }).unwrap();
+
+        // A nonce below the orders' nonce does not revive them
+        market_nonce.set_nonce(5).unwrap();
+        assert!(!market_nonce.is_order_valid(&order, &hash));
+        assert!(!market_nonce.is_order_valid(&Order { salt: 150, ..order.clone() }, &[8u8; 32]));
+        assert!(market_nonce.is_order_valid(&order, &[8u8; 32]));
+    }
+}
@@ -0,0 +1,73 @@
+use anchor_lang::prelude::*;
+use crate::errors::TerminatorError;
+
+/// Permanent record of a closed market
+///
+/// `close_market` closes the Market and its vault, but the market's
+/// UserBalance, UserPosition and OrderStatus PDAs (keyed by the market
+/// address) may outlive it. Since the market address only depends on the
+/// creator and `market_id`, `create_market` refuses any address that has a
+/// tombstone, so stale accounts can never be used against a new market.
+#[account]
+pub struct MarketTombstone {
+    /// Closed market address
+    pub market: Pubkey,
+
+    /// Unix timestamp when the market was closed
+    pub closed_at: i64,
+
+    /// PDA bump seed
+    pub bump: u8,
+}
+
+impl MarketTombstone {
+    /// Seed prefix for MarketTombstone PDA
+    pub const SEED_PREFIX: &'static [u8] = b"market_tombstone";
+
+    /// Space calculation for account initialization
+    /// discriminator(8) + market(32) + closed_at(8) + bump(1)
+    pub const INIT_SPACE: usize = 8 + 32 + 8 + 1;
+
+    /// Fail if the tombstone account exists (the market address was closed)
+    pub fn require_absent(tombstone: &AccountInfo) -> Result<()> {
+        require!(
+            tombstone.data_is_empty() && tombstone.lamports() == 0,
+            TerminatorError::MarketIdClosed
+        );
+        Ok(())
+    }
+}
+
+/// Helper to derive MarketTombstone PDA
+pub fn find_market_tombstone_pda(market: &Pubkey, program_id: &Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[MarketTombstone::SEED_PREFIX, market.as_ref()], program_id)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_closed_market_cannot_be_recreated() {
+        let market = Pubkey::new_unique();
+        let (key, bump) = find_market_tombstone_pda(&market, &crate::ID);
+        let system_program = anchor_lang::system_program::ID;
+
+        // Never closed: the tombstone address is an empty system account
+        let mut lamports = 0u64;
+        let mut data: Vec<u8> = Vec::new();
+        let empty = AccountInfo::new(&key, false, false, &mut lamports, &mut data, &system_program, false, 0);
+        assert!(MarketTombstone::require_absent(&empty).is_ok());
+
+        // Closed: close_market wrote the tombstone, so create_market fails
+        let tombstone = MarketTombstone { market, closed_at: 1_700_000_000, bump };
+        let mut lamports = 1_000_000u64;
+        let mut data = Vec::new();
+        tombstone.try_serialize(&mut data).unwrap();
+        let closed = AccountInfo::new(&key, false, true, &mut lamports, &mut data, &crate::ID, false, 0);
+        assert_eq!(
+            MarketTombstone::require_absent(&closed).unwrap_err(),
+            error!(TerminatorError::MarketIdClosed)
+        );
+    }
+}
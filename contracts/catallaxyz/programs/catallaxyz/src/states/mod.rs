@@ -1,19 +1,56 @@
 pub mod global;
+pub mod fee_curve;
+pub mod fee_tier;
 pub mod market;
+pub mod market_tombstone;
 pub mod user_balance;
 pub mod user_position;
+pub mod user_stats;
 
 // Exchange (Polymarket-style) order management
 pub mod order_types;
 pub mod user_nonce;
 pub mod order_status;
+pub mod delegate_registry;
+pub mod session_key;
+pub mod market_nonce;
+pub mod cancel_bitmap;
+
+// Access control
+pub mod role_assignment;
+
+// Referral program
+pub mod referral;
+
+// Market metadata
+pub mod market_clarifications;
+
+// Initial deployment layouts (read by the migrate_* instructions)
+pub mod legacy;
 
 pub use global::*;
+pub use fee_curve::*;
+pub use fee_tier::*;
 pub use market::*;
+pub use market_tombstone::*;
 pub use user_balance::*;
 pub use user_position::*;
+pub use user_stats::*;
 
 // Exchange exports
 pub use order_types::*;
 pub use user_nonce::*;
 pub use order_status::*;
+pub use delegate_registry::*;
+pub use session_key::*;
+pub use market_nonce::*;
+pub use cancel_bitmap::*;
+
+// Access control exports
+pub use role_assignment::*;
+
+// Referral exports
+pub use referral::*;
+
+// Market metadata exports
+pub use market_clarifications::*;
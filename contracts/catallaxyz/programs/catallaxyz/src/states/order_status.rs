@@ -23,6 +23,13 @@ pub struct OrderStatus {
     
     /// PDA bump seed
     pub bump: u8,
+    
+    /// Account that paid the rent (refunded by close_order_status)
+    /// 
+    /// Appended after the initial deployment. Statuses created before it are
+    /// keyed by the legacy order hash, which the fill paths no longer use, so
+    /// they are never loaded with this layout.
+    pub payer: Pubkey,
 }
 
 impl OrderStatus {
@@ -30,14 +37,16 @@ impl OrderStatus {
     pub const SEED_PREFIX: &'static [u8] = b"order_status";
     
     /// Space calculation for account initialization
-    /// discriminator(8) + order_hash(32) + is_filled_or_cancelled(1) + remaining(8) + bump(1)
-    pub const INIT_SPACE: usize = 8 + 32 + 1 + 8 + 1;
+    /// discriminator(8) + order_hash(32) + is_filled_or_cancelled(1) + remaining(8)
+    /// + bump(1) + payer(32)
+    pub const INIT_SPACE: usize = 8 + 32 + 1 + 8 + 1 + 32;
     
     /// Initialize order status for a new order
-    pub fn init(&mut self, order_hash: [u8; 32], maker_amount: u64, bump: u8) {
+    pub fn init(&mut self, order_hash: [u8; 32], maker_amount: u64, payer: Pubkey, bump: u8) {
         self.order_hash = order_hash;
         self.is_filled_or_cancelled = false;
         self.remaining = maker_amount;
+        self.payer = payer;
         self.bump = bump;
     }
     
@@ -43,11 +43,17 @@ pub struct Order {
     
     /// Side: 0=BUY, 1=SELL
     pub side: u8,
+    
+    /// Stop trigger price (scaled by 10^6, 0 when not a stop order)
+    pub trigger_price: u64,
+    
+    /// Stop trigger direction: 0=NONE, 1=ABOVE (last >= trigger), 2=BELOW (last <= trigger)
+    pub trigger_direction: u8,
 }
 
 impl Order {
     /// Serialized size for space calculation
-    pub const SERIALIZED_SIZE: usize = 8 + 32 + 32 + 32 + 32 + 1 + 8 + 8 + 8 + 8 + 2 + 1; // 172 bytes
+    pub const SERIALIZED_SIZE: usize = 8 + 32 + 32 + 32 + 32 + 1 + 8 + 8 + 8 + 8 + 2 + 1 + 8 + 1; // 181 bytes
     
     /// Check if order is a BUY order
     pub fn is_buy(&self) -> bool {
@@ -84,6 +90,27 @@ impl Order {
         self.taker == Pubkey::default()
     }
     
+    /// Check if order is a conditional stop order
+    pub fn is_stop(&self) -> bool {
+        self.trigger_direction != trigger_direction::NONE
+    }
+    
+    /// Check if the stop condition is met by the market's last traded price
+    /// for this order's outcome token
+    /// 
+    /// Non-stop orders are always triggered. Stop orders are never triggered
+    /// before the market has recorded a last price. The last price can be
+    /// moved by a fill of any size, so stops trust the operator's fill
+    /// sequencing (see `validate_stop_trigger`).
+    pub fn is_triggered(&self, last_price: Option<u64>) -> bool {
+        match (self.trigger_direction, last_price) {
+            (trigger_direction::NONE, _) => true,
+            (trigger_direction::ABOVE, Some(last)) => last >= self.trigger_price,
+            (trigger_direction::BELOW, Some(last)) => last <= self.trigger_price,
+            _ => false,
+        }
+    }
+    
     /// Calculate price from maker/taker amounts
     /// Returns price scaled by PRICE_SCALE (10^6)
     pub fn calculate_price(&self) -> u64 {
@@ -117,7 +144,7 @@ pub struct SignedOrder {
 }
 
 impl SignedOrder {
-    pub const SERIALIZED_SIZE: usize = Order::SERIALIZED_SIZE + 64; // 236 bytes
+    pub const SERIALIZED_SIZE: usize = Order::SERIALIZED_SIZE + 64; // 245 bytes
 }
 
 // ============================================
@@ -188,6 +215,16 @@ pub mod side {
     pub const SELL: u8 = 1;
 }
 
+/// Stop trigger direction constants
+pub mod trigger_direction {
+    /// Regular order (no trigger)
+    pub const NONE: u8 = 0;
+    /// Executable once last price >= trigger_price (stop-buy)
+    pub const ABOVE: u8 = 1;
+    /// Executable once last price <= trigger_price (stop-loss)
+    pub const BELOW: u8 = 2;
+}
+
 // ============================================
 // Price Crossing Check
 // ============================================
@@ -234,13 +271,125 @@ pub fn is_crossing(taker: &Order, maker: &Order, match_type: MatchType) -> bool
 // Order Hashing
 // ============================================
 
-/// Domain separator for order signing
+/// Legacy (v1) domain separator for order signing
+/// Does not bind program ID or cluster; only accepted behind
+/// `Global::accept_legacy_order_hash` during migration.
 pub const DOMAIN_SEPARATOR: &[u8] = b"Catallaxyz Exchange v1";
 
-/// Hash an order using Blake3
-/// Returns 32-byte hash
-pub fn hash_order(order: &Order) -> [u8; 32] {
-    let order_bytes = order.try_to_vec().unwrap_or_default();
+/// Domain tag for structured (v2+) order hashes
+pub const ORDER_DOMAIN_TAG: &[u8] = b"Catallaxyz Exchange";
+
+/// Current order domain version
+pub const ORDER_DOMAIN_VERSION: u8 = 2;
+
+/// Cluster tags bound into the order domain
+pub mod cluster {
+    pub const MAINNET_BETA: u8 = 0;
+    pub const DEVNET: u8 = 1;
+    pub const TESTNET: u8 = 2;
+    pub const LOCALNET: u8 = 3;
+
+    /// Check if a cluster tag is known
+    pub fn is_valid(cluster: u8) -> bool {
+        cluster <= LOCALNET
+    }
+}
+
+/// Structured signing domain
+/// 
+/// Binds an order signature to one deployment: a signature made for devnet
+/// or for a fork of the program is not valid anywhere else.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, PartialEq, Eq)]
+pub struct OrderDomain {
+    /// Program that executes the order
+    pub program_id: Pubkey,
+    /// Cluster tag (see `cluster`)
+    pub cluster: u8,
+    /// Domain version (`ORDER_DOMAIN_VERSION`)
+    pub version: u8,
+}
+
+impl OrderDomain {
+    /// Serialized size: program_id(32) + cluster(1) + version(1)
+    pub const SERIALIZED_SIZE: usize = 32 + 1 + 1;
+    
+    /// Current domain of this program on a cluster
+    pub fn new(cluster: u8) -> Self {
+        Self {
+            program_id: crate::ID,
+            cluster,
+            version: ORDER_DOMAIN_VERSION,
+        }
+    }
+}
+
+/// Canonical bytes of an order for signing (hash preimage)
+/// 
+/// Layout (all integers little-endian, Borsh):
+/// 
+/// | offset | size | field                                  |
+/// |--------|------|----------------------------------------|
+/// | 0      | 19   | "Catallaxyz Exchange" (ASCII)          |
+/// | 19     | 32   | domain.program_id                      |
+/// | 51     | 1    | domain.cluster                         |
+/// | 52     | 1    | domain.version                         |
+/// | 53     | 8    | salt (u64)                             |
+/// | 61     | 32   | maker                                  |
+/// | 93     | 32   | signer                                 |
+/// | 125    | 32   | taker                                  |
+/// | 157    | 32   | market                                 |
+/// | 189    | 1    | token_id (0 = USDC, 1 = YES, 2 = NO)   |
+/// | 190    | 8    | maker_amount (u64)                     |
+/// | 198    | 8    | taker_amount (u64)                     |
+/// | 206    | 8    | expiration (i64, 0 = never)            |
+/// | 214    | 8    | nonce (u64)                            |
+/// | 222    | 2    | fee_rate_bps (u16)                     |
+/// | 224    | 1    | side (0 = BUY, 1 = SELL)               |
+/// | 225    | 8    | trigger_price (u64)                    |
+/// | 233    | 1    | trigger_direction (0/1/2)              |
+/// 
+/// Total: 234 bytes. The order hash is `blake3(canonical bytes)`.
+pub fn order_signing_bytes(order: &Order, domain: &OrderDomain) -> Vec<u8> {
+    let mut bytes = Vec::with_capacity(
+        ORDER_DOMAIN_TAG.len() + OrderDomain::SERIALIZED_SIZE + Order::SERIALIZED_SIZE,
+    );
+    bytes.extend_from_slice(ORDER_DOMAIN_TAG);
+    bytes.extend_from_slice(domain.program_id.as_ref());
+    bytes.push(domain.cluster);
+    bytes.push(domain.version);
+    bytes.extend_from_slice(&order.try_to_vec().unwrap_or_default());
+    bytes
+}
+
+/// Hash an order in a signing domain using Blake3
+/// Returns 32-byte hash (also the OrderStatus PDA seed)
+pub fn hash_order(order: &Order, domain: &OrderDomain) -> [u8; 32] {
+    *blake3::hash(&order_signing_bytes(order, domain)).as_bytes()
+}
+
+/// Borsh encoding of an order in the legacy v1 field layout
+///
+/// v1 orders predate the stop trigger fields, so they are not part of the encoding.
+fn order_bytes_v1(order: &Order) -> Vec<u8> {
+    let mut bytes = Vec::with_capacity(172); // v1 Order::SERIALIZED_SIZE
+    bytes.extend_from_slice(&order.salt.to_le_bytes());
+    bytes.extend_from_slice(order.maker.as_ref());
+    bytes.extend_from_slice(order.signer.as_ref());
+    bytes.extend_from_slice(order.taker.as_ref());
+    bytes.extend_from_slice(order.market.as_ref());
+    bytes.push(order.token_id);
+    bytes.extend_from_slice(&order.maker_amount.to_le_bytes());
+    bytes.extend_from_slice(&order.taker_amount.to_le_bytes());
+    bytes.extend_from_slice(&order.expiration.to_le_bytes());
+    bytes.extend_from_slice(&order.nonce.to_le_bytes());
+    bytes.extend_from_slice(&order.fee_rate_bps.to_le_bytes());
+    bytes.push(order.side);
+    bytes
+}
+
+/// Hash an order with the legacy v1 format (no program ID / cluster binding)
+pub fn hash_order_legacy(order: &Order) -> [u8; 32] {
+    let order_bytes = order_bytes_v1(order);
     
     let mut combined = Vec::with_capacity(DOMAIN_SEPARATOR.len() + order_bytes.len());
     combined.extend_from_slice(DOMAIN_SEPARATOR);
@@ -270,6 +419,8 @@ mod tests {
             nonce: 0,
             fee_rate_bps: 0,
             side: side::BUY,
+            trigger_price: 0,
+            trigger_direction: trigger_direction::NONE,
         };
         assert_eq!(buy_order.calculate_price(), 500_000);
         
@@ -288,6 +439,8 @@ mod tests {
             nonce: 0,
             fee_rate_bps: 0,
             side: side::SELL,
+            trigger_price: 0,
+            trigger_direction: trigger_direction::NONE,
         };
         assert_eq!(sell_order.calculate_price(), 600_000);
     }
@@ -307,6 +460,8 @@ mod tests {
             nonce: 0,
             fee_rate_bps: 0,
             side: side::BUY,
+            trigger_price: 0,
+            trigger_direction: trigger_direction::NONE,
         };
         
         let sell_yes = Order {
@@ -336,4 +491,110 @@ mod tests {
         // SELL YES vs SELL NO = Merge
         assert_eq!(MatchType::from_orders(&sell_yes, &sell_no), Some(MatchType::Merge));
     }
+    
+    #[test]
+    fn test_stop_trigger() {
+        let stop_loss = Order {
+            salt: 1,
+            maker: Pubkey::default(),
+            signer: Pubkey::default(),
+            taker: Pubkey::default(),
+            market: Pubkey::default(),
+            token_id: token_id::YES,
+            maker_amount: 1_000_000,
+            taker_amount: 300_000,
+            expiration: 0,
+            nonce: 0,
+            fee_rate_bps: 0,
+            side: side::SELL,
+            trigger_price: 400_000,
+            trigger_direction: trigger_direction::BELOW,
+        };
+        assert!(stop_loss.is_stop());
+        assert!(!stop_loss.is_triggered(None));
+        assert!(!stop_loss.is_triggered(Some(400_001)));
+        assert!(stop_loss.is_triggered(Some(400_000)));
+        assert!(stop_loss.is_triggered(Some(350_000)));
+        
+        let stop_buy = Order {
+            side: side::BUY,
+            trigger_direction: trigger_direction::ABOVE,
+            ..stop_loss.clone()
+        };
+        assert!(!stop_buy.is_triggered(Some(399_999)));
+        assert!(stop_buy.is_triggered(Some(400_000)));
+        
+        let regular = Order {
+            trigger_price: 0,
+            trigger_direction: trigger_direction::NONE,
+            ..stop_loss
+        };
+        assert!(!regular.is_stop());
+        assert!(regular.is_triggered(None));
+    }
+    
+    #[test]
+    fn test_order_hash_domain_binding() {
+        let order = Order {
+            salt: 7,
+            maker: Pubkey::new_unique(),
+            signer: Pubkey::new_unique(),
+            taker: Pubkey::default(),
+            market: Pubkey::new_unique(),
+            token_id: token_id::YES,
+            maker_amount: 500_000,
+            taker_amount: 1_000_000,
+            expiration: 0,
+            nonce: 0,
+            fee_rate_bps: 0,
+            side: side::BUY,
+            trigger_price: 0,
+            trigger_direction: trigger_direction::NONE,
+        };
+        let mainnet = OrderDomain::new(cluster::MAINNET_BETA);
+        let devnet = OrderDomain::new(cluster::DEVNET);
+        let fork = OrderDomain { program_id: Pubkey::new_unique(), ..mainnet };
+        
+        // Canonical layout size is documented
+        assert_eq!(order_signing_bytes(&order, &mainnet).len(), 234);
+        assert_eq!(&order_signing_bytes(&order, &mainnet)[..19], ORDER_DOMAIN_TAG);
+        
+        // Cluster, program and legacy hashes all differ
+        let hash = hash_order(&order, &mainnet);
+        assert_ne!(hash, hash_order(&order, &devnet));
+        assert_ne!(hash, hash_order(&order, &fork));
+        assert_ne!(hash, hash_order_legacy(&order));
+        assert_eq!(hash, hash_order(&order.clone(), &mainnet));
+    }
+    
+    #[test]
+    fn test_legacy_order_hash_matches_v1() {
+        let mut order = Order {
+            salt: 42,
+            maker: Pubkey::new_from_array([1u8; 32]),
+            signer: Pubkey::new_from_array([2u8; 32]),
+            taker: Pubkey::default(),
+            market: Pubkey::new_from_array([3u8; 32]),
+            token_id: token_id::YES,
+            maker_amount: 500_000,
+            taker_amount: 1_000_000,
+            expiration: 1_700_000_000,
+            nonce: 7,
+            fee_rate_bps: 30,
+            side: side::BUY,
+            trigger_price: 0,
+            trigger_direction: trigger_direction::NONE,
+        };
+        // Hash of the same order produced by the v1 program's hash_order
+        let v1_hash = [
+            96, 121, 182, 61, 121, 48, 3, 71, 27, 252, 45, 99, 182, 218, 89, 96,
+            200, 26, 130, 173, 92, 1, 38, 108, 161, 74, 98, 124, 33, 68, 153, 40,
+        ];
+        assert_eq!(hash_order_legacy(&order), v1_hash);
+        
+        // Trigger fields are not part of the v1 layout
+        order.trigger_price = 600_000;
+        order.trigger_direction = trigger_direction::ABOVE;
+        assert_eq!(hash_order_legacy(&order), v1_hash);
+    }
 }
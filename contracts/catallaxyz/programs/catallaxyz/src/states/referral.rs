@@ -0,0 +1,69 @@
+use anchor_lang::prelude::*;
+
+/// A user's referrer, registered once by the user
+///
+/// Fill paths always take this PDA address and, when it exists, pass a share
+/// of the platform's cut of the USDC fees collected from the user (see
+/// `Global::referral_share_rate`) to the referrer's `ReferrerRewards`.
+#[account]
+pub struct Referral {
+    /// Referred user
+    pub user: Pubkey,
+
+    /// Referrer wallet
+    pub referrer: Pubkey,
+
+    /// Unix timestamp of the registration
+    pub registered_at: i64,
+
+    /// PDA bump seed
+    pub bump: u8,
+}
+
+impl Referral {
+    /// Seed prefix for Referral PDA
+    pub const SEED_PREFIX: &'static [u8] = b"referral";
+
+    /// Space calculation for account initialization
+    /// discriminator(8) + user(32) + referrer(32) + registered_at(8) + bump(1)
+    pub const INIT_SPACE: usize = 8 + 32 + 32 + 8 + 1;
+}
+
+/// Referral fees accrued to a referrer, claimable from the platform treasury
+#[account]
+pub struct ReferrerRewards {
+    /// Referrer wallet
+    pub referrer: Pubkey,
+
+    /// Unclaimed referral fees (USDC lamports)
+    pub accrued: u64,
+
+    /// Lifetime referral fees claimed (USDC lamports)
+    pub total_claimed: u64,
+
+    /// Number of users who registered this referrer
+    pub referred_users: u32,
+
+    /// PDA bump seed
+    pub bump: u8,
+}
+
+impl ReferrerRewards {
+    /// Seed prefix for ReferrerRewards PDA
+    pub const SEED_PREFIX: &'static [u8] = b"referrer_rewards";
+
+    /// Space calculation for account initialization
+    /// discriminator(8) + referrer(32) + accrued(8) + total_claimed(8)
+    /// + referred_users(4) + bump(1)
+    pub const INIT_SPACE: usize = 8 + 32 + 8 + 8 + 4 + 1;
+}
+
+/// Helper to derive Referral PDA
+pub fn find_referral_pda(user: &Pubkey, program_id: &Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[Referral::SEED_PREFIX, user.as_ref()], program_id)
+}
+
+/// Helper to derive ReferrerRewards PDA
+pub fn find_referrer_rewards_pda(referrer: &Pubkey, program_id: &Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[ReferrerRewards::SEED_PREFIX, referrer.as_ref()], program_id)
+}
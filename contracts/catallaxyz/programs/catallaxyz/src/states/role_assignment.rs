@@ -0,0 +1,66 @@
+use anchor_lang::prelude::*;
+
+/// Role constants
+///
+/// `Global::authority` is the super-admin: it implicitly holds every role and
+/// is the only key that can grant or revoke them.
+pub mod role {
+    /// Update platform fee rates
+    pub const FEE_ADMIN: u8 = 0;
+    /// Pause/unpause global trading and individual markets
+    pub const PAUSER: u8 = 1;
+    /// Withdraw treasury fees and distribute liquidity rewards
+    pub const TREASURER: u8 = 2;
+    /// Update market parameters, settle and delist markets
+    pub const MARKET_MODERATOR: u8 = 3;
+    /// Automated maintenance (terminate inactive markets, sweep dust, close markets)
+    pub const KEEPER: u8 = 4;
+    /// Execute trades (fill_order, fill_orders, fill_multi_leg, match_orders)
+    pub const OPERATOR: u8 = 5;
+
+    /// Check if a role tag is known
+    pub fn is_valid(role: u8) -> bool {
+        role <= OPERATOR
+    }
+}
+
+/// Role held by one key
+///
+/// One PDA per (role, holder), so there is no cap on the number of holders
+/// of a role. Revoking closes the account, which removes the role immediately.
+/// Role-gated instructions take the signer's assignment as an optional
+/// account; it is omitted when the signer is the authority.
+#[account]
+pub struct RoleAssignment {
+    /// Role tag (see `role`)
+    pub role: u8,
+
+    /// Key holding the role
+    pub holder: Pubkey,
+
+    /// Authority that granted the role
+    pub granted_by: Pubkey,
+
+    /// Unix timestamp of the grant
+    pub granted_at: i64,
+
+    /// PDA bump seed
+    pub bump: u8,
+}
+
+impl RoleAssignment {
+    /// Seed prefix for RoleAssignment PDA
+    pub const SEED_PREFIX: &'static [u8] = b"role";
+
+    /// Space calculation for account initialization
+    /// discriminator(8) + role(1) + holder(32) + granted_by(32) + granted_at(8) + bump(1)
+    pub const INIT_SPACE: usize = 8 + 1 + 32 + 32 + 8 + 1;
+}
+
+/// Helper to derive RoleAssignment PDA
+pub fn find_role_pda(role: u8, holder: &Pubkey, program_id: &Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(
+        &[RoleAssignment::SEED_PREFIX, &[role], holder.as_ref()],
+        program_id,
+    )
+}
@@ -0,0 +1,101 @@
+use anchor_lang::prelude::*;
+use crate::errors::TerminatorError;
+
+/// Maximum number of markets a session key can be scoped to
+pub const MAX_SESSION_MARKETS: usize = 8;
+
+/// Short-lived session key authorized by a wallet
+///
+/// Lets a browser-held hot key sign orders for `owner` (`Order::signer == key`)
+/// within a limited scope:
+/// - only in the listed markets
+/// - up to `max_notional` USDC of cumulative fills
+/// - until `expires_at`
+///
+/// Revoking closes the account, which invalidates the key immediately.
+#[account]
+pub struct SessionKey {
+    /// Wallet that authorized the session (order maker)
+    pub owner: Pubkey,
+
+    /// Session signing key (order signer)
+    pub key: Pubkey,
+
+    /// Number of allowed markets
+    pub market_count: u8,
+
+    /// Markets the key may trade (first `market_count` entries are valid)
+    pub markets: [Pubkey; MAX_SESSION_MARKETS],
+
+    /// Maximum cumulative USDC notional
+    pub max_notional: u64,
+
+    /// Cumulative USDC notional already filled
+    pub used_notional: u64,
+
+    /// Unix timestamp after which the key is invalid
+    pub expires_at: i64,
+
+    /// PDA bump seed
+    pub bump: u8,
+}
+
+impl SessionKey {
+    /// Seed prefix for SessionKey PDA
+    pub const SEED_PREFIX: &'static [u8] = b"session_key";
+
+    /// Space calculation for account initialization
+    /// discriminator(8) + owner(32) + key(32) + market_count(1) + markets(32 * 8)
+    /// + max_notional(8) + used_notional(8) + expires_at(8) + bump(1)
+    pub const INIT_SPACE: usize = 8 + 32 + 32 + 1 + 32 * MAX_SESSION_MARKETS + 8 + 8 + 8 + 1;
+
+    /// Check if the session key may trade in a market
+    pub fn allows_market(&self, market: &Pubkey) -> bool {
+        self.markets[..self.market_count as usize].contains(market)
+    }
+
+    /// Check scope and record `notional` USDC of usage
+    pub fn spend(&mut self, market: &Pubkey, now: i64, notional: u64) -> Result<()> {
+        require!(now <= self.expires_at, TerminatorError::SessionKeyExpired);
+        require!(self.allows_market(market), TerminatorError::SessionMarketNotAllowed);
+
+        let used = self.used_notional
+            .checked_add(notional)
+            .ok_or(TerminatorError::ArithmeticOverflow)?;
+        require!(used <= self.max_notional, TerminatorError::SessionNotionalExceeded);
+        self.used_notional = used;
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_session_key_scope() {
+        let market = Pubkey::new_unique();
+        let mut markets = [Pubkey::default(); MAX_SESSION_MARKETS];
+        markets[0] = market;
+        let mut session = SessionKey {
+            owner: Pubkey::new_unique(),
+            key: Pubkey::new_unique(),
+            market_count: 1,
+            markets,
+            max_notional: 1_000,
+            used_notional: 0,
+            expires_at: 100,
+            bump: 255,
+        };
+
+        assert!(session.spend(&market, 50, 700).is_ok());
+        // Notional cap
+        assert!(session.spend(&market, 50, 301).is_err());
+        assert!(session.spend(&market, 50, 300).is_ok());
+        assert_eq!(session.used_notional, 1_000);
+        // Market scope (default key is beyond market_count)
+        assert!(session.spend(&Pubkey::default(), 50, 0).is_err());
+        // Expiry
+        assert!(session.spend(&market, 101, 0).is_err());
+    }
+}
@@ -0,0 +1,122 @@
+use anchor_lang::prelude::*;
+
+/// Length of the rolling volume window (days)
+pub const STATS_WINDOW_DAYS: usize = 30;
+
+/// Seconds per daily volume bucket
+pub const SECONDS_PER_DAY: i64 = 24 * 60 * 60;
+
+/// Per-user trading stats for volume-tiered fees
+///
+/// Taker notional is accumulated into daily buckets (a ring buffer indexed by
+/// day number), so the rolling 30-day volume only needs the buckets that are
+/// still inside the window. Created by the operator on the user's first
+/// taker fill (`fill_order`, `fill_orders`, `fill_multi_leg`, `match_orders`).
+#[account]
+pub struct UserStats {
+    /// User wallet
+    pub user: Pubkey,
+
+    /// Day number (unix_timestamp / SECONDS_PER_DAY) of the latest bucket
+    pub last_day: i64,
+
+    /// Taker notional per day (USDC), bucket `day % STATS_WINDOW_DAYS`
+    pub daily_taker_volume: [u64; STATS_WINDOW_DAYS],
+
+    /// Fee tier as of the latest recorded fill (see `FeeTierTable`)
+    pub tier: u8,
+
+    /// PDA bump seed
+    pub bump: u8,
+}
+
+impl UserStats {
+    /// Seed prefix for UserStats PDA
+    pub const SEED_PREFIX: &'static [u8] = b"user_stats";
+
+    /// Space calculation for account initialization
+    /// discriminator(8) + user(32) + last_day(8) + daily_taker_volume(8 * 30)
+    /// + tier(1) + bump(1)
+    pub const INIT_SPACE: usize = 8 + 32 + 8 + 8 * STATS_WINDOW_DAYS + 1 + 1;
+
+    /// Initialize stats for a new user
+    pub fn init(&mut self, user: Pubkey, bump: u8) {
+        self.user = user;
+        self.last_day = 0;
+        self.daily_taker_volume = [0; STATS_WINDOW_DAYS];
+        self.tier = 0;
+        self.bump = bump;
+    }
+
+    /// Taker notional over the 30 days ending at `now` (inclusive of today)
+    pub fn rolling_volume(&self, now: i64) -> u64 {
+        let today = now.div_euclid(SECONDS_PER_DAY);
+        let window_start = today - STATS_WINDOW_DAYS as i64 + 1;
+        (window_start.max(self.last_day - STATS_WINDOW_DAYS as i64 + 1)..=self.last_day.min(today))
+            .map(|day| self.daily_taker_volume[Self::bucket(day)])
+            .fold(0u64, u64::saturating_add)
+    }
+
+    /// Add taker notional to today's bucket, clearing buckets of skipped days
+    pub fn record_taker_volume(&mut self, now: i64, notional: u64) {
+        let today = now.div_euclid(SECONDS_PER_DAY);
+        if today > self.last_day {
+            let stale_days = (today - self.last_day).min(STATS_WINDOW_DAYS as i64);
+            for day in (today - stale_days + 1)..=today {
+                self.daily_taker_volume[Self::bucket(day)] = 0;
+            }
+            self.last_day = today;
+        }
+        // Clock skew back across midnight counts towards the latest bucket
+        let bucket = Self::bucket(self.last_day);
+        self.daily_taker_volume[bucket] = self.daily_taker_volume[bucket].saturating_add(notional);
+    }
+
+    fn bucket(day: i64) -> usize {
+        day.rem_euclid(STATS_WINDOW_DAYS as i64) as usize
+    }
+}
+
+/// Helper to derive UserStats PDA
+pub fn find_user_stats_pda(user: &Pubkey, program_id: &Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[UserStats::SEED_PREFIX, user.as_ref()], program_id)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const DAY: i64 = SECONDS_PER_DAY;
+
+    fn stats() -> UserStats {
+        UserStats {
+            user: Pubkey::new_unique(),
+            last_day: 0,
+            daily_taker_volume: [0; STATS_WINDOW_DAYS],
+            tier: 0,
+            bump: 255,
+        }
+    }
+
+    #[test]
+    fn test_rolling_volume_window() {
+        let mut stats = stats();
+        let start = 20_000 * DAY;
+
+        stats.record_taker_volume(start, 100);
+        stats.record_taker_volume(start + 3_600, 50);
+        stats.record_taker_volume(start + 10 * DAY, 200);
+        assert_eq!(stats.rolling_volume(start + 10 * DAY), 350);
+
+        // Day `start` leaves the window 30 days later
+        assert_eq!(stats.rolling_volume(start + 29 * DAY), 350);
+        assert_eq!(stats.rolling_volume(start + 30 * DAY), 200);
+        assert_eq!(stats.rolling_volume(start + 40 * DAY), 0);
+
+        // Recording after a gap clears the reused buckets
+        stats.record_taker_volume(start + 30 * DAY, 10);
+        assert_eq!(stats.rolling_volume(start + 30 * DAY), 210);
+        stats.record_taker_volume(start + 100 * DAY, 5);
+        assert_eq!(stats.rolling_volume(start + 100 * DAY), 5);
+    }
+}
@@ -1,7 +1,7 @@
-/// Lightweight Switchboard VRF Integration
-/// 
-/// This module provides minimal Switchboard randomness parsing without the full SDK dependency.
-/// It only includes what we need: parsing RandomnessAccountData and extracting random values.
+//! Lightweight Switchboard VRF Integration
+//! 
+//! This module provides minimal Switchboard randomness parsing without the full SDK dependency.
+//! It only includes what we need: parsing RandomnessAccountData and extracting random values.
 
 use anchor_lang::prelude::*;
 
@@ -0,0 +1,75 @@
+//! Shared fixtures for unit tests
+
+use anchor_lang::prelude::*;
+use crate::states::{default_fees, FeeCurve, FeeTierTable, Global, DEFAULT_GOVERNANCE_DELAY, LEGACY_MAX_OPERATORS};
+
+/// Leak an account so it can back `Account`/`AccountInfo` values for the
+/// whole test
+pub(crate) fn leak_account(
+    key: Pubkey,
+    owner: Pubkey,
+    lamports: u64,
+    data: Vec<u8>,
+) -> &'static AccountInfo<'static> {
+    Box::leak(Box::new(AccountInfo::new(
+        Box::leak(Box::new(key)),
+        false,
+        true,
+        Box::leak(Box::new(lamports)),
+        Box::leak(data.into_boxed_slice()),
+        Box::leak(Box::new(owner)),
+        false,
+        0,
+    )))
+}
+
+/// Account data (discriminator included) for a program account
+pub(crate) fn serialized<T: AccountSerialize>(value: &T) -> Vec<u8> {
+    let mut data = Vec::new();
+    value.try_serialize(&mut data).unwrap();
+    data
+}
+
+/// Global config with the default fee schedule
+pub(crate) fn global(authority: Pubkey) -> Global {
+    Global {
+        authority,
+        usdc_mint: Pubkey::new_unique(),
+        legacy_keeper: Pubkey::default(),
+        bump: 255,
+        platform_treasury_bump: 255,
+        total_trading_fees_collected: 0,
+        total_creation_fees_collected: 0,
+        center_taker_fee_rate: default_fees::CENTER_TAKER_FEE_RATE,
+        extreme_taker_fee_rate: default_fees::EXTREME_TAKER_FEE_RATE,
+        platform_fee_rate: default_fees::PLATFORM_FEE_RATE,
+        maker_rebate_rate: default_fees::MAKER_REBATE_RATE,
+        creator_incentive_rate: default_fees::CREATOR_INCENTIVE_RATE,
+        fee_curve: FeeCurve::default(),
+        fee_tiers: FeeTierTable::default(),
+        referral_share_rate: default_fees::REFERRAL_SHARE_RATE,
+        trading_paused: false,
+        legacy_operator_count: 0,
+        legacy_operators: [Pubkey::default(); LEGACY_MAX_OPERATORS],
+        layout_version: Global::LAYOUT_VERSION,
+        cluster: 0,
+        accept_legacy_order_hash: false,
+        pending_authority: Pubkey::default(),
+        pending_authority_eta: 0,
+        authority_transfer_delay: 0,
+        governance_delay: DEFAULT_GOVERNANCE_DELAY,
+        pending_fee_rates: None,
+        pending_fee_tiers: FeeTierTable::default(),
+        pending_fee_rates_eta: 0,
+        pending_governance_delay: None,
+        pending_governance_delay_eta: 0,
+        creation_fee: crate::constants::MARKET_CREATION_FEE,
+        creation_fee_refund_volume: 0,
+        alt_fee_mint: Pubkey::default(),
+        alt_fee_recipient: Pubkey::default(),
+        alt_fee_rate: 0,
+        pending_authority_transfer_delay: None,
+        pending_authority_transfer_delay_eta: 0,
+        referral_rewards_outstanding: 0,
+    }
+}
@@ -24,7 +24,6 @@ import { setupProvider, printConfig } from "./utils/anchor-config.ts";
  * 
  * Environment variables:
  * - TEST_USDC_MINT: Address of the test USDC mint (required)
- * - KEEPER_PUBLIC_KEY: Set the keeper address (optional, defaults to authority)
  * 
  * Usage:
  *   TEST_USDC_MINT=<mint_address> yarn ts-node scripts/initialize-devnet.ts
@@ -172,17 +171,11 @@ async function main() {
     
     console.log("📝 Initializing Global account...");
     
-    const keeperEnv = process.env.KEEPER_PUBLIC_KEY;
-    const keeper = keeperEnv ? new PublicKey(keeperEnv) : null;
-    if (keeper) {
-      console.log("   Keeper:", keeper.toString());
-    }
-    
     try {
       const tx = await program.methods
         .initialize({
           usdcMint: TEST_USDC_MINT,
-          keeper,
+          cluster: 1, // order signing domain: devnet
         })
         .accountsStrict({
           authority: provider.wallet.publicKey,
@@ -376,7 +369,7 @@ async function main() {
   console.log(`USDC_MINT_ADDRESS=${TEST_USDC_MINT.toString()}`);
   console.log("");
   console.log("📝 Next Steps:");
-  console.log("1. Set Keeper (if not set):");
+  console.log("1. Grant the keeper role (authority already holds every role):");
   console.log("   KEEPER_PUBLIC_KEY=<pubkey> yarn ts-node scripts/set-keeper.ts");
   console.log("");
   console.log("2. Mint test USDC to users:");
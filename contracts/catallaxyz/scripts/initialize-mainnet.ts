@@ -27,9 +27,6 @@ import { setupProvider, printConfig, getAnchorConfig } from "./utils/anchor-conf
  * - Anchor.toml configured for Mainnet (cluster = "mainnet")
  * - At least 5 SOL in deployer wallet
  * 
- * Optional environment variables:
- * - KEEPER_PUBLIC_KEY: Set the keeper address (defaults to authority)
- * 
  * Usage:
  *   yarn ts-node scripts/initialize-mainnet.ts
  */
@@ -219,20 +216,11 @@ async function main() {
     
     console.log("📝 Initializing Global account...");
     
-    // Check for optional keeper from environment
-    const keeperEnv = process.env.KEEPER_PUBLIC_KEY;
-    const keeper = keeperEnv ? new PublicKey(keeperEnv) : null;
-    if (keeper) {
-      console.log("   Keeper:", keeper.toString());
-    } else {
-      console.log("   Keeper: (defaults to authority)");
-    }
-    
     try {
       const tx = await program.methods
         .initialize({
           usdcMint: MAINNET_USDC_MINT,
-          keeper,
+          cluster: 0, // order signing domain: mainnet-beta
         })
         .accountsStrict({
           authority: provider.wallet.publicKey,
@@ -13,9 +13,6 @@ import { setupProvider, printConfig } from "./utils/anchor-config.ts";
  * 2. Initialize Global account using tUSDC as base token
  * 3. Verify initialization results
  * 
- * Optional environment variables:
- * - KEEPER_PUBLIC_KEY: Set the keeper address (defaults to authority)
- * 
  * Usage:
  *   yarn ts-node scripts/initialize-with-tusdc.ts
  */
@@ -120,19 +117,10 @@ async function main() {
     // Initialize program
     console.log("📝 Sending initialization transaction...");
     
-    // Check for optional keeper from environment
-    const keeperEnv = process.env.KEEPER_PUBLIC_KEY;
-    const keeper = keeperEnv ? new PublicKey(keeperEnv) : null;
-    if (keeper) {
-      console.log("   Keeper:", keeper.toString());
-    } else {
-      console.log("   Keeper: (defaults to authority)");
-    }
-    
     const tx = await program.methods
         .initialize({
           usdcMint: tUsdcMint,
-          keeper,
+          cluster: 1, // order signing domain: devnet
         })
       .accountsStrict({
         authority: provider.wallet.publicKey,
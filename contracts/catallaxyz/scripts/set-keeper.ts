@@ -1,28 +1,31 @@
 import * as anchor from "@coral-xyz/anchor";
 import { Program } from "@coral-xyz/anchor";
-import { PublicKey } from "@solana/web3.js";
+import { PublicKey, SystemProgram } from "@solana/web3.js";
 import * as fs from "fs";
 import type { Catallaxyz } from "../target/types/catallaxyz";
 import { setupProvider, printConfig } from "./utils/anchor-config";
 
 /**
- * Set or Update Keeper Script
+ * Grant or Revoke Keeper Role Script
  * 
- * This script sets or updates the keeper wallet address in the Global account.
- * The keeper can perform automated tasks like terminating inactive markets.
+ * This script grants the KEEPER role to a wallet (creates its RoleAssignment PDA).
+ * Keepers can perform automated tasks like terminating inactive markets.
+ * Any number of keepers can be granted; the authority always has the role.
  * 
  * Usage:
  *   KEEPER_PUBLIC_KEY=<pubkey> yarn ts-node scripts/set-keeper.ts
  * 
- * To disable separate keeper (only authority can perform keeper tasks):
- *   KEEPER_PUBLIC_KEY=11111111111111111111111111111111 yarn ts-node scripts/set-keeper.ts
+ * To revoke the role:
+ *   KEEPER_PUBLIC_KEY=<pubkey> REVOKE=true yarn ts-node scripts/set-keeper.ts
  */
 
 const GLOBAL_SEED = "global";
+const ROLE_SEED = "role";
+const KEEPER_ROLE = 4; // states::role::KEEPER
 
 async function main() {
   console.log("=".repeat(70));
-  console.log("🔧 SET KEEPER");
+  console.log("🔧 KEEPER ROLE");
   console.log("=".repeat(70));
   console.log("");
 
@@ -34,14 +37,16 @@ async function main() {
     console.log("Usage:");
     console.log("  KEEPER_PUBLIC_KEY=<pubkey> yarn ts-node scripts/set-keeper.ts");
     console.log("");
-    console.log("To disable separate keeper:");
-    console.log("  KEEPER_PUBLIC_KEY=11111111111111111111111111111111 yarn ts-node scripts/set-keeper.ts");
+    console.log("To revoke:");
+    console.log("  KEEPER_PUBLIC_KEY=<pubkey> REVOKE=true yarn ts-node scripts/set-keeper.ts");
     process.exit(1);
   }
 
-  let newKeeper: PublicKey;
+  const revoke = process.env.REVOKE === "true";
+
+  let keeper: PublicKey;
   try {
-    newKeeper = new PublicKey(keeperEnv);
+    keeper = new PublicKey(keeperEnv);
   } catch (error) {
     console.log("❌ ERROR: Invalid KEEPER_PUBLIC_KEY");
     console.log("   Provided:", keeperEnv);
@@ -54,7 +59,8 @@ async function main() {
   const connection = provider.connection;
   
   console.log("🔑 Authority Wallet:", provider.wallet.publicKey.toString());
-  console.log("🤖 New Keeper:", newKeeper.toString());
+  console.log("🤖 Keeper:", keeper.toString());
+  console.log("📝 Action:", revoke ? "revoke" : "grant");
   console.log("");
 
   // Load program from IDL
@@ -77,25 +83,31 @@ async function main() {
     program.programId
   );
 
+  // Derive RoleAssignment PDA for (KEEPER, keeper)
+  const [rolePda] = PublicKey.findProgramAddressSync(
+    [Buffer.from(ROLE_SEED), Buffer.from([KEEPER_ROLE]), keeper.toBuffer()],
+    program.programId
+  );
+
   // Fetch current Global state
   try {
     const globalAccount = await program.account.global.fetch(globalPda);
     console.log("📊 Current Global State:");
     console.log("   Authority:", globalAccount.authority.toString());
-    console.log("   Current Keeper:", globalAccount.keeper.toString());
     console.log("");
 
     // Check if wallet is authority
     if (!globalAccount.authority.equals(provider.wallet.publicKey)) {
-      console.log("❌ ERROR: Only the authority can update the keeper");
+      console.log("❌ ERROR: Only the authority can grant or revoke roles");
       console.log("   Authority:", globalAccount.authority.toString());
       console.log("   Your wallet:", provider.wallet.publicKey.toString());
       process.exit(1);
     }
 
-    // Check if keeper is already set to the new value
-    if (globalAccount.keeper.equals(newKeeper)) {
-      console.log("ℹ️  Keeper is already set to this value");
+    // Check if the role is already in the requested state
+    const hasRole = (await connection.getAccountInfo(rolePda)) !== null;
+    if (hasRole !== revoke) {
+      console.log(`ℹ️  Keeper role is already ${hasRole ? "granted" : "revoked"}`);
       process.exit(0);
     }
 
@@ -105,18 +117,29 @@ async function main() {
     process.exit(1);
   }
 
-  // Send set_keeper transaction
-  console.log("📝 Updating keeper...");
+  // Send grant_role / revoke_role transaction
+  console.log(revoke ? "📝 Revoking keeper role..." : "📝 Granting keeper role...");
   try {
-    const tx = await program.methods
-      .setKeeper({ newKeeper })
-      .accountsStrict({
-        authority: provider.wallet.publicKey,
-        global: globalPda,
-      })
-      .rpc();
-
-    console.log("✅ Keeper updated successfully!");
+    const tx = revoke
+      ? await program.methods
+          .revokeRole()
+          .accountsStrict({
+            admin: provider.wallet.publicKey,
+            global: globalPda,
+            roleAssignment: rolePda,
+          })
+          .rpc()
+      : await program.methods
+          .grantRole({ role: KEEPER_ROLE, holder: keeper })
+          .accountsStrict({
+            admin: provider.wallet.publicKey,
+            global: globalPda,
+            roleAssignment: rolePda,
+            systemProgram: SystemProgram.programId,
+          })
+          .rpc();
+
+    console.log(revoke ? "✅ Keeper role revoked!" : "✅ Keeper role granted!");
     console.log("📝 Transaction Signature:", tx);
     console.log("");
 
@@ -126,20 +149,15 @@ async function main() {
     console.log("");
 
     // Verify the update
-    const updatedGlobal = await program.account.global.fetch(globalPda);
-    console.log("📊 Updated Global State:");
-    console.log("   Keeper:", updatedGlobal.keeper.toString());
-    
-    if (updatedGlobal.keeper.equals(newKeeper)) {
-      console.log("");
-      console.log("✅ Keeper verified!");
+    const hasRole = (await connection.getAccountInfo(rolePda)) !== null;
+    if (hasRole !== revoke) {
+      console.log("✅ Keeper role verified!");
     } else {
-      console.log("");
-      console.log("⚠️  Warning: Keeper value doesn't match expected");
+      console.log("⚠️  Warning: Keeper role doesn't match expected state");
     }
 
   } catch (error: any) {
-    console.log("❌ ERROR: Failed to update keeper");
+    console.log("❌ ERROR: Failed to update keeper role");
     console.log("   Error:", error.message);
     process.exit(1);
   }
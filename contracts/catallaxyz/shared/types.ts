@@ -96,7 +96,6 @@ export interface UserBalance {
 export interface GlobalState {
   authority: string;
   usdcMint: string;
-  keeper: string;
   
   // Fee rates (in basis points, 1e6 scale)
   centerTakerFeeRate: number;
@@ -107,8 +106,6 @@ export interface GlobalState {
   
   // Exchange (Polymarket-style) configuration
   tradingPaused: boolean;
-  operatorCount: number;
-  operators: string[];
   
   bump: number;
 }